@@ -1,17 +1,17 @@
 #![no_std]
 
+use payd_common::{admin, council, storage};
 use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env, String, Symbol, token};
 
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
-    Admin,
     Payment(u64),
     PaymentCount,
 }
 
 #[contracttype]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PaymentRecord {
     pub from: Address,
     pub amount: i128,
@@ -29,13 +29,26 @@ pub struct CrossAssetPaymentContract;
 impl CrossAssetPaymentContract {
     /// Initialize the contract with an admin.
     pub fn init(env: Env, admin: Address) {
-        if env.storage().instance().has(&DataKey::Admin) {
-            panic!("Already initialized");
-        }
-        env.storage().instance().set(&DataKey::Admin, &admin);
+        admin::init_admin(&env, &admin);
         env.storage().instance().set(&DataKey::PaymentCount, &0u64);
     }
 
+    /// Hands status updates of payments above each asset's threshold to `council`, usually a
+    /// `multisig` (admin only until a council is set, then the council only).
+    pub fn set_council(env: Env, council: Address) {
+        council::set_council(&env, &council);
+
+        env.events().publish((symbol_short!("council"),), council);
+    }
+
+    /// Sets the largest payment in `asset` the admin can update alone. Once a council is
+    /// set, payments in an asset without a threshold all go to the council.
+    pub fn set_threshold(env: Env, asset: Address, threshold: i128) {
+        council::set_threshold(&env, &asset, threshold);
+
+        env.events().publish((symbol_short!("threshold"), asset), threshold);
+    }
+
     /// Initiate a cross-asset payment.
     pub fn initiate_payment(
         env: Env,
@@ -69,6 +82,7 @@ impl CrossAssetPaymentContract {
         };
 
         env.storage().instance().set(&DataKey::Payment(count), &record);
+        storage::extend_instance(&env);
 
         // Emit an event for backend/anchor tracking
         env.events().publish(
@@ -80,15 +94,17 @@ impl CrossAssetPaymentContract {
     }
 
     /// Update the status of a payment (Admin or Anchor authorized).
+    /// Payments above the asset's threshold are updated by the council instead.
     pub fn update_status(env: Env, payment_id: u64, new_status: Symbol) {
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).expect("Not initialized");
-        admin.require_auth();
-
         let mut record: PaymentRecord = env.storage().instance()
             .get(&DataKey::Payment(payment_id))
             .expect("Payment not found");
 
-        record.status = new_status;
+        if !council::require_for(&env, &record.asset, record.amount) {
+            admin::require_admin(&env);
+        }
+
+        record.status = new_status.clone();
         env.storage().instance().set(&DataKey::Payment(payment_id), &record);
 
         env.events().publish(
@@ -97,6 +113,14 @@ impl CrossAssetPaymentContract {
         );
     }
 
+    pub fn get_council(env: Env) -> Option<Address> {
+        council::get_council(&env)
+    }
+
+    pub fn get_threshold(env: Env, asset: Address) -> Option<i128> {
+        council::get_threshold(&env, &asset)
+    }
+
     /// Get details of a payment.
     pub fn get_payment(env: Env, payment_id: u64) -> Option<PaymentRecord> {
         env.storage().instance().get(&DataKey::Payment(payment_id))
@@ -1,8 +1,8 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::testutils::{Address as _, Events};
-use soroban_sdk::{token, vec, Address, Env, IntoVal, String, vec};
+use soroban_sdk::testutils::{Address as _, Events, MockAuth, MockAuthInvoke};
+use soroban_sdk::{symbol_short, token, vec, Address, Env, IntoVal, String};
 
 #[test]
 fn test_initiate_payment() {
@@ -14,14 +14,14 @@ fn test_initiate_payment() {
     
     // Create a mock token
     let token_admin = Address::generate(&env);
-    let token_address = env.register_stellar_asset_contract(token_admin.clone());
+    let token_address = env.register_stellar_asset_contract_v2(token_admin.clone()).address();
     let token = token::Client::new(&env, &token_address);
     let stellar_token_admin = token::StellarAssetClient::new(&env, &token_address);
 
     // Initial supply to 'from'
     stellar_token_admin.mint(&from, &1000);
 
-    let contract_id = env.register_contract(None, CrossAssetPaymentContract);
+    let contract_id = env.register(CrossAssetPaymentContract, ());
     let client = CrossAssetPaymentContractClient::new(&env, &contract_id);
 
     client.init(&admin);
@@ -41,6 +41,8 @@ fn test_initiate_payment() {
     );
 
     assert_eq!(payment_id, 1);
+    // Events only cover the latest top-level call, so take them before anything else runs
+    let events = env.events().all().filter_by_contract(&contract_id);
 
     // Check balance of contract
     assert_eq!(token.balance(&contract_id), 500);
@@ -53,9 +55,17 @@ fn test_initiate_payment() {
     assert_eq!(record.status, symbol_short!("pending"));
 
     // Check events
-    let events = env.events().all();
-    let last_event = events.last().unwrap();
-    assert_eq!(last_event.2, record.into_val(&env));
+    assert_eq!(
+        events,
+        vec![
+            &env,
+            (
+                contract_id.clone(),
+                (symbol_short!("pay_init"), payment_id).into_val(&env),
+                record.into_val(&env),
+            ),
+        ]
+    );
 }
 
 #[test]
@@ -64,7 +74,7 @@ fn test_update_status() {
     env.mock_all_auths();
 
     let admin = Address::generate(&env);
-    let contract_id = env.register_contract(None, CrossAssetPaymentContract);
+    let contract_id = env.register(CrossAssetPaymentContract, ());
     let client = CrossAssetPaymentContractClient::new(&env, &contract_id);
 
     client.init(&admin);
@@ -73,7 +83,7 @@ fn test_update_status() {
     // Let's use initiate_payment to be realistic
     let from = Address::generate(&env);
     let token_admin = Address::generate(&env);
-    let token_address = env.register_stellar_asset_contract(token_admin);
+    let token_address = env.register_stellar_asset_contract_v2(token_admin).address();
     let stellar_token_admin = token::StellarAssetClient::new(&env, &token_address);
     stellar_token_admin.mint(&from, &1000);
 
@@ -91,3 +101,48 @@ fn test_update_status() {
     let record = client.get_payment(&payment_id).unwrap();
     assert_eq!(record.status, symbol_short!("success"));
 }
+
+#[test]
+fn test_large_payment_needs_council() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let council = Address::generate(&env);
+    let contract_id = env.register(CrossAssetPaymentContract, ());
+    let client = CrossAssetPaymentContractClient::new(&env, &contract_id);
+    client.init(&admin);
+
+    let from = Address::generate(&env);
+    let token_address = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+    token::StellarAssetClient::new(&env, &token_address).mint(&from, &5000);
+
+    client.set_council(&council);
+    client.set_threshold(&token_address, &1000);
+
+    let pay = |amount: i128| {
+        client.initiate_payment(
+            &from,
+            &amount,
+            &token_address,
+            &String::from_str(&env, "rec-1"),
+            &String::from_str(&env, "USD"),
+            &String::from_str(&env, "anc-1"),
+        )
+    };
+    let small = pay(1000);
+    let large = pay(1001);
+
+    let settled = symbol_short!("settled");
+    let update_as = |signer: &Address, payment_id: u64| {
+        let args = (payment_id, settled.clone()).into_val(&env);
+        let invoke = MockAuthInvoke { contract: &contract_id, fn_name: "update_status", args, sub_invokes: &[] };
+        client.mock_auths(&[MockAuth { address: signer, invoke: &invoke }]).try_update_status(&payment_id, &settled).is_ok()
+    };
+
+    env.set_auths(&[]);
+    assert!(update_as(&admin, small));
+    assert!(!update_as(&admin, large));
+    assert!(update_as(&council, large));
+    assert_eq!(client.get_payment(&large).unwrap().status, settled);
+}
@@ -0,0 +1,177 @@
+#![no_std]
+
+//! Merkle-root payroll: the employer funds a period in one transfer and posts the root of
+//! a Merkle tree of `(employee, amount)` leaves, and each employee claims their own line
+//! with a proof. Nothing is stored per employee beyond one bit, so a run costs the same
+//! whether it pays ten people or ten thousand.
+
+use payd_common::{admin, math, storage};
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, token, Address, BytesN, Env, Vec};
+
+pub mod merkle;
+
+#[cfg(test)]
+mod test;
+
+/// Claimed flags are packed this many to a storage entry.
+const BITMAP_WORD_BITS: u32 = 128;
+
+#[contracttype]
+pub enum DataKey {
+    Distribution(u32),
+    /// Claimed flags for leaves `word * 128` to `word * 128 + 127` of a period.
+    Claimed(u32, u32),
+}
+
+/// One funded period. `total` of `asset` was deposited for the `leaf_count` leaves under
+/// `root`; `claimed` has been paid out so far. Claims close at `expires_at`, after which
+/// the rest can be returned to the employer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Distribution {
+    pub asset: Address,
+    pub root: BytesN<32>,
+    pub leaf_count: u32,
+    pub total: i128,
+    pub claimed: i128,
+    pub expires_at: u64,
+    pub reclaimed: bool,
+}
+
+#[contract]
+pub struct MerklePayrollContract;
+
+#[contractimpl]
+impl MerklePayrollContract {
+    /// Initialize with the employer who funds every period.
+    pub fn init(e: Env, employer: Address) {
+        admin::init_admin(&e, &employer);
+    }
+
+    /// Deposits `total` of `asset` for `period` and commits to the tree `root` of
+    /// `leaf_count` leaves (employer only). Each period can be funded once.
+    pub fn fund(e: Env, period: u32, asset: Address, root: BytesN<32>, leaf_count: u32, total: i128, expires_at: u64) {
+        let employer = admin::require_admin(&e);
+
+        let key = DataKey::Distribution(period);
+        if e.storage().persistent().has(&key) {
+            panic!("Period already funded");
+        }
+        if total <= 0 {
+            panic!("Amount must be positive");
+        }
+        if leaf_count == 0 {
+            panic!("Tree must have leaves");
+        }
+        if expires_at <= e.ledger().timestamp() {
+            panic!("Expiry must be in the future");
+        }
+
+        token::Client::new(&e, &asset).transfer(&employer, &e.current_contract_address(), &total);
+
+        let distribution = Distribution {
+            asset,
+            root,
+            leaf_count,
+            total,
+            claimed: 0,
+            expires_at,
+            reclaimed: false,
+        };
+        e.storage().persistent().set(&key, &distribution);
+        storage::extend_persistent(&e, &key);
+
+        e.events().publish((symbol_short!("funded"), period), distribution);
+    }
+
+    /// Pays `employee` the `amount` of leaf `index` in `period`'s tree. `proof` lists the
+    /// sibling hashes from the leaf up (see `merkle`). Each leaf can be claimed once,
+    /// before the period expires.
+    pub fn claim(e: Env, period: u32, employee: Address, index: u32, amount: i128, proof: Vec<BytesN<32>>) {
+        employee.require_auth();
+
+        let key = DataKey::Distribution(period);
+        let mut distribution = Self::load_distribution(&e, period);
+        if e.ledger().timestamp() >= distribution.expires_at {
+            panic!("Distribution expired");
+        }
+        if amount <= 0 {
+            panic!("Amount must be positive");
+        }
+
+        let leaf = merkle::leaf(&e, index, &employee, amount);
+        if !merkle::verify(&e, &distribution.root, distribution.leaf_count, index, leaf, &proof) {
+            panic!("Invalid proof");
+        }
+
+        let word_key = DataKey::Claimed(period, index / BITMAP_WORD_BITS);
+        let word: u128 = e.storage().persistent().get(&word_key).unwrap_or(0);
+        let bit = 1u128 << (index % BITMAP_WORD_BITS);
+        if word & bit != 0 {
+            panic!("Already claimed");
+        }
+
+        // A root that promises more than was deposited can't drain other periods
+        distribution.claimed = math::add(&e, distribution.claimed, amount);
+        if distribution.claimed > distribution.total {
+            panic!("Distribution overdrawn");
+        }
+
+        e.storage().persistent().set(&word_key, &(word | bit));
+        storage::extend_persistent(&e, &word_key);
+        e.storage().persistent().set(&key, &distribution);
+        storage::extend_persistent(&e, &key);
+
+        token::Client::new(&e, &distribution.asset).transfer(&e.current_contract_address(), &employee, &amount);
+
+        e.events().publish((symbol_short!("claim"), period, employee), (index, amount));
+    }
+
+    /// Returns what was left unclaimed in `period` to the employer once it has expired,
+    /// and returns the amount. Anyone can call it.
+    pub fn reclaim(e: Env, period: u32) -> i128 {
+        let mut distribution = Self::load_distribution(&e, period);
+        if e.ledger().timestamp() < distribution.expires_at {
+            panic!("Distribution not expired");
+        }
+        if distribution.reclaimed {
+            panic!("Already reclaimed");
+        }
+
+        distribution.reclaimed = true;
+        let key = DataKey::Distribution(period);
+        e.storage().persistent().set(&key, &distribution);
+        storage::extend_persistent(&e, &key);
+
+        let remaining = math::sub(&e, distribution.total, distribution.claimed);
+        if remaining > 0 {
+            let employer = admin::get_admin(&e);
+            token::Client::new(&e, &distribution.asset).transfer(&e.current_contract_address(), &employer, &remaining);
+        }
+
+        e.events().publish((symbol_short!("reclaim"), period), remaining);
+
+        remaining
+    }
+
+    pub fn get_distribution(e: Env, period: u32) -> Option<Distribution> {
+        e.storage().persistent().get(&DataKey::Distribution(period))
+    }
+
+    pub fn is_claimed(e: Env, period: u32, index: u32) -> bool {
+        let word: u128 = e.storage().persistent().get(&DataKey::Claimed(period, index / BITMAP_WORD_BITS)).unwrap_or(0);
+        word & (1u128 << (index % BITMAP_WORD_BITS)) != 0
+    }
+
+    /// The leaf hash for `(index, employee, amount)`, so tree builders can check their encoding.
+    pub fn leaf(e: Env, index: u32, employee: Address, amount: i128) -> BytesN<32> {
+        merkle::leaf(&e, index, &employee, amount)
+    }
+
+    fn load_distribution(e: &Env, period: u32) -> Distribution {
+        let key = DataKey::Distribution(period);
+        let distribution = e.storage().persistent().get(&key).expect("Period not funded");
+        storage::extend_persistent(e, &key);
+        distribution
+    }
+}
@@ -0,0 +1,93 @@
+//! The Merkle tree a distribution commits to.
+//!
+//! Leaf `i` is `sha256(0x00 || i || employee || amount)`, with `i` as a big-endian `u32`,
+//! `employee` as its XDR encoding and `amount` as a big-endian `i128`. An inner node is
+//! `sha256(0x01 || left || right)`; the prefixes stop a leaf from passing as a node.
+//! Each level is paired up left to right, and a node left without a partner at the end
+//! of a level moves up unchanged, so a proof has no entry for that level.
+//!
+//! `root` and `proof` build the tree off-chain (or in tests); the contract only verifies.
+
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, Vec};
+
+const LEAF_PREFIX: u8 = 0;
+const NODE_PREFIX: u8 = 1;
+
+pub fn leaf(e: &Env, index: u32, employee: &Address, amount: i128) -> BytesN<32> {
+    let mut data = Bytes::from_array(e, &[LEAF_PREFIX]);
+    data.extend_from_array(&index.to_be_bytes());
+    data.append(&employee.clone().to_xdr(e));
+    data.extend_from_array(&amount.to_be_bytes());
+    e.crypto().sha256(&data).to_bytes()
+}
+
+fn node(e: &Env, left: &BytesN<32>, right: &BytesN<32>) -> BytesN<32> {
+    let mut data = Bytes::from_array(e, &[NODE_PREFIX]);
+    data.append(&left.clone().into());
+    data.append(&right.clone().into());
+    e.crypto().sha256(&data).to_bytes()
+}
+
+/// Whether `leaf` sits at `index` of a `leaf_count`-leaf tree with `root`. The proof lists
+/// the siblings from the bottom up and must have exactly one entry per level that has one.
+pub fn verify(e: &Env, root: &BytesN<32>, leaf_count: u32, index: u32, leaf: BytesN<32>, proof: &Vec<BytesN<32>>) -> bool {
+    if index >= leaf_count {
+        return false;
+    }
+
+    let mut hash = leaf;
+    let mut position = index;
+    let mut width = leaf_count;
+    let mut siblings = proof.iter();
+    while width > 1 {
+        if position ^ 1 < width {
+            let Some(sibling) = siblings.next() else {
+                return false;
+            };
+            hash = if position & 1 == 0 { node(e, &hash, &sibling) } else { node(e, &sibling, &hash) };
+        }
+        position /= 2;
+        width = width.div_ceil(2);
+    }
+
+    siblings.next().is_none() && hash == *root
+}
+
+pub fn root(e: &Env, leaves: &Vec<BytesN<32>>) -> BytesN<32> {
+    let mut level = leaves.clone();
+    while level.len() > 1 {
+        level = parent_level(e, &level);
+    }
+    level.first().expect("No leaves")
+}
+
+/// The proof for the leaf at `index`, in the form `verify` takes.
+pub fn proof(e: &Env, leaves: &Vec<BytesN<32>>, index: u32) -> Vec<BytesN<32>> {
+    if index >= leaves.len() {
+        panic!("Index out of range");
+    }
+
+    let mut proof = Vec::new(e);
+    let mut level = leaves.clone();
+    let mut position = index;
+    while level.len() > 1 {
+        if let Some(sibling) = level.get(position ^ 1) {
+            proof.push_back(sibling);
+        }
+        level = parent_level(e, &level);
+        position /= 2;
+    }
+    proof
+}
+
+fn parent_level(e: &Env, level: &Vec<BytesN<32>>) -> Vec<BytesN<32>> {
+    let mut parents = Vec::new(e);
+    for i in (0..level.len()).step_by(2) {
+        let left = level.get(i).unwrap();
+        match level.get(i + 1) {
+            Some(right) => parents.push_back(node(e, &left, &right)),
+            None => parents.push_back(left),
+        }
+    }
+    parents
+}
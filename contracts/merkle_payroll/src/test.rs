@@ -0,0 +1,280 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use payd_testutils::Asset;
+use soroban_sdk::{testutils::{Address as _, Events, Ledger}, token, vec, Address, Env, IntoVal};
+
+const DAY: u64 = 24 * 60 * 60;
+const EXPIRY: u64 = 30 * DAY;
+
+struct Setup<'a> {
+    client: MerklePayrollContractClient<'a>,
+    employer: Address,
+    asset: token::Client<'a>,
+}
+
+fn setup<'a>(e: &Env) -> Setup<'a> {
+    let employer = Address::generate(e);
+    let client = MerklePayrollContractClient::new(e, &e.register(MerklePayrollContract, ()));
+    client.init(&employer);
+
+    let asset = Asset::funded(e, &employer, 1_000_000);
+
+    Setup { client, employer, asset: asset.client }
+}
+
+/// An off-chain payroll file: one `(employee, amount)` line per leaf.
+struct Tree {
+    lines: std::vec::Vec<(Address, i128)>,
+    leaves: Vec<BytesN<32>>,
+}
+
+impl Tree {
+    fn new(e: &Env, amounts: &[i128]) -> Self {
+        let lines: std::vec::Vec<(Address, i128)> = amounts.iter().map(|amount| (Address::generate(e), *amount)).collect();
+        let mut leaves = Vec::new(e);
+        for (index, (employee, amount)) in lines.iter().enumerate() {
+            leaves.push_back(merkle::leaf(e, index as u32, employee, *amount));
+        }
+        Tree { lines, leaves }
+    }
+
+    fn root(&self, e: &Env) -> BytesN<32> {
+        merkle::root(e, &self.leaves)
+    }
+
+    fn total(&self) -> i128 {
+        self.lines.iter().map(|(_, amount)| amount).sum()
+    }
+
+    fn fund(&self, e: &Env, s: &Setup, period: u32) {
+        s.client.fund(&period, &s.asset.address, &self.root(e), &self.leaves.len(), &self.total(), &EXPIRY);
+    }
+
+    fn claim(&self, e: &Env, s: &Setup, period: u32, index: u32) {
+        let (employee, amount) = &self.lines[index as usize];
+        s.client.claim(&period, employee, &index, amount, &merkle::proof(e, &self.leaves, index));
+    }
+}
+
+#[test]
+fn test_proofs_verify_for_every_tree_shape() {
+    let e = Env::default();
+
+    // Odd widths leave a node without a partner on some levels
+    for size in 1..=9u32 {
+        let amounts: std::vec::Vec<i128> = (1..=size as i128).collect();
+        let tree = Tree::new(&e, &amounts);
+        let root = tree.root(&e);
+
+        for index in 0..size {
+            let proof = merkle::proof(&e, &tree.leaves, index);
+            assert!(merkle::verify(&e, &root, size, index, tree.leaves.get(index).unwrap(), &proof));
+
+            let other = tree.leaves.get((index + 1) % size).unwrap();
+            assert_eq!(merkle::verify(&e, &root, size, index, other, &proof), size == 1);
+        }
+    }
+}
+
+#[test]
+fn test_claims_pay_each_line_once() {
+    let e = payd_testutils::env();
+    let s = setup(&e);
+    let tree = Tree::new(&e, &[1_000, 1_500, 2_000, 800, 1_200]);
+    tree.fund(&e, &s, 7);
+    assert_eq!(s.asset.balance(&s.client.address), 6_500);
+
+    for index in 0..5 {
+        tree.claim(&e, &s, 7, index);
+        assert!(s.client.is_claimed(&7, &index));
+    }
+    for (employee, amount) in &tree.lines {
+        assert_eq!(s.asset.balance(employee), *amount);
+    }
+    assert_eq!(s.client.get_distribution(&7).unwrap().claimed, 6_500);
+    assert_eq!(s.asset.balance(&s.client.address), 0);
+
+    let (employee, amount) = &tree.lines[0];
+    let proof = merkle::proof(&e, &tree.leaves, 0);
+    let result = s.client.try_claim(&7, employee, &0, amount, &proof);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_claim_event() {
+    let e = payd_testutils::env();
+    let s = setup(&e);
+    let tree = Tree::new(&e, &[1_000, 1_500]);
+    tree.fund(&e, &s, 1);
+
+    tree.claim(&e, &s, 1, 1);
+    let (employee, _) = &tree.lines[1];
+    assert_eq!(
+        e.events().all().filter_by_contract(&s.client.address),
+        vec![
+            &e,
+            (
+                s.client.address.clone(),
+                (symbol_short!("claim"), 1u32, employee.clone()).into_val(&e),
+                (1u32, 1_500i128).into_val(&e),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_bitmap_spans_words() {
+    let e = payd_testutils::env();
+    let s = setup(&e);
+    let amounts = [10; 300];
+    let tree = Tree::new(&e, &amounts);
+    tree.fund(&e, &s, 0);
+
+    for index in [0, 127, 128, 299] {
+        tree.claim(&e, &s, 0, index);
+    }
+    for index in [0, 127, 128, 299] {
+        assert!(s.client.is_claimed(&0, &index));
+    }
+    for index in [1, 126, 129, 255, 256, 298] {
+        assert!(!s.client.is_claimed(&0, &index));
+    }
+    assert_eq!(s.client.get_distribution(&0).unwrap().claimed, 40);
+}
+
+#[test]
+fn test_invalid_proofs_rejected() {
+    let e = payd_testutils::env();
+    let s = setup(&e);
+    let tree = Tree::new(&e, &[1_000, 1_500, 2_000, 800, 1_200]);
+    tree.fund(&e, &s, 0);
+
+    let (alice, alice_amount) = &tree.lines[0];
+    let (bob, _) = &tree.lines[1];
+    let proof = merkle::proof(&e, &tree.leaves, 0);
+
+    // More than the line is worth
+    assert!(s.client.try_claim(&0, alice, &0, &(alice_amount + 1), &proof).is_err());
+    // Someone else's line
+    assert!(s.client.try_claim(&0, bob, &0, alice_amount, &proof).is_err());
+    // The right line at the wrong index
+    assert!(s.client.try_claim(&0, alice, &1, alice_amount, &proof).is_err());
+    // A leaf past the end of the tree
+    assert!(s.client.try_claim(&0, alice, &5, alice_amount, &proof).is_err());
+
+    let mut short = proof.clone();
+    short.pop_back();
+    assert!(s.client.try_claim(&0, alice, &0, alice_amount, &short).is_err());
+    let mut long = proof.clone();
+    long.push_back(tree.leaves.get(4).unwrap());
+    assert!(s.client.try_claim(&0, alice, &0, alice_amount, &long).is_err());
+
+    // A period that was never funded
+    assert!(s.client.try_claim(&1, alice, &0, alice_amount, &proof).is_err());
+
+    assert!(!s.client.is_claimed(&0, &0));
+    assert_eq!(s.asset.balance(alice), 0);
+}
+
+#[test]
+fn test_employee_must_authorize_claim() {
+    let e = payd_testutils::env();
+    let s = setup(&e);
+    let tree = Tree::new(&e, &[1_000, 1_500]);
+    tree.fund(&e, &s, 0);
+
+    let (alice, amount) = &tree.lines[0];
+    let proof = merkle::proof(&e, &tree.leaves, 0);
+    e.set_auths(&[]);
+    assert!(s.client.try_claim(&0, alice, &0, amount, &proof).is_err());
+}
+
+#[test]
+fn test_reclaim_after_expiry() {
+    let e = payd_testutils::env();
+    let s = setup(&e);
+    let tree = Tree::new(&e, &[1_000, 1_500, 2_000]);
+    tree.fund(&e, &s, 0);
+    tree.claim(&e, &s, 0, 1);
+
+    assert!(s.client.try_reclaim(&0).is_err());
+
+    // Claims close at expiry and the rest goes back to the employer
+    e.ledger().set_timestamp(EXPIRY);
+    let (alice, amount) = &tree.lines[0];
+    assert!(s.client.try_claim(&0, alice, &0, amount, &merkle::proof(&e, &tree.leaves, 0)).is_err());
+
+    let before = s.asset.balance(&s.employer);
+    assert_eq!(s.client.reclaim(&0), 3_000);
+    assert_eq!(s.asset.balance(&s.employer), before + 3_000);
+    assert_eq!(s.asset.balance(&s.client.address), 0);
+    assert!(s.client.get_distribution(&0).unwrap().reclaimed);
+
+    assert!(s.client.try_reclaim(&0).is_err());
+}
+
+#[test]
+fn test_periods_are_separate() {
+    let e = payd_testutils::env();
+    let s = setup(&e);
+    let june = Tree::new(&e, &[1_000, 1_500]);
+    let july = Tree::new(&e, &[2_000, 2_500, 3_000]);
+    june.fund(&e, &s, 6);
+    july.fund(&e, &s, 7);
+
+    june.claim(&e, &s, 6, 0);
+    assert!(!s.client.is_claimed(&7, &0));
+
+    // A June proof is no good against July's root
+    let (alice, amount) = &june.lines[1];
+    assert!(s.client.try_claim(&7, alice, &1, amount, &merkle::proof(&e, &june.leaves, 1)).is_err());
+
+    july.claim(&e, &s, 7, 0);
+    assert_eq!(s.asset.balance(&s.client.address), 1_500 + 5_500);
+}
+
+#[test]
+fn test_root_cannot_overdraw_deposit() {
+    let e = payd_testutils::env();
+    let s = setup(&e);
+    let tree = Tree::new(&e, &[1_000, 1_500]);
+    // Funded for less than the tree promises
+    s.client.fund(&0, &s.asset.address, &tree.root(&e), &2, &2_000, &EXPIRY);
+    tree.fund(&e, &s, 1);
+
+    tree.claim(&e, &s, 0, 1);
+    let (alice, amount) = &tree.lines[0];
+    assert!(s.client.try_claim(&0, alice, &0, amount, &merkle::proof(&e, &tree.leaves, 0)).is_err());
+    assert_eq!(s.asset.balance(&s.client.address), 500 + 2_500);
+}
+
+#[test]
+fn test_fund_validation() {
+    let e = payd_testutils::env();
+    let s = setup(&e);
+    let tree = Tree::new(&e, &[1_000, 1_500]);
+    let root = tree.root(&e);
+
+    assert!(s.client.try_fund(&0, &s.asset.address, &root, &2, &0, &EXPIRY).is_err());
+    assert!(s.client.try_fund(&0, &s.asset.address, &root, &0, &2_500, &EXPIRY).is_err());
+    e.ledger().set_timestamp(EXPIRY);
+    assert!(s.client.try_fund(&0, &s.asset.address, &root, &2, &2_500, &EXPIRY).is_err());
+
+    s.client.fund(&0, &s.asset.address, &root, &2, &2_500, &(EXPIRY + DAY));
+    assert!(s.client.try_fund(&0, &s.asset.address, &root, &2, &2_500, &(EXPIRY + DAY)).is_err());
+
+    e.set_auths(&[]);
+    assert!(s.client.try_fund(&1, &s.asset.address, &root, &2, &2_500, &(EXPIRY + DAY)).is_err());
+}
+
+#[test]
+fn test_leaf_matches_off_chain_encoding() {
+    let e = payd_testutils::env();
+    let s = setup(&e);
+    let employee = Address::generate(&e);
+
+    assert_eq!(s.client.leaf(&3, &employee, &1_000), merkle::leaf(&e, 3, &employee, 1_000));
+    assert_ne!(s.client.leaf(&3, &employee, &1_000), merkle::leaf(&e, 4, &employee, 1_000));
+}
@@ -0,0 +1,196 @@
+#![no_std]
+
+use payd_common::storage;
+use soroban_sdk::{
+    contract, contractimpl, contracttype, symbol_short, xdr::ToXdr, Address, BytesN, Env, IntoVal, Symbol, Val,
+    Vec,
+};
+
+#[cfg(test)]
+mod test;
+
+#[contracttype]
+pub enum DataKey {
+    Signers,
+    Threshold,
+    ProposalCount,
+    Proposal(u32),
+}
+
+/// What a proposal does once approved. `Call` invokes another contract with this
+/// multisig as the caller, so it passes the `require_auth` of any contract that has
+/// the multisig set as its admin (e.g. `update_status`, `update_recipients`, `clawback`)
+/// or as its council for amounts above a threshold (see `payd_common::council`).
+#[derive(Clone)]
+#[contracttype]
+pub enum Action {
+    Call(Address, Symbol, Vec<Val>),
+    SetSigners(Vec<Address>, u32),
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct Proposal {
+    pub action: Action,
+    pub call_hash: BytesN<32>,
+    pub proposer: Address,
+    pub expires_at: u64,
+    pub approvals: Vec<Address>,
+    pub executed: bool,
+}
+
+#[contract]
+pub struct MultisigContract;
+
+#[contractimpl]
+impl MultisigContract {
+    /// Initialize with the signer set and how many of them must approve a proposal.
+    pub fn init(e: Env, signers: Vec<Address>, threshold: u32) {
+        storage::guard_init(&e, &DataKey::Signers);
+        Self::set_signer_set(&e, signers, threshold);
+        e.storage().instance().set(&DataKey::ProposalCount, &0u32);
+    }
+
+    /// Proposes `action` and counts it as the proposer's approval. Returns the proposal id.
+    pub fn propose(e: Env, proposer: Address, action: Action, expires_at: u64) -> u32 {
+        proposer.require_auth();
+
+        if !Self::signers(&e).contains(&proposer) {
+            panic!("Not a signer");
+        }
+        if expires_at <= e.ledger().timestamp() {
+            panic!("Expiry must be in the future");
+        }
+
+        let mut count: u32 = storage::read_instance(&e, &DataKey::ProposalCount);
+        count += 1;
+        e.storage().instance().set(&DataKey::ProposalCount, &count);
+        storage::extend_instance(&e);
+
+        let call_hash = e.crypto().sha256(&action.clone().to_xdr(&e)).to_bytes();
+        let proposal = Proposal {
+            action,
+            call_hash: call_hash.clone(),
+            proposer: proposer.clone(),
+            expires_at,
+            approvals: Vec::from_array(&e, [proposer.clone()]),
+            executed: false,
+        };
+        Self::save_proposal(&e, count, &proposal);
+
+        e.events().publish((symbol_short!("proposed"), count, proposer), call_hash);
+
+        count
+    }
+
+    /// Adds `signer`'s approval to a pending proposal.
+    pub fn approve(e: Env, signer: Address, proposal_id: u32) {
+        signer.require_auth();
+
+        if !Self::signers(&e).contains(&signer) {
+            panic!("Not a signer");
+        }
+
+        let mut proposal = Self::pending_proposal(&e, proposal_id);
+        if proposal.approvals.contains(&signer) {
+            panic!("Already approved");
+        }
+        proposal.approvals.push_back(signer.clone());
+        Self::save_proposal(&e, proposal_id, &proposal);
+
+        e.events().publish((symbol_short!("approved"), proposal_id), signer);
+    }
+
+    /// Withdraws `signer`'s approval from a proposal that has not run yet.
+    pub fn revoke(e: Env, signer: Address, proposal_id: u32) {
+        signer.require_auth();
+
+        let mut proposal = Self::pending_proposal(&e, proposal_id);
+        let index = proposal.approvals.first_index_of(&signer).expect("Not approved");
+        proposal.approvals.remove(index);
+        Self::save_proposal(&e, proposal_id, &proposal);
+
+        e.events().publish((symbol_short!("revoked"), proposal_id), signer);
+    }
+
+    /// Runs a proposal once enough current signers have approved it. Anyone can call this.
+    /// Approvals from addresses that have since left the signer set do not count.
+    pub fn execute(e: Env, proposal_id: u32) -> Val {
+        let mut proposal = Self::pending_proposal(&e, proposal_id);
+
+        let signers = Self::signers(&e);
+        let threshold: u32 = storage::read_instance(&e, &DataKey::Threshold);
+        let approvals = proposal.approvals.iter().filter(|a| signers.contains(a)).count() as u32;
+        if approvals < threshold {
+            panic!("Not enough approvals");
+        }
+
+        // Mark it executed before making the call
+        proposal.executed = true;
+        Self::save_proposal(&e, proposal_id, &proposal);
+
+        let result = match proposal.action {
+            Action::Call(contract, function, args) => e.invoke_contract::<Val>(&contract, &function, args),
+            Action::SetSigners(signers, threshold) => {
+                Self::set_signer_set(&e, signers, threshold);
+                ().into_val(&e)
+            }
+        };
+
+        e.events().publish((symbol_short!("executed"), proposal_id), proposal.call_hash);
+
+        result
+    }
+
+    pub fn get_proposal(e: Env, proposal_id: u32) -> Option<Proposal> {
+        e.storage().persistent().get(&DataKey::Proposal(proposal_id))
+    }
+
+    pub fn get_signers(e: Env) -> Vec<Address> {
+        Self::signers(&e)
+    }
+
+    pub fn get_threshold(e: Env) -> u32 {
+        storage::read_instance(&e, &DataKey::Threshold)
+    }
+
+    fn signers(e: &Env) -> Vec<Address> {
+        storage::read_instance(e, &DataKey::Signers)
+    }
+
+    fn pending_proposal(e: &Env, proposal_id: u32) -> Proposal {
+        let key = DataKey::Proposal(proposal_id);
+        let proposal: Proposal = e.storage().persistent().get(&key).expect("Proposal not found");
+        storage::extend_persistent(e, &key);
+
+        if proposal.executed {
+            panic!("Already executed");
+        }
+        if e.ledger().timestamp() >= proposal.expires_at {
+            panic!("Proposal expired");
+        }
+        proposal
+    }
+
+    fn save_proposal(e: &Env, proposal_id: u32, proposal: &Proposal) {
+        let key = DataKey::Proposal(proposal_id);
+        e.storage().persistent().set(&key, proposal);
+        storage::extend_persistent(e, &key);
+    }
+
+    fn set_signer_set(e: &Env, signers: Vec<Address>, threshold: u32) {
+        if threshold == 0 || threshold > signers.len() {
+            panic!("Threshold must be between 1 and the number of signers");
+        }
+        for (i, signer) in signers.iter().enumerate() {
+            if signers.first_index_of(&signer) != Some(i as u32) {
+                panic!("Duplicate signer");
+            }
+        }
+
+        e.storage().instance().set(&DataKey::Signers, &signers);
+        e.storage().instance().set(&DataKey::Threshold, &threshold);
+
+        e.events().publish((symbol_short!("signers"),), (signers, threshold));
+    }
+}
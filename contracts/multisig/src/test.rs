@@ -0,0 +1,192 @@
+#![cfg(test)]
+
+use super::*;
+use payd_testutils::Asset;
+use revenue_split::{RecipientShare, RevenueSplitContract, RevenueSplitContractClient};
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Env, IntoVal, Symbol, Vec};
+
+struct Setup<'a> {
+    multisig: MultisigContractClient<'a>,
+    signers: [Address; 3],
+}
+
+fn setup<'a>(e: &Env) -> Setup<'a> {
+    let signers: [Address; 3] = payd_testutils::accounts(e);
+    let multisig = MultisigContractClient::new(e, &e.register(MultisigContract, ()));
+    multisig.init(&Vec::from_array(e, signers.clone()), &2);
+
+    Setup { multisig, signers }
+}
+
+/// A revenue split administered by the multisig, plus a proposal to point it all at `new_destination`.
+fn split_with_proposal(e: &Env, s: &Setup, new_destination: &Address) -> (Address, Action) {
+    let split_id = e.register(RevenueSplitContract, ());
+    let split = RevenueSplitContractClient::new(e, &split_id);
+    split.init(&s.multisig.address, &Vec::from_array(e, [
+        RecipientShare { destination: Address::generate(e), basis_points: 10000 },
+    ]));
+
+    let new_shares = Vec::from_array(e, [
+        RecipientShare { destination: new_destination.clone(), basis_points: 10000 },
+    ]);
+    let action = Action::Call(
+        split_id.clone(),
+        Symbol::new(e, "update_recipients"),
+        (new_shares,).into_val(e),
+    );
+    (split_id, action)
+}
+
+#[test]
+fn test_proposal_executes_after_threshold() {
+    let e = payd_testutils::env();
+    let s = setup(&e);
+    let destination = Address::generate(&e);
+    let (split_id, action) = split_with_proposal(&e, &s, &destination);
+
+    let id = s.multisig.propose(&s.signers[0], &action, &1000);
+    assert_eq!(s.multisig.get_proposal(&id).unwrap().approvals.len(), 1);
+
+    // The proposer's approval alone is not enough
+    assert!(s.multisig.try_execute(&id).is_err());
+
+    s.multisig.approve(&s.signers[1], &id);
+
+    // Execution needs no signature, and the split's admin check is met by the multisig itself
+    e.set_auths(&[]);
+    s.multisig.execute(&id);
+    assert!(s.multisig.get_proposal(&id).unwrap().executed);
+    e.mock_all_auths();
+
+    // The split now pays the new destination, proving the admin call went through
+    let sender = Address::generate(&e);
+    let asset = Asset::funded(&e, &sender, 100);
+    RevenueSplitContractClient::new(&e, &split_id).distribute(&asset.address, &sender, &100);
+    assert_eq!(asset.balance(&destination), 100);
+
+    // A proposal runs at most once
+    assert!(s.multisig.try_execute(&id).is_err());
+}
+
+#[test]
+fn test_single_admin_key_cannot_update() {
+    let e = payd_testutils::env();
+    let s = setup(&e);
+    let (split_id, _) = split_with_proposal(&e, &s, &Address::generate(&e));
+
+    // A signer calling the split directly does not satisfy the multisig's auth
+    e.set_auths(&[]);
+    let split = RevenueSplitContractClient::new(&e, &split_id);
+    let shares = Vec::from_array(&e, [
+        RecipientShare { destination: s.signers[0].clone(), basis_points: 10000 },
+    ]);
+    assert!(split.try_update_recipients(&shares).is_err());
+}
+
+#[test]
+fn test_call_hash_identifies_action() {
+    let e = payd_testutils::env();
+    let s = setup(&e);
+    let (_, action) = split_with_proposal(&e, &s, &Address::generate(&e));
+    let (_, other_action) = split_with_proposal(&e, &s, &Address::generate(&e));
+
+    let first = s.multisig.propose(&s.signers[0], &action, &1000);
+    let second = s.multisig.propose(&s.signers[1], &action, &1000);
+    let third = s.multisig.propose(&s.signers[1], &other_action, &1000);
+
+    let hash = |id: &u32| s.multisig.get_proposal(id).unwrap().call_hash;
+    assert_eq!(hash(&first), hash(&second));
+    assert_ne!(hash(&first), hash(&third));
+}
+
+#[test]
+fn test_expired_proposal() {
+    let e = payd_testutils::env();
+    let s = setup(&e);
+    let (_, action) = split_with_proposal(&e, &s, &Address::generate(&e));
+
+    let id = s.multisig.propose(&s.signers[0], &action, &1000);
+    e.ledger().set_timestamp(1000);
+
+    assert!(s.multisig.try_approve(&s.signers[1], &id).is_err());
+    assert!(s.multisig.try_execute(&id).is_err());
+}
+
+#[test]
+fn test_revoke_approval() {
+    let e = payd_testutils::env();
+    let s = setup(&e);
+    let (_, action) = split_with_proposal(&e, &s, &Address::generate(&e));
+
+    let id = s.multisig.propose(&s.signers[0], &action, &1000);
+    s.multisig.approve(&s.signers[1], &id);
+    s.multisig.revoke(&s.signers[0], &id);
+
+    assert!(s.multisig.try_execute(&id).is_err());
+}
+
+#[test]
+fn test_rotate_signers() {
+    let e = payd_testutils::env();
+    let s = setup(&e);
+    let newcomer = Address::generate(&e);
+
+    let new_signers = Vec::from_array(&e, [s.signers[1].clone(), s.signers[2].clone(), newcomer.clone()]);
+    let rotate = s.multisig.propose(&s.signers[0], &Action::SetSigners(new_signers.clone(), 3), &1000);
+
+    let (_, action) = split_with_proposal(&e, &s, &Address::generate(&e));
+    let pending = s.multisig.propose(&s.signers[0], &action, &1000);
+    s.multisig.approve(&s.signers[1], &pending);
+
+    s.multisig.approve(&s.signers[1], &rotate);
+    s.multisig.execute(&rotate);
+    assert_eq!(s.multisig.get_signers(), new_signers);
+    assert_eq!(s.multisig.get_threshold(), 3);
+
+    // The removed signer's earlier approval no longer counts
+    s.multisig.approve(&newcomer, &pending);
+    assert!(s.multisig.try_execute(&pending).is_err());
+    s.multisig.approve(&s.signers[2], &pending);
+    s.multisig.execute(&pending);
+}
+
+#[test]
+#[should_panic(expected = "Not a signer")]
+fn test_outsider_cannot_approve() {
+    let e = payd_testutils::env();
+    let s = setup(&e);
+    let (_, action) = split_with_proposal(&e, &s, &Address::generate(&e));
+
+    let id = s.multisig.propose(&s.signers[0], &action, &1000);
+    s.multisig.approve(&Address::generate(&e), &id);
+}
+
+#[test]
+#[should_panic(expected = "Already approved")]
+fn test_approve_twice() {
+    let e = payd_testutils::env();
+    let s = setup(&e);
+    let (_, action) = split_with_proposal(&e, &s, &Address::generate(&e));
+
+    let id = s.multisig.propose(&s.signers[0], &action, &1000);
+    s.multisig.approve(&s.signers[0], &id);
+}
+
+#[test]
+#[should_panic(expected = "Threshold must be between 1 and the number of signers")]
+fn test_threshold_above_signer_count() {
+    let e = Env::default();
+    let contract_id = e.register(MultisigContract, ());
+    let multisig = MultisigContractClient::new(&e, &contract_id);
+    multisig.init(&Vec::from_array(&e, [Address::generate(&e)]), &2);
+}
+
+#[test]
+#[should_panic(expected = "Duplicate signer")]
+fn test_duplicate_signer() {
+    let e = Env::default();
+    let signer = Address::generate(&e);
+    let contract_id = e.register(MultisigContract, ());
+    let multisig = MultisigContractClient::new(&e, &contract_id);
+    multisig.init(&Vec::from_array(&e, [signer.clone(), signer]), &1);
+}
@@ -0,0 +1,158 @@
+#![no_std]
+
+//! ORGUSD, the organization's SEP-41 stablecoin, built on OpenZeppelin's `stellar-tokens`.
+//!
+//! Only KYC'd org members (and the PayD contracts that hold funds for them) can hold or move
+//! it: every account must be on the allowlist. On top of that the compliance role can freeze
+//! individual accounts, and the admin (the issuer) mints and claws back.
+
+use payd_common::storage;
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env, MuxedAddress, String, Symbol};
+use stellar_access::access_control::{self as access_control, AccessControl};
+use stellar_macros::{only_admin, only_role};
+use stellar_tokens::fungible::{
+    allowlist::{AllowList, FungibleAllowList},
+    burnable::FungibleBurnable,
+    Base, FungibleToken,
+};
+
+#[cfg(test)]
+mod test;
+
+pub const DECIMALS: u32 = 7;
+
+#[contracttype]
+pub enum DataKey {
+    Frozen(Address),
+}
+
+#[contract]
+pub struct OrgUsdContract;
+
+#[contractimpl]
+impl OrgUsdContract {
+    /// `admin` issues the token and manages roles; `compliance` is granted the `compliance` role,
+    /// which manages the allowlist and account freezes. Both start on the allowlist.
+    pub fn __constructor(e: &Env, admin: Address, compliance: Address) {
+        Base::set_metadata(e, DECIMALS, String::from_str(e, "Organization USD"), String::from_str(e, "ORGUSD"));
+
+        access_control::set_admin(e, &admin);
+        access_control::grant_role_no_auth(e, &admin, &compliance, &Symbol::new(e, "compliance"));
+
+        AllowList::allow_user(e, &admin);
+        AllowList::allow_user(e, &compliance);
+    }
+
+    /// Issues `amount` to an allowed, unfrozen account (admin only).
+    #[only_admin]
+    pub fn mint(e: &Env, to: Address, amount: i128) {
+        if !AllowList::allowed(e, &to) {
+            panic!("Account not allowed");
+        }
+        Self::require_not_frozen(e, &to);
+
+        Base::mint(e, &to, amount);
+        storage::extend_instance(e);
+    }
+
+    /// Takes `amount` back from any account, frozen or not, and destroys it (admin only).
+    /// Used to recover funds for regulatory or error-correction purposes.
+    #[only_admin]
+    pub fn clawback(e: &Env, from: Address, amount: i128) {
+        if amount <= 0 {
+            panic!("Amount must be positive");
+        }
+
+        Base::update(e, Some(&from), None, amount);
+
+        // Same topics as a Stellar asset contract's clawback, so indexers treat both alike
+        let admin = access_control::get_admin(e).expect("No admin");
+        e.events().publish((symbol_short!("clawback"), admin, from), amount);
+    }
+
+    /// Stops `account` from sending, receiving or burning ORGUSD (compliance only).
+    #[only_role(operator, "compliance")]
+    pub fn freeze(e: &Env, account: Address, operator: Address) {
+        let key = DataKey::Frozen(account.clone());
+        e.storage().persistent().set(&key, &true);
+        storage::extend_persistent(e, &key);
+
+        e.events().publish((symbol_short!("frozen"), account), true);
+    }
+
+    /// Lifts a freeze (compliance only).
+    #[only_role(operator, "compliance")]
+    pub fn unfreeze(e: &Env, account: Address, operator: Address) {
+        e.storage().persistent().remove(&DataKey::Frozen(account.clone()));
+
+        e.events().publish((symbol_short!("frozen"), account), false);
+    }
+
+    pub fn is_frozen(e: &Env, account: Address) -> bool {
+        e.storage().persistent().has(&DataKey::Frozen(account))
+    }
+
+    fn require_not_frozen(e: &Env, account: &Address) {
+        if Self::is_frozen(e, account.clone()) {
+            panic!("Account frozen");
+        }
+    }
+}
+
+/// SEP-41 token interface. Transfers go through `AllowList`, which rejects any sender or
+/// recipient that is not allowed; frozen accounts, including a frozen spender, are checked first.
+#[contractimpl(contracttrait)]
+impl FungibleToken for OrgUsdContract {
+    type ContractType = AllowList;
+
+    fn transfer(e: &Env, from: Address, to: MuxedAddress, amount: i128) {
+        Self::require_not_frozen(e, &from);
+        Self::require_not_frozen(e, &to.address());
+
+        AllowList::transfer(e, &from, &to, amount);
+    }
+
+    fn transfer_from(e: &Env, spender: Address, from: Address, to: Address, amount: i128) {
+        Self::require_not_frozen(e, &spender);
+        Self::require_not_frozen(e, &from);
+        Self::require_not_frozen(e, &to);
+
+        AllowList::transfer_from(e, &spender, &from, &to, amount);
+    }
+}
+
+#[contractimpl(contracttrait)]
+impl FungibleBurnable for OrgUsdContract {
+    fn burn(e: &Env, from: Address, amount: i128) {
+        Self::require_not_frozen(e, &from);
+
+        AllowList::burn(e, &from, amount);
+    }
+
+    fn burn_from(e: &Env, spender: Address, from: Address, amount: i128) {
+        Self::require_not_frozen(e, &spender);
+        Self::require_not_frozen(e, &from);
+
+        AllowList::burn_from(e, &spender, &from, amount);
+    }
+}
+
+#[contractimpl(contracttrait)]
+impl FungibleAllowList for OrgUsdContract {
+    fn allowed(e: &Env, account: Address) -> bool {
+        AllowList::allowed(e, &account)
+    }
+
+    #[only_role(operator, "compliance")]
+    fn allow_user(e: &Env, user: Address, operator: Address) {
+        AllowList::allow_user(e, &user);
+    }
+
+    #[only_role(operator, "compliance")]
+    fn disallow_user(e: &Env, user: Address, operator: Address) {
+        AllowList::disallow_user(e, &user);
+    }
+}
+
+#[contractimpl(contracttrait)]
+impl AccessControl for OrgUsdContract {}
@@ -0,0 +1,246 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use revenue_split::{RecipientShare, RevenueSplitContract, RevenueSplitContractClient};
+use soroban_sdk::{testutils::{Address as _, Events, Ledger}, token, vec, Address, Env, IntoVal};
+use vesting_escrow::{CliffMode, TrancheTerms, VestingContract, VestingContractClient, VestingSchedule, DEFAULT_START_BOUNDS};
+
+struct Setup<'a> {
+    client: OrgUsdContractClient<'a>,
+    /// The same contract through the standard SEP-41 interface.
+    token: token::Client<'a>,
+    admin: Address,
+    compliance: Address,
+}
+
+fn setup<'a>(e: &Env) -> Setup<'a> {
+    let [admin, compliance] = payd_testutils::accounts(e);
+    let contract_id = e.register(OrgUsdContract, (&admin, &compliance));
+
+    Setup {
+        client: OrgUsdContractClient::new(e, &contract_id),
+        token: token::Client::new(e, &contract_id),
+        admin,
+        compliance,
+    }
+}
+
+/// A KYC'd member holding `amount`.
+fn member(e: &Env, s: &Setup, amount: i128) -> Address {
+    let member = Address::generate(e);
+    s.client.allow_user(&member, &s.compliance);
+    if amount > 0 {
+        s.client.mint(&member, &amount);
+    }
+    member
+}
+
+#[test]
+fn test_metadata() {
+    let e = payd_testutils::env();
+    let s = setup(&e);
+
+    assert_eq!(s.token.decimals(), 7);
+    assert_eq!(s.token.name(), String::from_str(&e, "Organization USD"));
+    assert_eq!(s.token.symbol(), String::from_str(&e, "ORGUSD"));
+    assert!(s.client.allowed(&s.admin));
+    assert!(s.client.allowed(&s.compliance));
+}
+
+#[test]
+fn test_sep41_transfer_and_burn() {
+    let e = payd_testutils::env();
+    let s = setup(&e);
+    let alice = member(&e, &s, 1_000);
+    let bob = member(&e, &s, 0);
+
+    s.token.transfer(&alice, &bob, &300);
+    assert_eq!(s.token.balance(&alice), 700);
+    assert_eq!(s.token.balance(&bob), 300);
+
+    s.token.burn(&bob, &100);
+    assert_eq!(s.token.balance(&bob), 200);
+    assert_eq!(s.client.total_supply(), 900);
+
+    assert!(s.token.try_transfer(&alice, &bob, &701).is_err());
+}
+
+#[test]
+fn test_sep41_allowance() {
+    let e = payd_testutils::env();
+    let s = setup(&e);
+    let alice = member(&e, &s, 1_000);
+    let bob = member(&e, &s, 0);
+    let spender = member(&e, &s, 0);
+
+    let live_until = e.ledger().sequence() + 1_000;
+    s.token.approve(&alice, &spender, &500, &live_until);
+    assert_eq!(s.token.allowance(&alice, &spender), 500);
+
+    s.token.transfer_from(&spender, &alice, &bob, &200);
+    s.token.burn_from(&spender, &alice, &100);
+    assert_eq!(s.token.allowance(&alice, &spender), 200);
+    assert_eq!(s.token.balance(&alice), 700);
+    assert_eq!(s.token.balance(&bob), 200);
+
+    assert!(s.token.try_transfer_from(&spender, &alice, &bob, &201).is_err());
+
+    // Allowances lapse with their ledger
+    e.ledger().set_sequence_number(live_until + 1);
+    assert_eq!(s.token.allowance(&alice, &spender), 0);
+}
+
+#[test]
+fn test_allowlist() {
+    let e = payd_testutils::env();
+    let s = setup(&e);
+    let alice = member(&e, &s, 1_000);
+    let outsider = Address::generate(&e);
+
+    assert!(s.token.try_transfer(&alice, &outsider, &100).is_err());
+    assert!(s.client.try_mint(&outsider, &100).is_err());
+
+    // Only the compliance role manages the list
+    assert!(s.client.try_allow_user(&outsider, &alice).is_err());
+    s.client.allow_user(&outsider, &s.compliance);
+    s.token.transfer(&alice, &outsider, &100);
+
+    // A member who leaves can no longer move what they hold
+    s.client.disallow_user(&outsider, &s.compliance);
+    assert!(!s.client.allowed(&outsider));
+    assert!(s.token.try_transfer(&outsider, &alice, &100).is_err());
+    assert_eq!(s.token.balance(&outsider), 100);
+}
+
+#[test]
+fn test_freeze() {
+    let e = payd_testutils::env();
+    let s = setup(&e);
+    let alice = member(&e, &s, 1_000);
+    let bob = member(&e, &s, 1_000);
+
+    assert!(s.client.try_freeze(&bob, &alice).is_err());
+    s.client.freeze(&bob, &s.compliance);
+    assert!(s.client.is_frozen(&bob));
+
+    assert!(s.token.try_transfer(&bob, &alice, &100).is_err());
+    assert!(s.token.try_transfer(&alice, &bob, &100).is_err());
+    assert!(s.token.try_burn(&bob, &100).is_err());
+    assert!(s.client.try_mint(&bob, &100).is_err());
+
+    let live_until = e.ledger().sequence() + 1_000;
+    s.token.approve(&bob, &alice, &500, &live_until);
+    assert!(s.token.try_transfer_from(&alice, &bob, &alice, &100).is_err());
+
+    // A frozen spender can't move someone else's funds either
+    let carol = member(&e, &s, 0);
+    s.token.approve(&alice, &bob, &500, &live_until);
+    assert!(s.token.try_transfer_from(&bob, &alice, &carol, &100).is_err());
+    assert!(s.token.try_burn_from(&bob, &alice, &100).is_err());
+    assert_eq!(s.token.balance(&alice), 1_000);
+
+    s.client.unfreeze(&bob, &s.compliance);
+    assert!(!s.client.is_frozen(&bob));
+    s.token.transfer(&bob, &alice, &100);
+    assert_eq!(s.token.balance(&alice), 1_100);
+}
+
+#[test]
+fn test_clawback() {
+    let e = payd_testutils::env();
+    let s = setup(&e);
+    let alice = member(&e, &s, 1_000);
+
+    // Frozen funds can still be recovered
+    s.client.freeze(&alice, &s.compliance);
+    s.client.clawback(&alice, &400);
+    assert_eq!(
+        e.events().all().filter_by_contract(&s.client.address),
+        vec![
+            &e,
+            (
+                s.client.address.clone(),
+                (symbol_short!("clawback"), s.admin.clone(), alice.clone()).into_val(&e),
+                400i128.into_val(&e),
+            ),
+        ]
+    );
+
+    assert_eq!(s.token.balance(&alice), 600);
+    assert_eq!(s.client.total_supply(), 600);
+    assert!(s.client.try_clawback(&alice, &601).is_err());
+    assert!(s.client.try_clawback(&alice, &0).is_err());
+}
+
+#[test]
+fn test_issuer_only() {
+    let e = payd_testutils::env();
+    let s = setup(&e);
+    let alice = member(&e, &s, 1_000);
+
+    e.set_auths(&[]);
+    assert!(s.client.try_mint(&alice, &100).is_err());
+    assert!(s.client.try_clawback(&alice, &100).is_err());
+    assert_eq!(s.token.balance(&alice), 1_000);
+}
+
+#[test]
+fn test_revenue_split_asset() {
+    let e = payd_testutils::env();
+    let s = setup(&e);
+    let payer = member(&e, &s, 1_000);
+    let first = member(&e, &s, 0);
+    let second = member(&e, &s, 0);
+
+    let split_id = e.register(RevenueSplitContract, ());
+    let split = RevenueSplitContractClient::new(&e, &split_id);
+    split.init(&s.admin, &vec![
+        &e,
+        RecipientShare { destination: first.clone(), basis_points: 6000 },
+        RecipientShare { destination: second.clone(), basis_points: 4000 },
+    ]);
+
+    // The split holds the funds in between, so it has to be on the list too
+    assert!(split.try_distribute(&s.client.address, &payer, &1_000).is_err());
+    s.client.allow_user(&split_id, &s.compliance);
+    split.distribute(&s.client.address, &payer, &1_000);
+
+    assert_eq!(s.token.balance(&first), 600);
+    assert_eq!(s.token.balance(&second), 400);
+    assert_eq!(s.token.balance(&split_id), 0);
+}
+
+#[test]
+fn test_vesting_asset() {
+    let e = payd_testutils::env();
+    let s = setup(&e);
+    let funder = member(&e, &s, 1_000);
+    let employee = member(&e, &s, 0);
+
+    let grant_id = e.register(VestingContract, ());
+    s.client.allow_user(&grant_id, &s.compliance);
+    let grant = VestingContractClient::new(&e, &grant_id);
+    let terms = TrancheTerms {
+        start_time: 0,
+        cliff_seconds: 0,
+        duration_seconds: 100,
+        schedule: VestingSchedule::Linear,
+        cliff_mode: CliffMode::CatchUp,
+    };
+    grant.initialize(&funder, &employee, &s.client.address, &1_000, &s.admin, &terms, &DEFAULT_START_BOUNDS);
+
+    e.ledger().set_timestamp(40);
+    grant.claim();
+    assert_eq!(s.token.balance(&employee), 400);
+
+    // A frozen employee can't claim until compliance lifts the freeze
+    s.client.freeze(&employee, &s.compliance);
+    e.ledger().set_timestamp(60);
+    assert!(grant.try_claim().is_err());
+
+    // The issuer recovers what was already paid out
+    s.client.clawback(&employee, &400);
+    assert_eq!(s.token.balance(&employee), 0);
+    assert_eq!(s.token.balance(&grant_id), 600);
+}
@@ -0,0 +1,333 @@
+#![no_std]
+
+use payd_common::{list, storage};
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, token, Address, Env, IntoVal, Symbol, Val, Vec};
+
+#[cfg(test)]
+mod test;
+
+/// How many roster entries a single `run_payroll` call processes. Large rosters
+/// are paid over several calls so each one stays well inside the resource budget.
+pub const PAGE_SIZE: u32 = 10;
+
+/// `PAGE_SIZE` while payslips are on. A payment with its payslip touches about 20 ledger
+/// entries, and a transaction may touch at most 100.
+pub const PAYSLIP_PAGE_SIZE: u32 = 4;
+
+#[contracttype]
+pub enum DataKey {
+    Employer,
+    Genesis,
+    PeriodSeconds,
+    /// Every employee on the roster, as a `payd_common::list`.
+    Roster,
+    Entry(Address),
+    OpenRun,
+    Cursor(u32),
+    Completed(u32),
+    Paid(u32, Address),
+    Payslips,
+    Payslip(u32, Address),
+}
+
+/// One employee on the roster. They are paid `amount` of `asset` every
+/// `frequency` periods, starting with `start_period`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RosterEntry {
+    pub employee: Address,
+    pub asset: Address,
+    pub amount: i128,
+    pub frequency: u32,
+    pub start_period: u32,
+}
+
+/// Mirror of `payslip::Payslip`, passed to the payslip contract's `mint`.
+#[derive(Clone)]
+#[contracttype]
+pub struct Payslip {
+    pub issuer: Address,
+    pub payer: Address,
+    pub asset: Address,
+    pub period_start: u64,
+    pub period_end: u64,
+    pub gross: i128,
+    pub net: i128,
+}
+
+#[contract]
+pub struct PayrollContract;
+
+#[contractimpl]
+impl PayrollContract {
+    /// Initialize payroll for `employer`. Period 0 begins at `genesis` and every period lasts `period_seconds`.
+    pub fn init(e: Env, employer: Address, genesis: u64, period_seconds: u64) {
+        storage::guard_init(&e, &DataKey::Employer);
+        if period_seconds == 0 {
+            panic!("Period must be positive");
+        }
+
+        e.storage().instance().set(&DataKey::Employer, &employer);
+        e.storage().instance().set(&DataKey::Genesis, &genesis);
+        e.storage().instance().set(&DataKey::PeriodSeconds, &period_seconds);
+    }
+
+    /// Adds `amount` of `asset` to the treasury salaries are paid from. Anyone can fund it.
+    pub fn deposit(e: Env, from: Address, asset: Address, amount: i128) {
+        from.require_auth();
+
+        if amount <= 0 {
+            panic!("Amount must be positive");
+        }
+
+        let client = token::Client::new(&e, &asset);
+        client.transfer(&from, &e.current_contract_address(), &amount);
+
+        e.events().publish((symbol_short!("deposit"), from, asset), amount);
+    }
+
+    /// Takes funds back out of the treasury (employer only).
+    pub fn withdraw(e: Env, asset: Address, to: Address, amount: i128) {
+        Self::employer(&e).require_auth();
+
+        if amount <= 0 {
+            panic!("Amount must be positive");
+        }
+
+        let client = token::Client::new(&e, &asset);
+        client.transfer(&e.current_contract_address(), &to, &amount);
+
+        e.events().publish((symbol_short!("withdraw"), to, asset), amount);
+    }
+
+    /// Adds an employee to the roster, or replaces their pay terms if they are already on it (employer only).
+    /// The new terms are used by any run that has not reached this employee yet. `start_period` must
+    /// not be in the past, or the next runs of periods that were never run would pay it out, nor a
+    /// period whose run has already completed, which would never pay it.
+    pub fn set_employee(e: Env, entry: RosterEntry) {
+        Self::employer(&e).require_auth();
+
+        if entry.amount <= 0 {
+            panic!("Amount must be positive");
+        }
+        if entry.frequency == 0 {
+            panic!("Frequency must be positive");
+        }
+        if entry.start_period < Self::first_open_period(&e) {
+            panic!("Start period has passed");
+        }
+
+        list::insert(&e, &DataKey::Roster, &entry.employee);
+        let key = DataKey::Entry(entry.employee.clone());
+        e.storage().persistent().set(&key, &entry);
+        storage::extend_persistent(&e, &key);
+
+        e.events().publish((symbol_short!("emp_set"), entry.employee.clone()), entry);
+    }
+
+    /// Takes an employee off the roster (employer only). The last employee on the roster takes
+    /// their place, so this is not allowed while a run is part-way through.
+    pub fn remove_employee(e: Env, employee: Address) {
+        Self::employer(&e).require_auth();
+
+        if e.storage().instance().has(&DataKey::OpenRun) {
+            panic!("Payroll run in progress");
+        }
+
+        let key = DataKey::Entry(employee.clone());
+        if !e.storage().persistent().has(&key) {
+            panic!("Employee not found");
+        }
+        e.storage().persistent().remove(&key);
+        list::remove(&e, &DataKey::Roster, &employee);
+
+        e.events().publish((symbol_short!("emp_rm"), employee), ());
+    }
+
+    /// Pays everyone due for `period`. Anyone can call it once the period has begun.
+    ///
+    /// Each call handles at most `PAGE_SIZE` roster entries and picks up where the last
+    /// one stopped; it returns `true` once the whole roster has been processed. Every
+    /// payment is recorded per period and employee, so nobody is paid twice for a period,
+    /// and calling again after completion is a no-op. Only one period can be in progress
+    /// at a time.
+    ///
+    /// While payslips are on (see `set_payslips`), every payment also mints the employee a
+    /// payslip for it, and each call handles at most `PAYSLIP_PAGE_SIZE` entries. Gross
+    /// equals net on those payslips, since the roster has no deductions.
+    pub fn run_payroll(e: Env, period: u32) -> bool {
+        if period > Self::current_period(e.clone()) {
+            panic!("Period has not started");
+        }
+        if e.storage().persistent().has(&DataKey::Completed(period)) {
+            return true;
+        }
+
+        let open_run: Option<u32> = e.storage().instance().get(&DataKey::OpenRun);
+        if open_run.is_some_and(|open_period| open_period != period) {
+            panic!("Another payroll run in progress");
+        }
+
+        let payslips: Option<Address> = e.storage().instance().get(&DataKey::Payslips);
+        let page_size = if payslips.is_some() { PAYSLIP_PAGE_SIZE } else { PAGE_SIZE };
+
+        let roster_size = list::len(&e, &DataKey::Roster);
+        let cursor: u32 = e.storage().persistent().get(&DataKey::Cursor(period)).unwrap_or(0);
+        let end = roster_size.min(cursor + page_size);
+
+        for index in cursor..end {
+            let employee: Address = list::get(&e, &DataKey::Roster, index).expect("Employee not found");
+            let entry = Self::load_entry(&e, &employee);
+            let paid_key = DataKey::Paid(period, employee.clone());
+
+            if !Self::is_due(&entry, period) || e.storage().persistent().has(&paid_key) {
+                continue;
+            }
+
+            let client = token::Client::new(&e, &entry.asset);
+            if client.balance(&e.current_contract_address()) < entry.amount {
+                panic!("Insufficient treasury balance");
+            }
+
+            // Record the payment before making it
+            e.storage().persistent().set(&paid_key, &(entry.asset.clone(), entry.amount));
+            storage::extend_persistent(&e, &paid_key);
+            client.transfer(&e.current_contract_address(), &employee, &entry.amount);
+
+            e.events().publish((symbol_short!("paid"), period, employee), entry.amount);
+
+            if let Some(payslips) = &payslips {
+                Self::issue_payslip(&e, payslips, period, &entry);
+            }
+        }
+
+        if end < roster_size {
+            e.storage().persistent().set(&DataKey::Cursor(period), &end);
+            storage::extend_persistent(&e, &DataKey::Cursor(period));
+            e.storage().instance().set(&DataKey::OpenRun, &period);
+            storage::extend_instance(&e);
+            return false;
+        }
+
+        e.storage().persistent().remove(&DataKey::Cursor(period));
+        e.storage().persistent().set(&DataKey::Completed(period), &true);
+        storage::extend_persistent(&e, &DataKey::Completed(period));
+        e.storage().instance().remove(&DataKey::OpenRun);
+        storage::extend_instance(&e);
+
+        e.events().publish((symbol_short!("run_done"), period), roster_size);
+
+        true
+    }
+
+    /// Sets the payslip contract payments mint payslips from, or turns payslips off with `None`
+    /// (employer only). This contract must be an approved issuer there, or runs fail until
+    /// payslips are turned off again.
+    pub fn set_payslips(e: Env, payslips: Option<Address>) {
+        Self::employer(&e).require_auth();
+
+        match &payslips {
+            Some(payslips) => e.storage().instance().set(&DataKey::Payslips, payslips),
+            None => e.storage().instance().remove(&DataKey::Payslips),
+        }
+    }
+
+    /// The payslip id issued for `employee`'s `period` payment, if any.
+    pub fn get_payslip(e: Env, period: u32, employee: Address) -> Option<u32> {
+        e.storage().persistent().get(&DataKey::Payslip(period, employee))
+    }
+
+    /// The period the ledger is currently in.
+    pub fn current_period(e: Env) -> u32 {
+        let genesis: u64 = storage::read_instance(&e, &DataKey::Genesis);
+        let period_seconds: u64 = storage::read_instance(&e, &DataKey::PeriodSeconds);
+
+        let now = e.ledger().timestamp();
+        if now < genesis {
+            panic!("Payroll has not started");
+        }
+        ((now - genesis) / period_seconds) as u32
+    }
+
+    /// Whether `employee` has been paid for `period`.
+    pub fn is_paid(e: Env, period: u32, employee: Address) -> bool {
+        e.storage().persistent().has(&DataKey::Paid(period, employee))
+    }
+
+    pub fn is_completed(e: Env, period: u32) -> bool {
+        e.storage().persistent().has(&DataKey::Completed(period))
+    }
+
+    /// The period whose run is part-way through, if any.
+    pub fn get_open_run(e: Env) -> Option<u32> {
+        e.storage().instance().get(&DataKey::OpenRun)
+    }
+
+    pub fn get_employee(e: Env, employee: Address) -> Option<RosterEntry> {
+        e.storage().persistent().get(&DataKey::Entry(employee))
+    }
+
+    /// Up to `limit` employees on the roster, in the order runs visit them, starting at `start`.
+    pub fn get_roster(e: Env, start: u32, limit: u32) -> Vec<Address> {
+        list::page(&e, &DataKey::Roster, start, limit)
+    }
+
+    pub fn get_roster_size(e: Env) -> u32 {
+        list::len(&e, &DataKey::Roster)
+    }
+
+    fn employer(e: &Env) -> Address {
+        storage::read_instance(e, &DataKey::Employer)
+    }
+
+    /// Mints `entry.employee` a payslip for the `period` payment just made.
+    fn issue_payslip(e: &Env, payslips: &Address, period: u32, entry: &RosterEntry) {
+        let genesis: u64 = storage::read_instance(e, &DataKey::Genesis);
+        let period_seconds: u64 = storage::read_instance(e, &DataKey::PeriodSeconds);
+        let period_start = genesis + u64::from(period) * period_seconds;
+
+        let payslip = Payslip {
+            issuer: e.current_contract_address(),
+            payer: Self::employer(e),
+            asset: entry.asset.clone(),
+            period_start,
+            period_end: period_start + period_seconds,
+            gross: entry.amount,
+            net: entry.amount,
+        };
+        let args: Vec<Val> = (entry.employee.clone(), payslip).into_val(e);
+        let id: u32 = e.invoke_contract(payslips, &Symbol::new(e, "mint"), args);
+
+        // Recorded after the mint since the id comes from it; the payslip contract cannot call back in
+        let key = DataKey::Payslip(period, entry.employee.clone());
+        e.storage().persistent().set(&key, &id);
+        storage::extend_persistent(e, &key);
+
+        e.events().publish((symbol_short!("payslip"), period, entry.employee.clone()), id);
+    }
+
+    fn load_entry(e: &Env, employee: &Address) -> RosterEntry {
+        let key = DataKey::Entry(employee.clone());
+        let entry = e.storage().persistent().get(&key).expect("Employee not found");
+        storage::extend_persistent(e, &key);
+        entry
+    }
+
+    /// The earliest period a run can still pay: the current one, the one after it once the
+    /// current run has completed, or period 0 before payroll has started.
+    fn first_open_period(e: &Env) -> u32 {
+        let genesis: u64 = storage::read_instance(e, &DataKey::Genesis);
+        if e.ledger().timestamp() < genesis {
+            return 0;
+        }
+        let current = Self::current_period(e.clone());
+        if e.storage().persistent().has(&DataKey::Completed(current)) {
+            return current + 1;
+        }
+        current
+    }
+
+    fn is_due(entry: &RosterEntry, period: u32) -> bool {
+        period >= entry.start_period && (period - entry.start_period).is_multiple_of(entry.frequency)
+    }
+}
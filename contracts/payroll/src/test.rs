@@ -0,0 +1,275 @@
+#![cfg(test)]
+
+use super::*;
+use payd_testutils::Asset;
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Env, token};
+
+const WEEK: u64 = 7 * 24 * 60 * 60;
+
+fn setup<'a>(e: &Env) -> (PayrollContractClient<'a>, Address, Address) {
+    let employer = Address::generate(e);
+    let client = PayrollContractClient::new(e, &e.register(PayrollContract, ()));
+    client.init(&employer, &0, &WEEK);
+
+    (client, employer, Asset::new(e).address)
+}
+
+fn fund(e: &Env, client: &PayrollContractClient, employer: &Address, asset: &Address, amount: i128) {
+    token::StellarAssetClient::new(e, asset).mint(employer, &amount);
+    client.deposit(employer, asset, &amount);
+}
+
+fn entry(employee: &Address, asset: &Address, amount: i128, frequency: u32) -> RosterEntry {
+    RosterEntry {
+        employee: employee.clone(),
+        asset: asset.clone(),
+        amount,
+        frequency,
+        start_period: 0,
+    }
+}
+
+#[test]
+fn test_run_payroll_pays_once_per_period() {
+    let e = payd_testutils::env();
+    let (client, employer, asset) = setup(&e);
+    fund(&e, &client, &employer, &asset, 10000);
+    let token_client = token::Client::new(&e, &asset);
+
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    client.set_employee(&entry(&alice, &asset, 1000, 1));
+    client.set_employee(&entry(&bob, &asset, 1500, 1));
+
+    assert!(client.run_payroll(&0));
+    assert_eq!(token_client.balance(&alice), 1000);
+    assert_eq!(token_client.balance(&bob), 1500);
+    assert!(client.is_paid(&0, &alice));
+    assert!(client.is_completed(&0));
+
+    // Running the same period again pays nobody
+    assert!(client.run_payroll(&0));
+    assert_eq!(token_client.balance(&alice), 1000);
+
+    // The next period can't run before it starts
+    assert!(client.try_run_payroll(&1).is_err());
+
+    e.ledger().set_timestamp(WEEK);
+    assert!(client.run_payroll(&1));
+    assert_eq!(token_client.balance(&alice), 2000);
+    assert_eq!(token_client.balance(&bob), 3000);
+}
+
+#[test]
+fn test_frequency_and_start_period() {
+    let e = payd_testutils::env();
+    let (client, employer, asset) = setup(&e);
+    fund(&e, &client, &employer, &asset, 10000);
+    let token_client = token::Client::new(&e, &asset);
+
+    let weekly = Address::generate(&e);
+    let biweekly = Address::generate(&e);
+    let late_joiner = Address::generate(&e);
+    client.set_employee(&entry(&weekly, &asset, 100, 1));
+    client.set_employee(&entry(&biweekly, &asset, 200, 2));
+    client.set_employee(&RosterEntry { start_period: 2, ..entry(&late_joiner, &asset, 50, 1) });
+
+    e.ledger().set_timestamp(4 * WEEK);
+    for period in 0..4 {
+        client.run_payroll(&period);
+    }
+
+    assert_eq!(token_client.balance(&weekly), 400);
+    assert_eq!(token_client.balance(&biweekly), 400);
+    assert_eq!(token_client.balance(&late_joiner), 100);
+    assert!(!client.is_paid(&1, &biweekly));
+}
+
+#[test]
+fn test_large_roster_is_paginated() {
+    let e = payd_testutils::env();
+    let (client, employer, asset) = setup(&e);
+    fund(&e, &client, &employer, &asset, 100000);
+    let token_client = token::Client::new(&e, &asset);
+
+    let roster_size = PAGE_SIZE * 2 + 5;
+    for _ in 0..roster_size {
+        client.set_employee(&entry(&Address::generate(&e), &asset, 10, 1));
+    }
+    let roster = client.get_roster(&0, &roster_size);
+    assert_eq!(roster.len(), roster_size);
+
+    assert!(!client.run_payroll(&0));
+    assert_eq!(client.get_open_run(), Some(0));
+    assert!(client.is_paid(&0, &roster.get(PAGE_SIZE - 1).unwrap()));
+    assert!(!client.is_paid(&0, &roster.get(PAGE_SIZE).unwrap()));
+
+    // Roster changes that would shift the cursor wait until the run is done
+    assert!(client.try_remove_employee(&roster.get(0).unwrap()).is_err());
+
+    assert!(!client.run_payroll(&0));
+    assert!(client.run_payroll(&0));
+    assert_eq!(client.get_open_run(), None);
+
+    for employee in roster.iter() {
+        assert_eq!(token_client.balance(&employee), 10);
+    }
+    assert_eq!(token_client.balance(&client.address), 100000 - 10 * roster_size as i128);
+}
+
+#[test]
+fn test_one_open_run_at_a_time() {
+    let e = payd_testutils::env();
+    let (client, employer, asset) = setup(&e);
+    fund(&e, &client, &employer, &asset, 100000);
+
+    for _ in 0..PAGE_SIZE + 1 {
+        client.set_employee(&entry(&Address::generate(&e), &asset, 10, 1));
+    }
+
+    e.ledger().set_timestamp(WEEK);
+    assert!(!client.run_payroll(&0));
+    assert!(client.try_run_payroll(&1).is_err());
+
+    assert!(client.run_payroll(&0));
+    assert!(!client.run_payroll(&1));
+}
+
+#[test]
+fn test_insufficient_treasury_rolls_back_page() {
+    let e = payd_testutils::env();
+    let (client, employer, asset) = setup(&e);
+    fund(&e, &client, &employer, &asset, 1500);
+
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    client.set_employee(&entry(&alice, &asset, 1000, 1));
+    client.set_employee(&entry(&bob, &asset, 1000, 1));
+
+    assert!(client.try_run_payroll(&0).is_err());
+    assert!(!client.is_paid(&0, &alice));
+
+    // Once topped up, the same period runs normally
+    fund(&e, &client, &employer, &asset, 500);
+    assert!(client.run_payroll(&0));
+    assert!(client.is_paid(&0, &bob));
+}
+
+#[test]
+fn test_remove_employee() {
+    let e = payd_testutils::env();
+    let (client, employer, asset) = setup(&e);
+    fund(&e, &client, &employer, &asset, 1000);
+
+    let alice = Address::generate(&e);
+    client.set_employee(&entry(&alice, &asset, 1000, 1));
+    client.remove_employee(&alice);
+
+    assert_eq!(client.get_roster_size(), 0);
+    assert!(client.get_employee(&alice).is_none());
+    assert!(client.run_payroll(&0));
+    assert!(!client.is_paid(&0, &alice));
+}
+
+#[test]
+fn test_set_employee_updates_terms() {
+    let e = payd_testutils::env();
+    let (client, employer, asset) = setup(&e);
+    fund(&e, &client, &employer, &asset, 1000);
+
+    let alice = Address::generate(&e);
+    client.set_employee(&entry(&alice, &asset, 100, 1));
+    client.set_employee(&entry(&alice, &asset, 300, 1));
+
+    assert_eq!(client.get_roster_size(), 1);
+    client.run_payroll(&0);
+    assert_eq!(token::Client::new(&e, &asset).balance(&alice), 300);
+}
+
+#[test]
+fn test_start_period_in_past() {
+    let e = payd_testutils::env();
+    let (client, employer, asset) = setup(&e);
+    fund(&e, &client, &employer, &asset, 10000);
+
+    // Periods 0-2 were never run; a new hire backdated to period 0 must not collect them
+    e.ledger().set_timestamp(3 * WEEK);
+    let alice = Address::generate(&e);
+    assert!(client.try_set_employee(&entry(&alice, &asset, 100, 1)).is_err());
+
+    client.set_employee(&RosterEntry { start_period: 3, ..entry(&alice, &asset, 100, 1) });
+    for period in 0..4 {
+        client.run_payroll(&period);
+    }
+    assert_eq!(token::Client::new(&e, &asset).balance(&alice), 100);
+}
+
+#[test]
+fn test_start_period_already_run() {
+    let e = payd_testutils::env();
+    let (client, employer, asset) = setup(&e);
+    fund(&e, &client, &employer, &asset, 10000);
+
+    // Period 1 has already been paid out, so a hire starting in it would never be paid
+    e.ledger().set_timestamp(WEEK);
+    assert!(client.run_payroll(&1));
+    let alice = Address::generate(&e);
+    assert!(client.try_set_employee(&RosterEntry { start_period: 1, ..entry(&alice, &asset, 100, 1) }).is_err());
+
+    client.set_employee(&RosterEntry { start_period: 2, ..entry(&alice, &asset, 100, 1) });
+    e.ledger().set_timestamp(2 * WEEK);
+    assert!(client.run_payroll(&2));
+    assert_eq!(token::Client::new(&e, &asset).balance(&alice), 100);
+}
+
+#[test]
+fn test_remove_employee_moves_last_into_place() {
+    let e = payd_testutils::env();
+    let (client, _, asset) = setup(&e);
+
+    let employees = [Address::generate(&e), Address::generate(&e), Address::generate(&e)];
+    for employee in &employees {
+        client.set_employee(&entry(employee, &asset, 10, 1));
+    }
+    client.remove_employee(&employees[0]);
+
+    assert_eq!(client.get_roster(&0, &10), soroban_sdk::vec![&e, employees[2].clone(), employees[1].clone()]);
+    assert_eq!(client.get_roster(&1, &10), soroban_sdk::vec![&e, employees[1].clone()]);
+}
+
+#[test]
+#[should_panic(expected = "Frequency must be positive")]
+fn test_zero_frequency() {
+    let e = payd_testutils::env();
+    let (client, _, asset) = setup(&e);
+    client.set_employee(&entry(&Address::generate(&e), &asset, 100, 0));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn test_init_twice() {
+    let e = payd_testutils::env();
+    let (client, employer, _) = setup(&e);
+    client.init(&employer, &0, &WEEK);
+}
+
+#[test]
+fn test_payslips_are_off_by_default() {
+    let e = payd_testutils::env();
+    let (client, employer, asset) = setup(&e);
+    fund(&e, &client, &employer, &asset, 1000);
+
+    let alice = Address::generate(&e);
+    client.set_employee(&entry(&alice, &asset, 100, 1));
+
+    client.run_payroll(&0);
+    assert!(client.is_paid(&0, &alice));
+    assert_eq!(client.get_payslip(&0, &alice), None);
+
+    // A run fails while the payslip contract does not take this payroll's payslips
+    e.ledger().set_timestamp(WEEK);
+    client.set_payslips(&Some(Address::generate(&e)));
+    assert!(client.try_run_payroll(&1).is_err());
+    client.set_payslips(&None);
+    client.run_payroll(&1);
+}
@@ -0,0 +1,152 @@
+#![no_std]
+
+//! Soulbound payslips: one non-transferable NFT per salary payment, minted to the worker by
+//! an approved issuer (e.g. a `PayrollContract` as it pays), so lenders can verify income
+//! on-chain.
+
+use payd_common::{admin, list, storage};
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env, String, Symbol, Vec};
+use stellar_tokens::non_fungible::{Base, NonFungibleToken};
+
+#[cfg(test)]
+mod test;
+
+const ISSUER_ROLE: Symbol = symbol_short!("issuer");
+
+#[contracttype]
+pub enum DataKey {
+    Payslip(u32),
+    /// Every payslip id a worker holds, oldest first, as a `payd_common::list`.
+    Holder(Address),
+}
+
+/// What a payslip attests. `gross` is the salary before deductions and `net` what was
+/// actually paid, both in `asset`, for the pay period `[period_start, period_end)`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Payslip {
+    pub issuer: Address,
+    pub payer: Address,
+    pub asset: Address,
+    pub period_start: u64,
+    pub period_end: u64,
+    pub gross: i128,
+    pub net: i128,
+}
+
+#[contract]
+pub struct PayslipContract;
+
+#[contractimpl]
+impl PayslipContract {
+    /// Initialize with an admin who approves issuers.
+    pub fn init(e: &Env, admin: Address) {
+        admin::init_admin(e, &admin);
+        Base::set_metadata(
+            e,
+            String::from_str(e, "https://payd.app/payslips/"),
+            String::from_str(e, "PayD Payslip"),
+            String::from_str(e, "PAYSLIP"),
+        );
+    }
+
+    /// Approves `issuer` to mint payslips, or withdraws the approval (admin only).
+    pub fn set_issuer(e: &Env, issuer: Address, approved: bool) {
+        admin::require_admin(e);
+        admin::set_role(e, &issuer, approved.then_some(ISSUER_ROLE));
+
+        e.events().publish((symbol_short!("issuer"), issuer), approved);
+    }
+
+    pub fn is_issuer(e: &Env, issuer: Address) -> bool {
+        admin::get_role(e, &issuer) == Some(ISSUER_ROLE)
+    }
+
+    /// Mints a payslip to `to` and returns its id (approved issuers only, as `payslip.issuer`).
+    pub fn mint(e: &Env, to: Address, payslip: Payslip) -> u32 {
+        admin::require_role(e, &payslip.issuer, &ISSUER_ROLE);
+
+        if payslip.period_end <= payslip.period_start {
+            panic!("Period must end after it starts");
+        }
+        if payslip.net <= 0 || payslip.net > payslip.gross {
+            panic!("Net must be positive and at most gross");
+        }
+
+        let id = Base::sequential_mint(e, &to);
+        let key = DataKey::Payslip(id);
+        e.storage().persistent().set(&key, &payslip);
+        storage::extend_persistent(e, &key);
+        list::push(e, &DataKey::Holder(to.clone()), &id);
+
+        e.events().publish((symbol_short!("payslip"), to, payslip.payer), (id, payslip.asset, payslip.net));
+
+        id
+    }
+
+    pub fn get_payslip(e: &Env, id: u32) -> Option<Payslip> {
+        let key = DataKey::Payslip(id);
+        let payslip = e.storage().persistent().get(&key);
+        if payslip.is_some() {
+            storage::extend_persistent(e, &key);
+        }
+        payslip
+    }
+
+    /// How many payslips `holder` has been issued.
+    pub fn payslip_count(e: &Env, holder: Address) -> u32 {
+        list::len(e, &DataKey::Holder(holder))
+    }
+
+    /// Of `holder`'s payslips `start` to `start + limit` (oldest first, at most
+    /// `list::MAX_PAGE_SIZE`), those for pay periods that fall entirely within `[from, to)`.
+    /// Page through `payslip_count` of them to see every one.
+    pub fn payslips(e: &Env, holder: Address, from: u64, to: u64, start: u32, limit: u32) -> Vec<Payslip> {
+        let ids: Vec<u32> = list::page(e, &DataKey::Holder(holder), start, limit);
+
+        let mut payslips = Vec::new(e);
+        for id in ids.iter() {
+            let payslip = Self::get_payslip(e, id).expect("Payslip not found");
+            if payslip.period_start >= from && payslip.period_end <= to {
+                payslips.push_back(payslip);
+            }
+        }
+        payslips
+    }
+
+    /// Total net pay in `asset` on the same page of `holder`'s payslips as `payslips`, counting
+    /// only payslips from currently approved issuers. Summing every page gives the income for
+    /// pay periods within `[from, to)`.
+    pub fn income(e: &Env, holder: Address, asset: Address, from: u64, to: u64, start: u32, limit: u32) -> i128 {
+        let mut total: i128 = 0;
+        for payslip in Self::payslips(e, holder, from, to, start, limit).iter() {
+            if payslip.asset == asset && Self::is_issuer(e, payslip.issuer.clone()) {
+                total = total.checked_add(payslip.net).expect("Income overflows");
+            }
+        }
+        total
+    }
+}
+
+/// Payslips are bound to the worker they were issued to: every way of moving or
+/// delegating one is rejected.
+#[contractimpl(contracttrait)]
+impl NonFungibleToken for PayslipContract {
+    type ContractType = Base;
+
+    fn transfer(_e: &Env, _from: Address, _to: Address, _token_id: u32) {
+        panic!("Payslips are non-transferable");
+    }
+
+    fn transfer_from(_e: &Env, _spender: Address, _from: Address, _to: Address, _token_id: u32) {
+        panic!("Payslips are non-transferable");
+    }
+
+    fn approve(_e: &Env, _approver: Address, _approved: Address, _token_id: u32, _live_until_ledger: u32) {
+        panic!("Payslips are non-transferable");
+    }
+
+    fn approve_for_all(_e: &Env, _owner: Address, _operator: Address, _live_until_ledger: u32) {
+        panic!("Payslips are non-transferable");
+    }
+}
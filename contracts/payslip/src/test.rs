@@ -0,0 +1,195 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use payd_testutils::Asset;
+use payroll::{PayrollContract, PayrollContractClient, RosterEntry};
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Env};
+
+const MONTH: u64 = 30 * 24 * 60 * 60;
+
+fn setup<'a>(e: &Env) -> (PayslipContractClient<'a>, Address, Address) {
+    let [admin, issuer] = payd_testutils::accounts(e);
+    let client = PayslipContractClient::new(e, &e.register(PayslipContract, ()));
+    client.init(&admin);
+    client.set_issuer(&issuer, &true);
+
+    (client, admin, issuer)
+}
+
+/// A funded monthly payroll that mints its payslips through `payslips`. Returns it with its
+/// employer and asset.
+fn payroll_with_payslips<'a>(e: &Env, payslips: &PayslipContractClient) -> (PayrollContractClient<'a>, Address, Address) {
+    let employer = Address::generate(e);
+    let payroll = PayrollContractClient::new(e, &e.register(PayrollContract, ()));
+    payroll.init(&employer, &0, &MONTH);
+    payslips.set_issuer(&payroll.address, &true);
+    payroll.set_payslips(&Some(payslips.address.clone()));
+
+    let asset = Asset::funded(e, &employer, 100_000).address;
+    payroll.deposit(&employer, &asset, &100_000);
+
+    (payroll, employer, asset)
+}
+
+fn payslip(e: &Env, issuer: &Address, asset: &Address, month: u64, net: i128) -> Payslip {
+    Payslip {
+        issuer: issuer.clone(),
+        payer: Address::generate(e),
+        asset: asset.clone(),
+        period_start: month * MONTH,
+        period_end: (month + 1) * MONTH,
+        gross: net + net / 4,
+        net,
+    }
+}
+
+#[test]
+fn test_mint() {
+    let e = payd_testutils::env();
+    let (client, _, issuer) = setup(&e);
+    let worker = Address::generate(&e);
+    let asset = Address::generate(&e);
+
+    let first = payslip(&e, &issuer, &asset, 0, 4000);
+    assert_eq!(client.mint(&worker, &first), 0);
+    assert_eq!(client.mint(&worker, &payslip(&e, &issuer, &asset, 1, 4000)), 1);
+
+    assert_eq!(client.get_payslip(&0), Some(first));
+    assert_eq!(client.owner_of(&0), worker);
+    assert_eq!(client.balance(&worker), 2);
+    assert_eq!(client.get_payslip(&2), None);
+}
+
+#[test]
+fn test_non_transferable() {
+    let e = payd_testutils::env();
+    let (client, _, issuer) = setup(&e);
+    let worker = Address::generate(&e);
+    let lender = Address::generate(&e);
+
+    let id = client.mint(&worker, &payslip(&e, &issuer, &Address::generate(&e), 0, 4000));
+
+    assert!(client.try_transfer(&worker, &lender, &id).is_err());
+    assert!(client.try_transfer_from(&lender, &worker, &lender, &id).is_err());
+    assert!(client.try_approve(&worker, &lender, &id, &1000).is_err());
+    assert!(client.try_approve_for_all(&worker, &lender, &1000).is_err());
+    assert_eq!(client.owner_of(&id), worker);
+}
+
+#[test]
+fn test_only_approved_issuers() {
+    let e = payd_testutils::env();
+    let (client, _, issuer) = setup(&e);
+    let worker = Address::generate(&e);
+    let asset = Address::generate(&e);
+
+    let forged = payslip(&e, &Address::generate(&e), &asset, 0, 1_000_000);
+    assert!(client.try_mint(&worker, &forged).is_err());
+
+    client.set_issuer(&issuer, &false);
+    assert!(!client.is_issuer(&issuer));
+    assert!(client.try_mint(&worker, &payslip(&e, &issuer, &asset, 0, 4000)).is_err());
+}
+
+#[test]
+fn test_invalid_payslips() {
+    let e = payd_testutils::env();
+    let (client, _, issuer) = setup(&e);
+    let worker = Address::generate(&e);
+    let asset = Address::generate(&e);
+
+    let mut backwards = payslip(&e, &issuer, &asset, 1, 4000);
+    backwards.period_end = backwards.period_start;
+    assert!(client.try_mint(&worker, &backwards).is_err());
+
+    let mut over_gross = payslip(&e, &issuer, &asset, 1, 4000);
+    over_gross.gross = 3999;
+    assert!(client.try_mint(&worker, &over_gross).is_err());
+
+    assert!(client.try_mint(&worker, &payslip(&e, &issuer, &asset, 1, 0)).is_err());
+}
+
+#[test]
+fn test_income_over_range() {
+    let e = payd_testutils::env();
+    let (client, _, issuer) = setup(&e);
+    let worker = Address::generate(&e);
+    let usdc = Address::generate(&e);
+    let eurc = Address::generate(&e);
+
+    for month in 0..6 {
+        client.mint(&worker, &payslip(&e, &issuer, &usdc, month, 4000 + month as i128 * 100));
+    }
+    client.mint(&worker, &payslip(&e, &issuer, &eurc, 2, 500));
+
+    // Months 2 to 4; the range must contain the whole pay period
+    assert_eq!(client.payslips(&worker, &(2 * MONTH), &(5 * MONTH), &0, &50).len(), 4);
+    assert_eq!(client.income(&worker, &usdc, &(2 * MONTH), &(5 * MONTH), &0, &50), 4200 + 4300 + 4400);
+    assert_eq!(client.income(&worker, &usdc, &(2 * MONTH), &(5 * MONTH - 1), &0, &50), 4200 + 4300);
+    assert_eq!(client.income(&worker, &eurc, &0, &(6 * MONTH), &0, &50), 500);
+    assert_eq!(client.income(&Address::generate(&e), &usdc, &0, &(6 * MONTH), &0, &50), 0);
+
+    // Lenders page through a long history
+    assert_eq!(client.payslip_count(&worker), 7);
+    let pages = [0, 4].map(|start| client.income(&worker, &usdc, &0, &(6 * MONTH), &start, &4));
+    assert_eq!(pages, [4000 + 4100 + 4200 + 4300, 4400 + 4500]);
+
+    // A revoked issuer's payslips stop counting
+    client.set_issuer(&issuer, &false);
+    assert_eq!(client.income(&worker, &usdc, &0, &(6 * MONTH), &0, &50), 0);
+}
+
+#[test]
+fn test_payroll_issues_payslips() {
+    let e = payd_testutils::env();
+    let (client, _, _) = setup(&e);
+
+    let (payroll, employer, asset) = payroll_with_payslips(&e, &client);
+
+    let alice = Address::generate(&e);
+    payroll.set_employee(&RosterEntry { employee: alice.clone(), asset: asset.clone(), amount: 3000, frequency: 1, start_period: 0 });
+
+    // Every payment comes with its payslip, and paying again for a period issues none
+    for period in 0..3u32 {
+        e.ledger().set_timestamp(u64::from(period) * MONTH);
+        payroll.run_payroll(&period);
+    }
+    payroll.run_payroll(&2);
+    assert_eq!(client.payslip_count(&alice), 3);
+
+    let id = payroll.get_payslip(&1, &alice).unwrap();
+    assert_eq!(client.owner_of(&id), alice);
+    assert_eq!(client.get_payslip(&id), Some(Payslip {
+        issuer: payroll.address.clone(),
+        payer: employer,
+        asset: asset.clone(),
+        period_start: MONTH,
+        period_end: 2 * MONTH,
+        gross: 3000,
+        net: 3000,
+    }));
+    assert_eq!(client.income(&alice, &asset, &0, &(3 * MONTH), &0, &50), 9000);
+}
+
+#[test]
+fn test_payslip_pages_fit_a_transaction() {
+    let e = payd_testutils::env();
+    let (client, _, _) = setup(&e);
+
+    let (payroll, _, asset) = payroll_with_payslips(&e, &client);
+    let mut employees = std::vec::Vec::new();
+    for _ in 0..payroll::PAYSLIP_PAGE_SIZE * 2 + 1 {
+        let employee = Address::generate(&e);
+        payroll.set_employee(&RosterEntry { employee: employee.clone(), asset: asset.clone(), amount: 3000, frequency: 1, start_period: 0 });
+        employees.push(employee);
+    }
+
+    // Each page stays inside the transaction limits the test environment enforces
+    assert!(!payroll.run_payroll(&0));
+    assert!(!payroll.run_payroll(&0));
+    assert!(payroll.run_payroll(&0));
+    for employee in &employees {
+        assert_eq!(client.payslip_count(employee), 1);
+    }
+}
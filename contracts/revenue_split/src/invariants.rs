@@ -0,0 +1,85 @@
+//! Property tests over randomized share vectors and amounts.
+
+#![cfg(test)]
+extern crate std;
+
+use crate::{RecipientShare, RevenueSplitContract, RevenueSplitContractClient, TOTAL_BASIS_POINTS};
+use proptest::prelude::*;
+use soroban_sdk::{testutils::Address as _, token, Address, Env, Vec};
+use std::vec::Vec as StdVec;
+
+/// Basis points for 1 to 20 recipients summing to 10000, zero shares included.
+fn valid_shares() -> impl Strategy<Value = StdVec<u32>> {
+    prop::collection::vec(0..=TOTAL_BASIS_POINTS, 0..20).prop_map(|mut cuts| {
+        cuts.sort_unstable();
+        cuts.push(TOTAL_BASIS_POINTS);
+        let mut previous = 0;
+        cuts.into_iter()
+            .map(|cut| {
+                let share = cut - previous;
+                previous = cut;
+                share
+            })
+            .collect()
+    })
+}
+
+fn shares(e: &Env, basis_points: &[u32]) -> (Vec<RecipientShare>, StdVec<Address>) {
+    let destinations: StdVec<Address> = basis_points.iter().map(|_| Address::generate(e)).collect();
+    let mut shares = Vec::new(e);
+    for (destination, basis_points) in destinations.iter().zip(basis_points) {
+        shares.push_back(RecipientShare { destination: destination.clone(), basis_points: *basis_points });
+    }
+    (shares, destinations)
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn distribute_moves_exactly_amount(basis_points in valid_shares(), amount in 0i128..=1_000_000_000_000_000) {
+        let e = Env::default();
+        e.mock_all_auths();
+        e.cost_estimate().budget().reset_unlimited();
+
+        let (share_list, destinations) = shares(&e, &basis_points);
+        let contract_id = e.register(RevenueSplitContract, ());
+        let client = RevenueSplitContractClient::new(&e, &contract_id);
+        client.init(&Address::generate(&e), &share_list);
+
+        let token_id = e.register_stellar_asset_contract_v2(Address::generate(&e)).address();
+        let token = token::Client::new(&e, &token_id);
+        let sender = Address::generate(&e);
+        token::StellarAssetClient::new(&e, &token_id).mint(&sender, &amount);
+
+        client.distribute(&token_id, &sender, &amount);
+
+        prop_assert_eq!(token.balance(&sender), 0);
+        prop_assert_eq!(token.balance(&contract_id), 0);
+
+        let received: StdVec<i128> = destinations.iter().map(|d| token.balance(d)).collect();
+        prop_assert_eq!(received.iter().sum::<i128>(), amount);
+
+        // Everyone gets at least their rounded-down share; only the last recipient gets the dust
+        let last = received.len() - 1;
+        for (i, (got, bp)) in received.iter().zip(&basis_points).enumerate() {
+            let floor = amount * *bp as i128 / TOTAL_BASIS_POINTS as i128;
+            if i == last {
+                prop_assert!(*got >= floor);
+            } else {
+                prop_assert_eq!(*got, floor);
+            }
+        }
+    }
+
+    #[test]
+    fn shares_must_sum_to_total(basis_points in prop::collection::vec(0..=TOTAL_BASIS_POINTS, 1..10)) {
+        prop_assume!(basis_points.iter().sum::<u32>() != TOTAL_BASIS_POINTS);
+
+        let e = Env::default();
+        let (share_list, _) = shares(&e, &basis_points);
+        let client = RevenueSplitContractClient::new(&e, &e.register(RevenueSplitContract, ()));
+
+        prop_assert!(client.try_init(&Address::generate(&e), &share_list).is_err());
+    }
+}
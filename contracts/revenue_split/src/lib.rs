@@ -1,24 +1,31 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Vec, token};
+use payd_common::{admin, council, math, storage};
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, BytesN, Env, Vec, token};
 
 #[cfg(test)]
 mod test;
+#[cfg(test)]
+mod invariants;
 
 #[contracttype]
 pub enum DataKey {
-    Admin,
     Recipients,
 }
 
-#[derive(Clone)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
 pub struct RecipientShare {
     pub destination: Address,
     pub basis_points: u32,
 }
 
-pub const TOTAL_BASIS_POINTS: u32 = 10000; // 100%
+pub use payd_common::math::TOTAL_BASIS_POINTS;
+
+/// The most recipients a split takes. `distribute` pays each with a token transfer that writes
+/// its balance, and a transaction may write at most 50 ledger entries; 40 leaves room for the
+/// vault or a grant routing funds into the split (see `payd-budget`).
+pub const MAX_RECIPIENTS: u32 = 40;
 
 #[contract]
 pub struct RevenueSplitContract;
@@ -27,73 +34,99 @@ pub struct RevenueSplitContract;
 impl RevenueSplitContract {
     /// Initialize the contract with an admin and an initial set of recipients/shares.
     pub fn init(env: Env, admin: Address, shares: Vec<RecipientShare>) {
-        if env.storage().instance().has(&DataKey::Admin) {
-            panic!("Already initialized");
-        }
-        
-        let mut total_bp = 0;
-        for share in shares.iter() {
-            total_bp += share.basis_points;
-        }
-        
-        if total_bp != TOTAL_BASIS_POINTS {
-            panic!("Shares must sum to 10000 basis points");
-        }
+        admin::init_admin(&env, &admin);
+        Self::check_shares(&shares);
 
-        env.storage().instance().set(&DataKey::Admin, &admin);
         env.storage().instance().set(&DataKey::Recipients, &shares);
+
+        env.events().publish((symbol_short!("shares"),), shares);
     }
 
     /// Allows the current admin to set a new admin.
     pub fn set_admin(env: Env, new_admin: Address) {
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).expect("Not initialized");
-        admin.require_auth();
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        admin::set_admin(&env, &new_admin);
     }
 
-    /// Updates the recipient splits dynamically (admin only).
-    pub fn update_recipients(env: Env, new_shares: Vec<RecipientShare>) {
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).expect("Not initialized");
-        admin.require_auth();
+    /// Hands recipient changes to `council`, usually a `multisig` (admin only until a council
+    /// is set, then the council only).
+    pub fn set_council(env: Env, council: Address) {
+        council::set_council(&env, &council);
 
-        let mut total_bp = 0;
-        for share in new_shares.iter() {
-            total_bp += share.basis_points;
-        }
-        
-        if total_bp != TOTAL_BASIS_POINTS {
-            panic!("Shares must sum to 10000 basis points");
-        }
+        env.events().publish((symbol_short!("council"),), council);
+    }
+
+    /// Updates the recipient splits dynamically (admin only, or council only once one is set).
+    pub fn update_recipients(env: Env, new_shares: Vec<RecipientShare>) {
+        council::require_owner(&env);
+        Self::check_shares(&new_shares);
 
         env.storage().instance().set(&DataKey::Recipients, &new_shares);
+
+        env.events().publish((symbol_short!("shares"),), new_shares);
+    }
+
+    /// Replaces this split's code with the already uploaded `new_wasm_hash`, keeping its
+    /// recipients and admin. Admin only, or council only once one is set, since new code could
+    /// change the recipients.
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        council::require_owner(&env);
+        env.deployer().update_current_contract_wasm(new_wasm_hash.clone());
+
+        env.events().publish((symbol_short!("upgraded"),), new_wasm_hash);
     }
 
     /// Distributes a specific token amount from a sender to the listed recipients based on their shares.
+    /// The full amount is pulled from the sender in one transfer first, so a contract sender
+    /// (e.g. the treasury vault) only has to authorize a single transfer.
     pub fn distribute(env: Env, token: Address, from: Address, amount: i128) {
         from.require_auth();
         
-        let shares: Vec<RecipientShare> = env.storage().instance().get(&DataKey::Recipients).expect("Not initialized");
+        let shares: Vec<RecipientShare> = storage::read_instance(&env, &DataKey::Recipients);
         let client = token::Client::new(&env, &token);
+        let this = env.current_contract_address();
+
+        client.transfer(&from, &this, &amount);
+        storage::extend_instance(&env);
 
         let mut amount_distributed = 0;
 
         for (i, share) in shares.iter().enumerate() {
             // Calculate slice of the total amount using basis points
             // Formula: amount * basis_points / 10000
-            let recipient_amount = (amount as i128 * share.basis_points as i128) / TOTAL_BASIS_POINTS as i128;
+            let recipient_amount = math::bps(&env, amount, share.basis_points);
             
-            if recipient_amount > 0 {
-                // To avoid precision loss dust, the last recipient takes any minor remainders.
-                if i as u32 == shares.len() - 1 {
-                    let final_amount = amount - amount_distributed;
-                    if final_amount > 0 {
-                        client.transfer(&from, &share.destination, &final_amount);
-                    }
-                } else {
-                    client.transfer(&from, &share.destination, &recipient_amount);
-                    amount_distributed += recipient_amount;
+            // To avoid precision loss dust, the last recipient takes any minor remainders.
+            // Everything was pulled in up front, so the remainder must not stay behind here.
+            if i as u32 == shares.len() - 1 {
+                let final_amount = amount - amount_distributed;
+                if final_amount > 0 {
+                    client.transfer(&this, &share.destination, &final_amount);
                 }
+            } else if recipient_amount > 0 {
+                client.transfer(&this, &share.destination, &recipient_amount);
+                amount_distributed += recipient_amount;
             }
         }
+
+        env.events().publish((symbol_short!("distrib"), token, from), amount);
+    }
+
+    pub fn get_council(env: Env) -> Option<Address> {
+        council::get_council(&env)
+    }
+
+    fn check_shares(shares: &Vec<RecipientShare>) {
+        if shares.len() > MAX_RECIPIENTS {
+            panic!("Too many recipients");
+        }
+
+        let mut total_bp = 0;
+        for share in shares.iter() {
+            total_bp += share.basis_points;
+        }
+        
+        if total_bp != TOTAL_BASIS_POINTS {
+            panic!("Shares must sum to 10000 basis points");
+        }
     }
 }
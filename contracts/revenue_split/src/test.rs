@@ -1,7 +1,7 @@
 #![cfg(test)]
 
-use crate::{RevenueSplitContract, RevenueSplitContractClient, RecipientShare};
-use soroban_sdk::{testutils::{Address as _}, Address, Env, Vec};
+use crate::{RevenueSplitContract, RevenueSplitContractClient, RecipientShare, MAX_RECIPIENTS};
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, Address, Env, IntoVal, Vec};
 use soroban_sdk::token::Client as TokenClient;
 use soroban_sdk::token::StellarAssetClient;
 
@@ -50,6 +50,26 @@ fn test_init_invalid_shares() {
     client.init(&admin, &shares);
 }
 
+#[test]
+fn test_recipient_cap() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = RevenueSplitContractClient::new(&env, &env.register(RevenueSplitContract, ()));
+    let shares = |count: u32| {
+        let mut shares = Vec::new(&env);
+        for i in 0..count {
+            let basis_points = if i == 0 { 10000 - (count - 1) * 100 } else { 100 };
+            shares.push_back(RecipientShare { destination: Address::generate(&env), basis_points });
+        }
+        shares
+    };
+
+    assert!(client.try_init(&Address::generate(&env), &shares(MAX_RECIPIENTS + 1)).is_err());
+    client.init(&Address::generate(&env), &shares(MAX_RECIPIENTS));
+    assert!(client.try_update_recipients(&shares(MAX_RECIPIENTS + 1)).is_err());
+    client.update_recipients(&shares(1));
+}
+
 #[test]
 fn test_distribution() {
     let env = Env::default();
@@ -117,3 +137,38 @@ fn test_update_recipients() {
 
     client.update_recipients(&new_shares);
 }
+
+#[test]
+fn test_council_owns_recipients() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(RevenueSplitContract, ());
+    let client = RevenueSplitContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let council = Address::generate(&env);
+    let shares = Vec::from_array(&env, [
+        RecipientShare { destination: Address::generate(&env), basis_points: 10000 },
+    ]);
+    client.init(&admin, &shares);
+    client.set_council(&council);
+    assert_eq!(client.get_council(), Some(council.clone()));
+
+    // The admin alone can no longer redirect the split
+    let attacker = Vec::from_array(&env, [
+        RecipientShare { destination: admin.clone(), basis_points: 10000 },
+    ]);
+    let update_signed_by = |signer: &Address| {
+        let invoke = MockAuthInvoke {
+            contract: &contract_id,
+            fn_name: "update_recipients",
+            args: (attacker.clone(),).into_val(&env),
+            sub_invokes: &[],
+        };
+        env.set_auths(&[]);
+        client.mock_auths(&[MockAuth { address: signer, invoke: &invoke }]).try_update_recipients(&attacker).is_ok()
+    };
+    assert!(!update_signed_by(&admin));
+    assert!(update_signed_by(&council));
+}
@@ -0,0 +1,79 @@
+#![no_std]
+
+use payd_common::{factory, list};
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, BytesN, Env, IntoVal, Symbol, Val, Vec};
+
+#[cfg(test)]
+mod test;
+
+#[contracttype]
+pub enum DataKey {
+    /// Splits deployed for an owner, oldest first, as a `payd_common::list`.
+    Splits(Address),
+}
+
+/// Mirror of `revenue_split::RecipientShare`. Contract types are encoded by
+/// field name, so this is passed straight through to the deployed split's `init`.
+#[derive(Clone)]
+#[contracttype]
+pub struct RecipientShare {
+    pub destination: Address,
+    pub basis_points: u32,
+}
+
+#[contract]
+pub struct RevenueSplitFactoryContract;
+
+#[contractimpl]
+impl RevenueSplitFactoryContract {
+    /// Initialize the factory with an admin and the uploaded `revenue_split` WASM hash.
+    pub fn init(env: Env, admin: Address, wasm_hash: BytesN<32>) {
+        factory::init(&env, &admin, &wasm_hash);
+    }
+
+    /// Replaces the template used for future deployments (admin only).
+    /// Splits that were already deployed keep running their original code.
+    pub fn set_wasm_hash(env: Env, new_wasm_hash: BytesN<32>) {
+        factory::set_wasm_hash(&env, &new_wasm_hash);
+    }
+
+    /// Deploys a new split owned by `owner` and initializes it with `shares` in the same call.
+    /// The salt is derived from the owner and their deployment count, so addresses are predictable.
+    pub fn deploy_split(env: Env, owner: Address, shares: Vec<RecipientShare>) -> Address {
+        owner.require_auth();
+
+        let key = DataKey::Splits(owner.clone());
+        let split = factory::deploy(&env, &owner, list::len(&env, &key));
+
+        // The owner becomes the admin of their split.
+        let init_args: Vec<Val> = (owner.clone(), shares).into_val(&env);
+        env.invoke_contract::<()>(&split, &Symbol::new(&env, "init"), init_args);
+
+        list::push(&env, &key, &split);
+
+        env.events().publish(
+            (symbol_short!("split_new"), owner),
+            split.clone(),
+        );
+
+        split
+    }
+
+    /// Returns the address the owner's `index`-th split is (or will be) deployed at.
+    pub fn split_address(env: Env, owner: Address, index: u32) -> Address {
+        factory::address(&env, &owner, index)
+    }
+
+    /// Get up to `limit` splits deployed for an owner, oldest first, starting at `start`.
+    pub fn get_splits(env: Env, owner: Address, start: u32, limit: u32) -> Vec<Address> {
+        list::page(&env, &DataKey::Splits(owner), start, limit)
+    }
+
+    pub fn get_split_count(env: Env, owner: Address) -> u32 {
+        list::len(&env, &DataKey::Splits(owner))
+    }
+
+    pub fn get_wasm_hash(env: Env) -> BytesN<32> {
+        factory::get_wasm_hash(&env)
+    }
+}
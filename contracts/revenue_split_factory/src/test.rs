@@ -0,0 +1,110 @@
+#![cfg(test)]
+
+use crate::{RecipientShare, RevenueSplitFactoryContract, RevenueSplitFactoryContractClient};
+use payd_testutils::Asset;
+use soroban_sdk::{testutils::{Address as _, BytesN as _}, Address, BytesN, Env, Vec};
+use revenue_split::RevenueSplitContractClient;
+
+fn setup<'a>(env: &Env) -> (RevenueSplitFactoryContractClient<'a>, Address) {
+    let contract_id = env.register(RevenueSplitFactoryContract, ());
+    let client = RevenueSplitFactoryContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let wasm_hash = env.deployer().upload_contract_wasm(payd_wasm::REVENUE_SPLIT);
+    client.init(&admin, &wasm_hash);
+
+    (client, admin)
+}
+
+fn shares(env: &Env, first: &Address, second: &Address) -> Vec<RecipientShare> {
+    Vec::from_array(env, [
+        RecipientShare { destination: first.clone(), basis_points: 7000 },
+        RecipientShare { destination: second.clone(), basis_points: 3000 },
+    ])
+}
+
+#[test]
+fn test_deploy_split_initializes_instance() {
+    let env = payd_testutils::env();
+
+    let (factory, _) = setup(&env);
+
+    let owner = Address::generate(&env);
+    let recipient1 = Address::generate(&env);
+    let recipient2 = Address::generate(&env);
+
+    let expected = factory.split_address(&owner, &0);
+    let split_id = factory.deploy_split(&owner, &shares(&env, &recipient1, &recipient2));
+    assert_eq!(split_id, expected);
+
+    // The deployed split is live and distributes according to the shares it was created with
+    let sender = Address::generate(&env);
+    let asset = Asset::funded(&env, &sender, 1000);
+
+    let split = RevenueSplitContractClient::new(&env, &split_id);
+    split.distribute(&asset.address, &sender, &1000);
+
+    assert_eq!(asset.balance(&recipient1), 700);
+    assert_eq!(asset.balance(&recipient2), 300);
+}
+
+#[test]
+fn test_registry_per_owner() {
+    let env = payd_testutils::env();
+
+    let (factory, _) = setup(&env);
+
+    let owner_a = Address::generate(&env);
+    let owner_b = Address::generate(&env);
+    let recipient1 = Address::generate(&env);
+    let recipient2 = Address::generate(&env);
+
+    let a1 = factory.deploy_split(&owner_a, &shares(&env, &recipient1, &recipient2));
+    let a2 = factory.deploy_split(&owner_a, &shares(&env, &recipient2, &recipient1));
+    let b1 = factory.deploy_split(&owner_b, &shares(&env, &recipient1, &recipient2));
+
+    assert_ne!(a1, a2);
+    assert_ne!(a1, b1);
+    assert_eq!(factory.get_splits(&owner_a, &0, &10), Vec::from_array(&env, [a1, a2.clone()]));
+    assert_eq!(factory.get_splits(&owner_a, &1, &10), Vec::from_array(&env, [a2.clone()]));
+    assert_eq!(factory.get_splits(&owner_b, &0, &10), Vec::from_array(&env, [b1]));
+    assert_eq!(factory.get_split_count(&owner_a), 2);
+    assert_eq!(factory.split_address(&owner_a, &1), a2);
+}
+
+#[test]
+fn test_deploy_split_rejects_invalid_shares() {
+    let env = payd_testutils::env();
+
+    let (factory, _) = setup(&env);
+
+    let owner = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let shares = Vec::from_array(&env, [
+        RecipientShare { destination: recipient, basis_points: 5000 },
+    ]);
+
+    // The split's `init` panics, which rolls back the deployment and leaves the registry empty
+    assert!(factory.try_deploy_split(&owner, &shares).is_err());
+    assert_eq!(factory.get_split_count(&owner), 0);
+}
+
+#[test]
+fn test_set_wasm_hash() {
+    let env = payd_testutils::env();
+
+    let (factory, _) = setup(&env);
+
+    let new_hash = BytesN::<32>::random(&env);
+    factory.set_wasm_hash(&new_hash);
+    assert_eq!(factory.get_wasm_hash(), new_hash);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn test_init_twice() {
+    let env = Env::default();
+    let (factory, admin) = setup(&env);
+
+    factory.init(&admin, &BytesN::<32>::random(&env));
+}
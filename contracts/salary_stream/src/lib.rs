@@ -0,0 +1,286 @@
+#![no_std]
+
+use payd_common::{list, storage};
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, token, Address, Env, Vec};
+
+#[cfg(test)]
+mod test;
+
+#[contracttype]
+pub enum DataKey {
+    Employer,
+    Token,
+    /// Everyone with a stream, as a `payd_common::list`.
+    Employees,
+    Stream(Address),
+    Liability,
+}
+
+/// A salary stream for one employee. Salary accrues every second at `rate_per_second`;
+/// `accrued` holds what was settled up to `last_update` and has not been withdrawn yet.
+#[derive(Clone)]
+#[contracttype]
+pub struct Stream {
+    pub rate_per_second: i128,
+    pub last_update: u64,
+    pub accrued: i128,
+    pub withdrawn: i128,
+    pub is_active: bool,
+}
+
+/// Running total of what every stream together owes, so it never has to be summed stream
+/// by stream: `settled` as of `last_update`, growing by `rate_per_second` (the sum of the
+/// active streams' rates) from then on.
+#[derive(Clone)]
+#[contracttype]
+pub struct Liability {
+    pub settled: i128,
+    pub rate_per_second: i128,
+    pub last_update: u64,
+}
+
+#[contract]
+pub struct SalaryStreamContract;
+
+#[contractimpl]
+impl SalaryStreamContract {
+    /// Initialize the contract with the employer and the token salaries are paid in.
+    pub fn init(e: Env, employer: Address, token: Address) {
+        storage::guard_init(&e, &DataKey::Employer);
+        e.storage().instance().set(&DataKey::Employer, &employer);
+        e.storage().instance().set(&DataKey::Token, &token);
+        let liability = Liability { settled: 0, rate_per_second: 0, last_update: e.ledger().timestamp() };
+        e.storage().instance().set(&DataKey::Liability, &liability);
+    }
+
+    /// Adds `amount` to the shared treasury every stream is paid from. Anyone can fund it.
+    pub fn deposit(e: Env, from: Address, amount: i128) {
+        from.require_auth();
+
+        if amount <= 0 {
+            panic!("Amount must be positive");
+        }
+
+        let token: Address = storage::read_instance(&e, &DataKey::Token);
+        let client = token::Client::new(&e, &token);
+        client.transfer(&from, &e.current_contract_address(), &amount);
+
+        e.events().publish((symbol_short!("deposit"), from), amount);
+    }
+
+    /// Starts paying `employee` `rate_per_second` from now on (employer only).
+    /// A cancelled stream can be reopened once its remaining balance has been withdrawn.
+    pub fn open_stream(e: Env, employee: Address, rate_per_second: i128) {
+        Self::employer(&e).require_auth();
+
+        if rate_per_second <= 0 {
+            panic!("Rate must be positive");
+        }
+
+        let key = DataKey::Stream(employee.clone());
+        if e.storage().persistent().has(&key) {
+            panic!("Stream already exists");
+        }
+
+        let stream = Stream {
+            rate_per_second,
+            last_update: e.ledger().timestamp(),
+            accrued: 0,
+            withdrawn: 0,
+            is_active: true,
+        };
+        Self::save_stream(&e, &employee, &stream);
+        list::insert(&e, &DataKey::Employees, &employee);
+        Self::update_liability(&e, rate_per_second, 0);
+
+        e.events().publish((symbol_short!("strm_new"), employee), rate_per_second);
+    }
+
+    /// Changes an employee's rate (employer only). Salary earned at the old rate is
+    /// settled first, so the change only applies from now on.
+    pub fn set_rate(e: Env, employee: Address, rate_per_second: i128) {
+        Self::employer(&e).require_auth();
+
+        if rate_per_second <= 0 {
+            panic!("Rate must be positive");
+        }
+
+        let mut stream = Self::load_stream(&e, &employee);
+        if !stream.is_active {
+            panic!("Stream cancelled");
+        }
+
+        Self::settle(&e, &mut stream);
+        Self::update_liability(&e, rate_per_second - stream.rate_per_second, 0);
+        stream.rate_per_second = rate_per_second;
+        Self::save_stream(&e, &employee, &stream);
+
+        e.events().publish((symbol_short!("rate_upd"), employee), rate_per_second);
+    }
+
+    /// Withdraws the employee's accrued salary and returns the amount paid.
+    /// If the treasury is short, what is available is paid and the rest stays owed.
+    pub fn withdraw(e: Env, employee: Address) -> i128 {
+        employee.require_auth();
+
+        let mut stream = Self::load_stream(&e, &employee);
+        Self::settle(&e, &mut stream);
+
+        Self::pay_out(&e, &employee, stream)
+    }
+
+    /// Stops the stream (employer only) and pays the employee everything earned up to now.
+    /// Returns the amount paid. Anything the treasury could not cover stays withdrawable.
+    pub fn cancel_stream(e: Env, employee: Address) -> i128 {
+        Self::employer(&e).require_auth();
+
+        let mut stream = Self::load_stream(&e, &employee);
+        if !stream.is_active {
+            panic!("Stream cancelled");
+        }
+
+        Self::settle(&e, &mut stream);
+        Self::update_liability(&e, -stream.rate_per_second, 0);
+        stream.is_active = false;
+
+        e.events().publish((symbol_short!("cancel"), employee.clone()), stream.accrued);
+
+        Self::pay_out(&e, &employee, stream)
+    }
+
+    /// Sends treasury funds that are not owed to any employee back out (employer only).
+    pub fn withdraw_treasury(e: Env, to: Address, amount: i128) {
+        let employer = Self::employer(&e);
+        employer.require_auth();
+
+        if amount <= 0 {
+            panic!("Amount must be positive");
+        }
+
+        let token: Address = storage::read_instance(&e, &DataKey::Token);
+        let client = token::Client::new(&e, &token);
+        let free = client.balance(&e.current_contract_address()) - Self::get_liability(e.clone());
+        if amount > free {
+            panic!("Amount exceeds unallocated treasury");
+        }
+
+        client.transfer(&e.current_contract_address(), &to, &amount);
+
+        e.events().publish((symbol_short!("treas_out"), to), amount);
+    }
+
+    /// Salary the employee has earned and not withdrawn yet, as of now.
+    pub fn get_accrued(e: Env, employee: Address) -> i128 {
+        let mut stream = Self::load_stream(&e, &employee);
+        Self::settle(&e, &mut stream);
+        stream.accrued
+    }
+
+    /// Total salary owed across all streams right now.
+    pub fn get_liability(e: Env) -> i128 {
+        let mut liability: Liability = storage::read_instance(&e, &DataKey::Liability);
+        Self::settle_liability(&e, &mut liability);
+        liability.settled
+    }
+
+    pub fn get_stream(e: Env, employee: Address) -> Option<Stream> {
+        e.storage().persistent().get(&DataKey::Stream(employee))
+    }
+
+    /// Up to `limit` employees with a stream, starting at `start`. Removing a stream moves
+    /// the last employee into its place.
+    pub fn get_employees(e: Env, start: u32, limit: u32) -> Vec<Address> {
+        list::page(&e, &DataKey::Employees, start, limit)
+    }
+
+    pub fn get_employee_count(e: Env) -> u32 {
+        list::len(&e, &DataKey::Employees)
+    }
+
+    fn employer(e: &Env) -> Address {
+        storage::read_instance(e, &DataKey::Employer)
+    }
+
+    fn load_stream(e: &Env, employee: &Address) -> Stream {
+        let key = DataKey::Stream(employee.clone());
+        let stream = e.storage().persistent().get(&key).expect("Stream not found");
+        storage::extend_persistent(e, &key);
+        stream
+    }
+
+    fn save_stream(e: &Env, employee: &Address, stream: &Stream) {
+        let key = DataKey::Stream(employee.clone());
+        e.storage().persistent().set(&key, stream);
+        storage::extend_persistent(e, &key);
+    }
+
+    /// Moves salary earned since `last_update` into `accrued`. Same per-second linear
+    /// release as `VestingContract::calc_vested`, without an end date.
+    fn settle(e: &Env, stream: &mut Stream) {
+        let now = e.ledger().timestamp();
+        if stream.is_active && now > stream.last_update {
+            let elapsed = (now - stream.last_update) as i128;
+            let earned = stream
+                .rate_per_second
+                .checked_mul(elapsed)
+                .expect("Stream overflow");
+            stream.accrued = stream.accrued.checked_add(earned).expect("Stream overflow");
+        }
+        stream.last_update = now;
+    }
+
+    /// Brings the running liability up to now, then adds `rate_change` to its rate and
+    /// takes `paid` off what is owed.
+    fn update_liability(e: &Env, rate_change: i128, paid: i128) {
+        let mut liability: Liability = storage::read_instance(e, &DataKey::Liability);
+        Self::settle_liability(e, &mut liability);
+        liability.rate_per_second = liability.rate_per_second.checked_add(rate_change).expect("Stream overflow");
+        liability.settled -= paid;
+        e.storage().instance().set(&DataKey::Liability, &liability);
+        storage::extend_instance(e);
+    }
+
+    /// `settle` for the sum of all streams.
+    fn settle_liability(e: &Env, liability: &mut Liability) {
+        let now = e.ledger().timestamp();
+        if now > liability.last_update {
+            let elapsed = (now - liability.last_update) as i128;
+            let earned = liability.rate_per_second.checked_mul(elapsed).expect("Stream overflow");
+            liability.settled = liability.settled.checked_add(earned).expect("Stream overflow");
+        }
+        liability.last_update = now;
+    }
+
+    /// Pays as much of `stream.accrued` as the treasury holds and saves the stream.
+    /// A cancelled stream is removed once it is fully paid.
+    fn pay_out(e: &Env, employee: &Address, mut stream: Stream) -> i128 {
+        let token: Address = storage::read_instance(e, &DataKey::Token);
+        let client = token::Client::new(e, &token);
+
+        let balance = client.balance(&e.current_contract_address());
+        let payout = stream.accrued.min(balance);
+        if payout < stream.accrued {
+            e.events().publish((symbol_short!("underpaid"), employee.clone()), stream.accrued - payout);
+        }
+
+        // Update state
+        stream.accrued -= payout;
+        stream.withdrawn += payout;
+
+        let key = DataKey::Stream(employee.clone());
+        if !stream.is_active && stream.accrued == 0 {
+            e.storage().persistent().remove(&key);
+            list::remove(e, &DataKey::Employees, employee);
+        } else {
+            Self::save_stream(e, employee, &stream);
+        }
+
+        if payout > 0 {
+            Self::update_liability(e, 0, payout);
+            client.transfer(&e.current_contract_address(), employee, &payout);
+            e.events().publish((symbol_short!("withdraw"), employee.clone()), payout);
+        }
+
+        payout
+    }
+}
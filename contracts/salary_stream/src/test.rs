@@ -0,0 +1,241 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use payd_testutils::Asset;
+use soroban_sdk::{testutils::{storage::Persistent, Address as _, Ledger}, Address, Env, token};
+
+struct Setup<'a> {
+    client: SalaryStreamContractClient<'a>,
+    employer: Address,
+    token: token::Client<'a>,
+}
+
+fn setup<'a>(e: &Env, treasury: i128) -> Setup<'a> {
+    let employer = Address::generate(e);
+    let client = SalaryStreamContractClient::new(e, &e.register(SalaryStreamContract, ()));
+    let asset = Asset::funded(e, &employer, treasury);
+
+    client.init(&employer, &asset.address);
+    if treasury > 0 {
+        client.deposit(&employer, &treasury);
+    }
+
+    Setup { client, employer, token: asset.client }
+}
+
+#[test]
+fn test_stream_accrues_and_withdraws() {
+    let e = payd_testutils::env();
+    let s = setup(&e, 10000);
+
+    let employee = Address::generate(&e);
+    e.ledger().set_timestamp(100);
+    s.client.open_stream(&employee, &2);
+
+    e.ledger().set_timestamp(150);
+    assert_eq!(s.client.get_accrued(&employee), 100);
+    assert_eq!(s.client.withdraw(&employee), 100);
+    let signers: std::vec::Vec<Address> = e.auths().iter().map(|(address, _)| address.clone()).collect();
+    assert_eq!(signers, std::vec![employee.clone()]);
+    assert_eq!(s.token.balance(&employee), 100);
+
+    // Withdrawing again in the same second pays nothing
+    assert_eq!(s.client.withdraw(&employee), 0);
+
+    e.ledger().set_timestamp(200);
+    assert_eq!(s.client.withdraw(&employee), 100);
+    assert_eq!(s.client.get_stream(&employee).unwrap().withdrawn, 200);
+}
+
+#[test]
+fn test_withdraw_keeps_stream_alive() {
+    let e = payd_testutils::env();
+    let s = setup(&e, 10000);
+
+    let employee = Address::generate(&e);
+    s.client.open_stream(&employee, &2);
+
+    e.ledger().with_mut(|ledger| ledger.sequence_number += 2 * storage::DAY_IN_LEDGERS);
+    s.client.withdraw(&employee);
+
+    let ttl = e.as_contract(&s.client.address, || e.storage().persistent().get_ttl(&DataKey::Stream(employee.clone())));
+    assert_eq!(ttl, storage::PERSISTENT_BUMP_AMOUNT);
+}
+
+#[test]
+fn test_set_rate_settles_old_rate() {
+    let e = payd_testutils::env();
+    let s = setup(&e, 10000);
+
+    let employee = Address::generate(&e);
+    s.client.open_stream(&employee, &1);
+
+    e.ledger().set_timestamp(100);
+    s.client.set_rate(&employee, &3);
+
+    e.ledger().set_timestamp(200);
+    // 100s at 1/s plus 100s at 3/s
+    assert_eq!(s.client.get_accrued(&employee), 400);
+    assert_eq!(s.client.withdraw(&employee), 400);
+}
+
+#[test]
+fn test_cancel_settles_pro_rata() {
+    let e = payd_testutils::env();
+    let s = setup(&e, 10000);
+
+    let employee = Address::generate(&e);
+    s.client.open_stream(&employee, &5);
+
+    e.ledger().set_timestamp(40);
+    s.client.withdraw(&employee);
+
+    e.ledger().set_timestamp(73);
+    assert_eq!(s.client.cancel_stream(&employee), 165);
+    assert_eq!(s.token.balance(&employee), 365);
+
+    // Fully paid, so the stream is gone and nothing more accrues
+    assert!(s.client.get_stream(&employee).is_none());
+    assert_eq!(s.client.get_employee_count(), 0);
+    assert_eq!(s.client.get_liability(), 0);
+
+    // The employee can be put back on a new stream
+    s.client.open_stream(&employee, &1);
+}
+
+#[test]
+fn test_shared_treasury_shortfall() {
+    let e = payd_testutils::env();
+    let s = setup(&e, 300);
+
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    s.client.open_stream(&alice, &2);
+    s.client.open_stream(&bob, &2);
+
+    e.ledger().set_timestamp(100);
+    assert_eq!(s.client.get_liability(), 400);
+
+    // Alice withdraws first and is paid in full; Bob gets what is left
+    assert_eq!(s.client.withdraw(&alice), 200);
+    assert_eq!(s.client.withdraw(&bob), 100);
+    assert_eq!(s.client.get_accrued(&bob), 100);
+
+    // The rest is paid once the employer tops up
+    token::StellarAssetClient::new(&e, &s.token.address).mint(&s.employer, &1000);
+    s.client.deposit(&s.employer, &1000);
+    assert_eq!(s.client.withdraw(&bob), 100);
+    assert_eq!(s.token.balance(&bob), 200);
+}
+
+#[test]
+fn test_liability_tracks_every_stream() {
+    let e = payd_testutils::env();
+    let s = setup(&e, 100_000);
+
+    let employees: std::vec::Vec<Address> = (0..3).map(|_| Address::generate(&e)).collect();
+    for (i, employee) in employees.iter().enumerate() {
+        e.ledger().set_timestamp(10 * i as u64);
+        s.client.open_stream(employee, &(i as i128 + 1));
+    }
+
+    // Fully paid cancelled streams are gone
+    let owed = |s: &Setup| {
+        let open = employees.iter().filter(|employee| s.client.get_stream(employee).is_some());
+        open.map(|employee| s.client.get_accrued(employee)).sum::<i128>()
+    };
+
+    e.ledger().set_timestamp(100);
+    assert_eq!(s.client.get_liability(), 100 + 2 * 90 + 3 * 80);
+    assert_eq!(s.client.get_liability(), owed(&s));
+
+    s.client.set_rate(&employees[0], &10);
+    s.client.withdraw(&employees[1]);
+    e.ledger().set_timestamp(150);
+    s.client.cancel_stream(&employees[2]);
+    e.ledger().set_timestamp(200);
+    assert_eq!(s.client.get_liability(), owed(&s));
+    assert_eq!(s.client.get_liability(), 100 + 10 * 100 + 2 * 100);
+
+    assert_eq!(s.client.get_employee_count(), 2);
+    assert_eq!(s.client.get_employees(&0, &10), soroban_sdk::vec![&e, employees[0].clone(), employees[1].clone()]);
+}
+
+#[test]
+fn test_cancel_with_short_treasury_keeps_debt() {
+    let e = payd_testutils::env();
+    let s = setup(&e, 50);
+
+    let employee = Address::generate(&e);
+    s.client.open_stream(&employee, &1);
+
+    e.ledger().set_timestamp(80);
+    assert_eq!(s.client.cancel_stream(&employee), 50);
+
+    // Accrual stopped at cancellation, but the unpaid 30 is still owed
+    e.ledger().set_timestamp(500);
+    assert_eq!(s.client.get_accrued(&employee), 30);
+    assert!(s.client.try_open_stream(&employee, &1).is_err());
+
+    token::StellarAssetClient::new(&e, &s.token.address).mint(&s.employer, &30);
+    s.client.deposit(&s.employer, &30);
+    assert_eq!(s.client.withdraw(&employee), 30);
+    assert!(s.client.get_stream(&employee).is_none());
+}
+
+#[test]
+fn test_withdraw_treasury_keeps_liability() {
+    let e = payd_testutils::env();
+    let s = setup(&e, 1000);
+
+    let employee = Address::generate(&e);
+    s.client.open_stream(&employee, &1);
+
+    e.ledger().set_timestamp(400);
+    let to = Address::generate(&e);
+    assert!(s.client.try_withdraw_treasury(&to, &601).is_err());
+
+    s.client.withdraw_treasury(&to, &600);
+    assert_eq!(s.token.balance(&to), 600);
+    assert_eq!(s.client.withdraw(&employee), 400);
+}
+
+#[test]
+#[should_panic(expected = "Stream already exists")]
+fn test_open_stream_twice() {
+    let e = payd_testutils::env();
+    let s = setup(&e, 0);
+
+    let employee = Address::generate(&e);
+    s.client.open_stream(&employee, &1);
+    s.client.open_stream(&employee, &1);
+}
+
+#[test]
+#[should_panic(expected = "Rate must be positive")]
+fn test_zero_rate() {
+    let e = payd_testutils::env();
+    let s = setup(&e, 0);
+    s.client.open_stream(&Address::generate(&e), &0);
+}
+
+#[test]
+#[should_panic(expected = "Stream not found")]
+fn test_withdraw_without_stream() {
+    let e = payd_testutils::env();
+    let s = setup(&e, 0);
+    s.client.withdraw(&Address::generate(&e));
+}
+
+#[test]
+#[should_panic(expected = "Stream overflow")]
+fn test_accrual_overflow() {
+    let e = payd_testutils::env();
+    let s = setup(&e, 0);
+
+    let employee = Address::generate(&e);
+    s.client.open_stream(&employee, &i128::MAX);
+    e.ledger().set_timestamp(2);
+    s.client.get_accrued(&employee);
+}
@@ -0,0 +1,254 @@
+#![no_std]
+
+use payd_common::{admin, council};
+use soroban_sdk::{
+    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
+    contract, contractimpl, contracttype, symbol_short, token, vec, Address, Env, IntoVal, Symbol, Val,
+    Vec,
+};
+
+#[cfg(test)]
+mod test;
+
+pub const DAY_SECONDS: u64 = 24 * 60 * 60;
+
+// Spent-today counters live in temporary storage and only need to outlast their day.
+const DAY_IN_LEDGERS: u32 = 17280;
+
+#[contracttype]
+pub enum DataKey {
+    DailyLimit(Symbol, Address),
+    Spent(Symbol, Address, u64),
+    Allowance(Address, Address, Symbol, Address),
+}
+
+#[contract]
+pub struct TreasuryVaultContract;
+
+#[contractimpl]
+impl TreasuryVaultContract {
+    /// Initialize the vault with an admin who manages roles, limits and allowances.
+    pub fn init(e: Env, admin: Address) {
+        admin::init_admin(&e, &admin);
+    }
+
+    /// Allows the current admin to set a new admin.
+    pub fn set_admin(e: Env, new_admin: Address) {
+        admin::set_admin(&e, &new_admin);
+    }
+
+    /// Hands withdrawals and spends above each asset's threshold to `council`, usually a
+    /// `multisig` (admin only until a council is set, then the council only).
+    pub fn set_council(e: Env, council: Address) {
+        council::set_council(&e, &council);
+
+        e.events().publish((symbol_short!("council"),), council);
+    }
+
+    /// Sets the most of `asset` that withdrawals and spends together may move in a day without
+    /// the council. Once a council is set, an asset without a threshold needs it for every amount.
+    pub fn set_threshold(e: Env, asset: Address, threshold: i128) {
+        council::set_threshold(&e, &asset, threshold);
+
+        e.events().publish((symbol_short!("threshold"), asset), threshold);
+    }
+
+    /// Adds `amount` of `asset` to the vault. Anyone can deposit.
+    pub fn deposit(e: Env, from: Address, asset: Address, amount: i128) {
+        from.require_auth();
+
+        if amount <= 0 {
+            panic!("Amount must be positive");
+        }
+
+        let client = token::Client::new(&e, &asset);
+        client.transfer(&from, &e.current_contract_address(), &amount);
+
+        e.events().publish((symbol_short!("deposit"), from, asset), amount);
+    }
+
+    /// Gives `member` a role, or takes it away with `None` (admin only).
+    pub fn set_role(e: Env, member: Address, role: Option<Symbol>) {
+        admin::require_admin(&e);
+        admin::set_role(&e, &member, role.clone());
+
+        e.events().publish((symbol_short!("role"), member), role);
+    }
+
+    /// Sets how much of `asset` all members of `role` may withdraw per day, combined (admin only).
+    /// A role without a limit for an asset cannot withdraw it.
+    pub fn set_daily_limit(e: Env, role: Symbol, asset: Address, limit: i128) {
+        admin::require_admin(&e);
+
+        if limit < 0 {
+            panic!("Limit must not be negative");
+        }
+        e.storage().persistent().set(&DataKey::DailyLimit(role.clone(), asset.clone()), &limit);
+
+        e.events().publish((symbol_short!("limit"), role, asset), limit);
+    }
+
+    /// Withdraws `amount` of `asset` to `to`, counted against the member's role limit for the current day.
+    /// Once the day's withdrawals and spends of the asset would pass its threshold, the council
+    /// has to authorize as well.
+    pub fn withdraw(e: Env, member: Address, asset: Address, to: Address, amount: i128) {
+        member.require_auth();
+
+        if amount <= 0 {
+            panic!("Amount must be positive");
+        }
+        council::require_for_today(&e, &asset, amount);
+
+        let role = admin::get_role(&e, &member).expect("No role");
+        let limit: i128 = e.storage().persistent().get(&DataKey::DailyLimit(role.clone(), asset.clone())).unwrap_or(0);
+
+        let spent_key = DataKey::Spent(role, asset.clone(), e.ledger().timestamp() / DAY_SECONDS);
+        let spent: i128 = e.storage().temporary().get(&spent_key).unwrap_or(0);
+        if spent + amount > limit {
+            panic!("Daily limit exceeded");
+        }
+        e.storage().temporary().set(&spent_key, &(spent + amount));
+        e.storage().temporary().extend_ttl(&spent_key, DAY_IN_LEDGERS, DAY_IN_LEDGERS * 2);
+
+        let client = token::Client::new(&e, &asset);
+        client.transfer(&e.current_contract_address(), &to, &amount);
+
+        e.events().publish((symbol_short!("withdraw"), member, asset), (to, amount));
+    }
+
+    /// Lets `spender` pay up to `amount` of `asset` through `spend` calls to `target.function`
+    /// (admin only). Replaces any previous allowance for the same spender, call and asset.
+    ///
+    /// `function` must be one of the calls that only pull funds from their payer: a token
+    /// `transfer` (with `target` the asset itself), `CrossAssetPaymentContract::initiate_payment`,
+    /// `RevenueSplitContract::distribute` or `VestingContract::initialize`/`top_up`.
+    pub fn approve(e: Env, spender: Address, target: Address, function: Symbol, asset: Address, amount: i128) {
+        admin::require_admin(&e);
+
+        if amount < 0 {
+            panic!("Amount must not be negative");
+        }
+        Self::check_call(&e, &target, &function, &asset);
+
+        let key = DataKey::Allowance(spender.clone(), target.clone(), function.clone(), asset.clone());
+        if amount == 0 {
+            e.storage().persistent().remove(&key);
+        } else {
+            e.storage().persistent().set(&key, &amount);
+        }
+
+        e.events().publish((symbol_short!("approve"), spender, target), (function, asset, amount));
+    }
+
+    /// Calls `target.function(args)` with the vault as the paying account, taking `amount` out
+    /// of the spender's allowance for that call.
+    ///
+    /// For anything but a direct `transfer` the vault authorizes exactly one `asset` transfer of
+    /// `amount` from itself into `target`, which is how the funding entry points pull their funds.
+    /// Either way the call must leave the vault exactly `amount` of `asset` poorer, so the arguments
+    /// are never read and a target can change its signature without this contract knowing.
+    /// Calls `approve` does not allow are rejected, and spends that take the day's total past the
+    /// asset's threshold also need the council's authorization.
+    pub fn spend(e: Env, spender: Address, target: Address, function: Symbol, asset: Address, amount: i128, args: Vec<Val>) -> Val {
+        spender.require_auth();
+
+        Self::check_call(&e, &target, &function, &asset);
+        if amount <= 0 {
+            panic!("Amount must be positive");
+        }
+        council::require_for_today(&e, &asset, amount);
+
+        let key = DataKey::Allowance(spender.clone(), target.clone(), function.clone(), asset.clone());
+        let allowance: i128 = e.storage().persistent().get(&key).unwrap_or(0);
+        if amount > allowance {
+            panic!("Allowance exceeded");
+        }
+
+        // Update the allowance before handing control to the target
+        if allowance == amount {
+            e.storage().persistent().remove(&key);
+        } else {
+            e.storage().persistent().set(&key, &(allowance - amount));
+        }
+
+        let vault = e.current_contract_address();
+        let client = token::Client::new(&e, &asset);
+        let before = client.balance(&vault);
+
+        // A direct transfer is authorized by the vault being the invoker
+        if target != asset {
+            e.authorize_as_current_contract(vec![
+                &e,
+                InvokerContractAuthEntry::Contract(SubContractInvocation {
+                    context: ContractContext {
+                        contract: asset.clone(),
+                        fn_name: symbol_short!("transfer"),
+                        args: (vault.clone(), target.clone(), amount).into_val(&e),
+                    },
+                    sub_invocations: Vec::new(&e),
+                }),
+            ]);
+        }
+
+        let result = e.invoke_contract::<Val>(&target, &function, args);
+        if before - client.balance(&vault) != amount {
+            panic!("Call must pay exactly the amount");
+        }
+
+        e.events().publish((symbol_short!("spend"), spender, target), (asset, amount));
+
+        result
+    }
+
+    pub fn get_council(e: Env) -> Option<Address> {
+        council::get_council(&e)
+    }
+
+    pub fn get_threshold(e: Env, asset: Address) -> Option<i128> {
+        council::get_threshold(&e, &asset)
+    }
+
+    /// How much of `asset` has been withdrawn or spent today without the council.
+    pub fn get_moved_today(e: Env, asset: Address) -> i128 {
+        council::moved_today(&e, &asset)
+    }
+
+    pub fn get_role(e: Env, member: Address) -> Option<Symbol> {
+        admin::get_role(&e, &member)
+    }
+
+    pub fn get_daily_limit(e: Env, role: Symbol, asset: Address) -> i128 {
+        e.storage().persistent().get(&DataKey::DailyLimit(role, asset)).unwrap_or(0)
+    }
+
+    /// How much of `asset` members of `role` have withdrawn today.
+    pub fn get_spent_today(e: Env, role: Symbol, asset: Address) -> i128 {
+        let day = e.ledger().timestamp() / DAY_SECONDS;
+        e.storage().temporary().get(&DataKey::Spent(role, asset, day)).unwrap_or(0)
+    }
+
+    pub fn get_allowance(e: Env, spender: Address, target: Address, function: Symbol, asset: Address) -> i128 {
+        e.storage().persistent().get(&DataKey::Allowance(spender, target, function, asset)).unwrap_or(0)
+    }
+
+    /// Rejects anything but a transfer of `asset` itself or one of the funding calls, which
+    /// can do no more with the vault as their invoker than pull funds from it.
+    fn check_call(e: &Env, target: &Address, function: &Symbol, asset: &Address) {
+        if *function == symbol_short!("transfer") {
+            if target != asset {
+                panic!("Transfers must target the asset");
+            }
+            return;
+        }
+        let funding = [
+            Symbol::new(e, "initiate_payment"),
+            Symbol::new(e, "distribute"),
+            Symbol::new(e, "initialize"),
+            symbol_short!("top_up"),
+        ];
+        if !funding.contains(function) {
+            panic!("Unsupported call");
+        }
+    }
+
+}
@@ -0,0 +1,399 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use payd_testutils::Asset;
+use revenue_split::{RecipientShare, RevenueSplitContract, RevenueSplitContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke},
+    token, Address, Env, IntoVal, Symbol, Vec,
+};
+use vesting_escrow::{CliffMode, TrancheTerms, VestingContract, VestingContractClient, VestingSchedule, DEFAULT_START_BOUNDS};
+
+struct Setup<'a> {
+    vault: TreasuryVaultContractClient<'a>,
+    admin: Address,
+    asset: Address,
+    token: token::Client<'a>,
+}
+
+fn setup<'a>(e: &Env, balance: i128) -> Setup<'a> {
+    let [admin, employer] = payd_testutils::accounts(e);
+    let vault = TreasuryVaultContractClient::new(e, &e.register(TreasuryVaultContract, ()));
+    vault.init(&admin);
+
+    let asset = Asset::funded(e, &employer, balance);
+    vault.deposit(&employer, &asset.address, &balance);
+
+    Setup { vault, admin, asset: asset.address, token: asset.client }
+}
+
+/// Authorizes only `spender`'s call to `spend`, so the vault's own authorization of the
+/// nested transfer is checked for real rather than mocked. Returns whether the call succeeded.
+fn spend_as_spender(
+    e: &Env,
+    s: &Setup,
+    spender: &Address,
+    target: &Address,
+    function: &str,
+    amount: i128,
+    args: Vec<Val>,
+) -> bool {
+    let function = Symbol::new(e, function);
+    e.set_auths(&[]);
+    s.vault
+        .mock_auths(&[MockAuth {
+            address: spender,
+            invoke: &MockAuthInvoke {
+                contract: &s.vault.address,
+                fn_name: "spend",
+                args: (spender, target, &function, &s.asset, amount, args.clone()).into_val(e),
+                sub_invokes: &[],
+            },
+        }])
+        .try_spend(spender, target, &function, &s.asset, &amount, &args)
+        .is_ok()
+}
+
+#[test]
+fn test_role_daily_limit() {
+    let e = payd_testutils::env();
+    let s = setup(&e, 10000);
+    let accounting = Symbol::new(&e, "accounting");
+
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let to = Address::generate(&e);
+    s.vault.set_role(&alice, &Some(accounting.clone()));
+    s.vault.set_role(&bob, &Some(accounting.clone()));
+    s.vault.set_daily_limit(&accounting, &s.asset, &1000);
+
+    e.ledger().set_timestamp(DAY_SECONDS + 10);
+    s.vault.withdraw(&alice, &s.asset, &to, &600);
+    let signers: std::vec::Vec<Address> = e.auths().iter().map(|(address, _)| address.clone()).collect();
+    assert_eq!(signers, std::vec![alice.clone()]);
+
+    // The limit is shared by everyone holding the role
+    assert!(s.vault.try_withdraw(&bob, &s.asset, &to, &401).is_err());
+    s.vault.withdraw(&bob, &s.asset, &to, &400);
+    assert_eq!(s.vault.get_spent_today(&accounting, &s.asset), 1000);
+
+    // It resets the next day
+    e.ledger().set_timestamp(2 * DAY_SECONDS);
+    assert_eq!(s.vault.get_spent_today(&accounting, &s.asset), 0);
+    s.vault.withdraw(&alice, &s.asset, &to, &1000);
+    assert_eq!(s.token.balance(&to), 2000);
+}
+
+#[test]
+fn test_withdraw_without_limit_for_asset() {
+    let e = payd_testutils::env();
+    let s = setup(&e, 10000);
+    let ops = Symbol::new(&e, "ops");
+
+    let member = Address::generate(&e);
+    s.vault.set_role(&member, &Some(ops));
+
+    assert!(s.vault.try_withdraw(&member, &s.asset, &member, &1).is_err());
+}
+
+#[test]
+#[should_panic(expected = "No role")]
+fn test_withdraw_without_role() {
+    let e = payd_testutils::env();
+    let s = setup(&e, 10000);
+    let member = Address::generate(&e);
+    s.vault.withdraw(&member, &s.asset, &member, &1);
+}
+
+#[test]
+fn test_spend_funds_revenue_split() {
+    let e = payd_testutils::env();
+    let s = setup(&e, 10000);
+
+    let split_id = e.register(RevenueSplitContract, ());
+    let recipient1 = Address::generate(&e);
+    let recipient2 = Address::generate(&e);
+    RevenueSplitContractClient::new(&e, &split_id).init(&Address::generate(&e), &Vec::from_array(&e, [
+        RecipientShare { destination: recipient1.clone(), basis_points: 7000 },
+        RecipientShare { destination: recipient2.clone(), basis_points: 3000 },
+    ]));
+
+    let spender = Address::generate(&e);
+    let distribute = Symbol::new(&e, "distribute");
+    s.vault.approve(&spender, &split_id, &distribute, &s.asset, &1500);
+
+    let args: Vec<Val> = (s.asset.clone(), s.vault.address.clone(), 1000i128).into_val(&e);
+    assert!(spend_as_spender(&e, &s, &spender, &split_id, "distribute", 1000, args));
+
+    assert_eq!(s.token.balance(&recipient1), 700);
+    assert_eq!(s.token.balance(&recipient2), 300);
+    assert_eq!(s.token.balance(&s.vault.address), 9000);
+    assert_eq!(s.vault.get_allowance(&spender, &split_id, &distribute, &s.asset), 500);
+}
+
+fn linear_terms(duration_seconds: u64) -> TrancheTerms {
+    TrancheTerms {
+        start_time: 0,
+        cliff_seconds: 0,
+        duration_seconds,
+        schedule: VestingSchedule::Linear,
+        cliff_mode: CliffMode::CatchUp,
+    }
+}
+
+#[test]
+fn test_spend_funds_vesting_grant() {
+    let e = payd_testutils::env();
+    let s = setup(&e, 10000);
+
+    let grant_id = e.register(VestingContract, ());
+    let beneficiary = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let initialize = Symbol::new(&e, "initialize");
+    s.vault.approve(&spender, &grant_id, &initialize, &s.asset, &4000);
+
+    let args: Vec<Val> = (
+        s.vault.address.clone(),
+        beneficiary.clone(),
+        s.asset.clone(),
+        4000i128,
+        Address::generate(&e),
+        linear_terms(1000),
+        DEFAULT_START_BOUNDS,
+    )
+        .into_val(&e);
+    assert!(spend_as_spender(&e, &s, &spender, &grant_id, "initialize", 4000, args));
+
+    let grant = VestingContractClient::new(&e, &grant_id);
+    assert_eq!(grant.get_config().total_amount, 4000);
+    assert_eq!(s.token.balance(&grant_id), 4000);
+    assert_eq!(s.vault.get_allowance(&spender, &grant_id, &initialize, &s.asset), 0);
+}
+
+#[test]
+fn test_spend_requires_vault_as_payer() {
+    let e = payd_testutils::env();
+    let s = setup(&e, 10000);
+
+    let split_id = e.register(RevenueSplitContract, ());
+    RevenueSplitContractClient::new(&e, &split_id).init(&Address::generate(&e), &Vec::from_array(&e, [
+        RecipientShare { destination: Address::generate(&e), basis_points: 10000 },
+    ]));
+
+    let spender = Address::generate(&e);
+    let distribute = Symbol::new(&e, "distribute");
+    s.vault.approve(&spender, &split_id, &distribute, &s.asset, &5000);
+
+    // Someone else paying, or paying in another asset, is not what the allowance covers
+    let other = Address::generate(&e);
+    let args: Vec<Val> = (s.asset.clone(), other, 100i128).into_val(&e);
+    assert!(!spend_as_spender(&e, &s, &spender, &split_id, "distribute", 100, args));
+    let args: Vec<Val> = (Address::generate(&e), s.vault.address.clone(), 100i128).into_val(&e);
+    assert!(!spend_as_spender(&e, &s, &spender, &split_id, "distribute", 100, args));
+
+    // Nor is a call that pays a different amount than the one charged to the allowance
+    let args: Vec<Val> = (s.asset.clone(), s.vault.address.clone(), 200i128).into_val(&e);
+    assert!(!spend_as_spender(&e, &s, &spender, &split_id, "distribute", 100, args));
+    let args: Vec<Val> = (s.asset.clone(), s.vault.address.clone(), 50i128).into_val(&e);
+    assert!(!spend_as_spender(&e, &s, &spender, &split_id, "distribute", 100, args));
+
+    assert_eq!(s.token.balance(&s.vault.address), 10000);
+    assert_eq!(s.vault.get_allowance(&spender, &split_id, &distribute, &s.asset), 5000);
+}
+
+#[test]
+fn test_spend_checks_allowance() {
+    let e = payd_testutils::env();
+    let s = setup(&e, 10000);
+
+    let spender = Address::generate(&e);
+    let target = Address::generate(&e);
+    let function = Symbol::new(&e, "distribute");
+
+    // No allowance for this target at all
+    let args: Vec<Val> = (s.asset.clone(), s.vault.address.clone(), 1i128).into_val(&e);
+    assert!(s.vault.try_spend(&spender, &target, &function, &s.asset, &1, &args).is_err());
+
+    s.vault.approve(&spender, &target, &function, &s.asset, &100);
+    let args: Vec<Val> = (s.asset.clone(), s.vault.address.clone(), 101i128).into_val(&e);
+    assert!(s.vault.try_spend(&spender, &target, &function, &s.asset, &101, &args).is_err());
+
+    // Approving zero revokes the allowance
+    s.vault.approve(&spender, &target, &function, &s.asset, &0);
+    assert_eq!(s.vault.get_allowance(&spender, &target, &function, &s.asset), 0);
+}
+
+#[test]
+fn test_spend_transfer() {
+    let e = payd_testutils::env();
+    let s = setup(&e, 10000);
+
+    let spender = Address::generate(&e);
+    let payee = Address::generate(&e);
+    let transfer = Symbol::new(&e, "transfer");
+    s.vault.approve(&spender, &s.asset, &transfer, &s.asset, &500);
+
+    let args: Vec<Val> = (s.vault.address.clone(), payee.clone(), 300i128).into_val(&e);
+    assert!(spend_as_spender(&e, &s, &spender, &s.asset, "transfer", 300, args));
+    assert_eq!(s.token.balance(&payee), 300);
+    assert_eq!(s.vault.get_allowance(&spender, &s.asset, &transfer, &s.asset), 200);
+
+    // The vault invoking the asset authorizes any transfer from it, so the amount moved is checked
+    let args: Vec<Val> = (s.vault.address.clone(), payee.clone(), 5000i128).into_val(&e);
+    assert!(!spend_as_spender(&e, &s, &spender, &s.asset, "transfer", 100, args));
+    assert_eq!(s.token.balance(&payee), 300);
+
+    // A transfer allowance only covers the asset's own `transfer`
+    e.mock_all_auths();
+    assert!(s.vault.try_approve(&spender, &payee, &transfer, &s.asset, &500).is_err());
+}
+
+#[test]
+fn test_spender_cannot_sweep_grant_through_vault() {
+    let e = payd_testutils::env();
+    let s = setup(&e, 10000);
+
+    // The vault funds a grant and is its clawback admin
+    let grant_id = e.register(VestingContract, ());
+    let grant = VestingContractClient::new(&e, &grant_id);
+    grant.initialize(
+        &s.vault.address,
+        &Address::generate(&e),
+        &s.asset,
+        &4000,
+        &s.vault.address,
+        &linear_terms(1000),
+        &DEFAULT_START_BOUNDS,
+    );
+    // Tokens sent straight to the grant are excess the clawback admin could sweep
+    token::StellarAssetClient::new(&e, &s.asset).mint(&grant_id, &1000);
+
+    let spender = Address::generate(&e);
+    let top_up = Symbol::new(&e, "top_up");
+    s.vault.approve(&spender, &grant_id, &top_up, &s.asset, &4000);
+
+    // Only the approved call shape goes through, so the vault's admin rights stay out of reach
+    let sweep = Symbol::new(&e, "sweep_excess");
+    assert!(s.vault.try_approve(&spender, &grant_id, &sweep, &s.asset, &4000).is_err());
+    let args: Vec<Val> = (spender.clone(),).into_val(&e);
+    assert!(!spend_as_spender(&e, &s, &spender, &grant_id, "sweep_excess", 1000, args));
+    let args: Vec<Val> = Vec::new(&e);
+    assert!(!spend_as_spender(&e, &s, &spender, &grant_id, "clawback", 1000, args));
+
+    assert_eq!(s.token.balance(&spender), 0);
+    assert_eq!(s.token.balance(&grant_id), 5000);
+    assert!(grant.get_config().is_active);
+
+    let args: Vec<Val> = (s.vault.address.clone(), 1000i128, None::<vesting_escrow::TrancheTerms>).into_val(&e);
+    assert!(spend_as_spender(&e, &s, &spender, &grant_id, "top_up", 1000, args));
+    assert_eq!(s.token.balance(&grant_id), 6000);
+}
+
+/// Withdraws with the authorization of exactly `signers`. Returns whether the call succeeded.
+fn withdraw_signed_by(e: &Env, s: &Setup, member: &Address, amount: i128, signers: &[&Address]) -> bool {
+    let args: Vec<Val> = (member, &s.asset, member, amount).into_val(e);
+    let invoke = MockAuthInvoke { contract: &s.vault.address, fn_name: "withdraw", args, sub_invokes: &[] };
+    let auths: std::vec::Vec<MockAuth> = signers.iter().map(|address| MockAuth { address, invoke: &invoke }).collect();
+    e.set_auths(&[]);
+    s.vault.mock_auths(&auths).try_withdraw(member, &s.asset, member, &amount).is_ok()
+}
+
+#[test]
+fn test_large_withdrawal_needs_council() {
+    let e = payd_testutils::env();
+    let s = setup(&e, 10000);
+    let role = Symbol::new(&e, "payroll");
+    let member = Address::generate(&e);
+    s.vault.set_role(&member, &Some(role.clone()));
+    s.vault.set_daily_limit(&role, &s.asset, &5000);
+
+    let council = Address::generate(&e);
+    s.vault.set_council(&council);
+    s.vault.set_threshold(&s.asset, &1000);
+
+    assert!(withdraw_signed_by(&e, &s, &member, 1000, &[&member]));
+    assert!(!withdraw_signed_by(&e, &s, &member, 1001, &[&member]));
+    assert!(withdraw_signed_by(&e, &s, &member, 1001, &[&member, &council]));
+    assert_eq!(s.token.balance(&member), 2001);
+}
+
+#[test]
+fn test_split_withdrawals_need_council() {
+    let e = payd_testutils::env();
+    let s = setup(&e, 10000);
+    s.vault.set_council(&Address::generate(&e));
+    s.vault.set_threshold(&s.asset, &1000);
+
+    // An admin who hands itself a role and a high limit still cannot take more than the
+    // threshold in a day by staying under it on every call
+    let role = Symbol::new(&e, "payroll");
+    s.vault.set_role(&s.admin, &Some(role.clone()));
+    s.vault.set_daily_limit(&role, &s.asset, &10000);
+    for _ in 0..4 {
+        assert!(withdraw_signed_by(&e, &s, &s.admin, 250, &[&s.admin]));
+    }
+    assert!(!withdraw_signed_by(&e, &s, &s.admin, 1, &[&s.admin]));
+    assert_eq!(s.vault.get_moved_today(&s.asset), 1000);
+
+    // Spends draw on the same daily total
+    let spender = Address::generate(&e);
+    e.mock_all_auths();
+    s.vault.approve(&spender, &s.asset, &Symbol::new(&e, "transfer"), &s.asset, &500);
+    let args: Vec<Val> = (s.vault.address.clone(), spender.clone(), 100i128).into_val(&e);
+    assert!(!spend_as_spender(&e, &s, &spender, &s.asset, "transfer", 100, args));
+
+    e.ledger().set_timestamp(DAY_SECONDS);
+    assert!(withdraw_signed_by(&e, &s, &s.admin, 250, &[&s.admin]));
+    assert_eq!(s.token.balance(&s.admin), 1250);
+}
+
+#[test]
+fn test_large_spend_needs_council() {
+    let e = payd_testutils::env();
+    let s = setup(&e, 10000);
+
+    let split_id = e.register(RevenueSplitContract, ());
+    RevenueSplitContractClient::new(&e, &split_id).init(&Address::generate(&e), &Vec::from_array(&e, [
+        RecipientShare { destination: Address::generate(&e), basis_points: 10000 },
+    ]));
+    let spender = Address::generate(&e);
+    s.vault.approve(&spender, &split_id, &Symbol::new(&e, "distribute"), &s.asset, &5000);
+
+    s.vault.set_council(&Address::generate(&e));
+    s.vault.set_threshold(&s.asset, &1000);
+
+    let args: Vec<Val> = (s.asset.clone(), s.vault.address.clone(), 1000i128).into_val(&e);
+    assert!(spend_as_spender(&e, &s, &spender, &split_id, "distribute", 1000, args));
+    let args: Vec<Val> = (s.asset.clone(), s.vault.address.clone(), 1001i128).into_val(&e);
+    assert!(!spend_as_spender(&e, &s, &spender, &split_id, "distribute", 1001, args));
+    assert_eq!(s.token.balance(&s.vault.address), 9000);
+}
+
+#[test]
+fn test_council_owns_its_settings() {
+    let e = payd_testutils::env();
+    let s = setup(&e, 1);
+    let council = Address::generate(&e);
+    s.vault.set_council(&council);
+    assert_eq!(s.vault.get_council(), Some(council.clone()));
+    assert_eq!(s.vault.get_threshold(&s.asset), None);
+
+    // Once there is a council, the admin alone cannot raise a threshold or replace it
+    let args: Vec<Val> = (&s.asset, 1_000_000i128).into_val(&e);
+    let invoke = MockAuthInvoke { contract: &s.vault.address, fn_name: "set_threshold", args, sub_invokes: &[] };
+    e.set_auths(&[]);
+    let result = s.vault.mock_auths(&[MockAuth { address: &s.admin, invoke: &invoke }]).try_set_threshold(&s.asset, &1_000_000);
+    assert!(result.is_err());
+    let result = s.vault.mock_auths(&[MockAuth { address: &council, invoke: &invoke }]).try_set_threshold(&s.asset, &1_000_000);
+    assert!(result.is_ok());
+    assert_eq!(s.vault.get_threshold(&s.asset), Some(1_000_000));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn test_init_twice() {
+    let e = payd_testutils::env();
+    let s = setup(&e, 1);
+    s.vault.init(&Address::generate(&e));
+}
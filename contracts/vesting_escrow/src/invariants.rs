@@ -0,0 +1,228 @@
+//! Property tests over randomized schedules, timestamps and operation sequences.
+
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use proptest::prelude::*;
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Env, Vec, token};
+use std::vec::Vec as StdVec;
+
+const START: u64 = 1_000_000;
+
+/// A schedule without the `Env`-bound parts, so proptest can generate and shrink it.
+#[derive(Clone, Debug)]
+enum Plan {
+    Linear,
+    Periodic(u64, i128),
+    /// `(offset from start, basis_points)`, increasing offsets, summing to 10000.
+    Milestones(StdVec<(u64, u32)>),
+}
+
+#[derive(Clone, Debug)]
+struct Grant {
+    cliff_seconds: u64,
+    duration_seconds: u64,
+    amount: i128,
+    plan: Plan,
+    cliff_mode: CliffMode,
+}
+
+fn milestones(duration_seconds: u64) -> impl Strategy<Value = Plan> {
+    (prop::collection::btree_set(0..=duration_seconds, 1..=5), prop::collection::vec(1u32..100, 5)).prop_map(
+        |(offsets, weights)| {
+            let offsets: StdVec<u64> = offsets.into_iter().collect();
+            let weights = &weights[..offsets.len()];
+            let sum: u32 = weights.iter().sum();
+
+            // Scale the weights to 10000 basis points; the last milestone takes the rounding
+            let mut basis_points: StdVec<u32> = weights.iter().map(|w| w * TOTAL_BASIS_POINTS / sum).collect();
+            let assigned: u32 = basis_points[..basis_points.len() - 1].iter().sum();
+            *basis_points.last_mut().unwrap() = TOTAL_BASIS_POINTS - assigned;
+
+            Plan::Milestones(offsets.into_iter().zip(basis_points).collect())
+        },
+    )
+}
+
+fn grant() -> impl Strategy<Value = Grant> {
+    grant_of_at_most(1_000_000_000_000)
+}
+
+fn grant_of_at_most(max_amount: i128) -> impl Strategy<Value = Grant> {
+    (1u64..=1_000_000, 1i128..=max_amount)
+        .prop_flat_map(|(duration_seconds, amount)| {
+            let plan = prop_oneof![
+                Just(Plan::Linear),
+                (1..=duration_seconds, 1..=amount).prop_map(|(period, per_period)| Plan::Periodic(period, per_period)),
+                milestones(duration_seconds),
+            ];
+            let cliff_mode = prop_oneof![Just(CliffMode::CatchUp), Just(CliffMode::StartAtCliff)];
+            (0..=duration_seconds, Just(duration_seconds), Just(amount), plan, cliff_mode)
+        })
+        .prop_map(|(cliff_seconds, duration_seconds, amount, plan, cliff_mode)| Grant {
+            cliff_seconds,
+            duration_seconds,
+            amount,
+            plan,
+            cliff_mode,
+        })
+}
+
+struct Setup<'a> {
+    client: VestingContractClient<'a>,
+    token: token::Client<'a>,
+    funder: Address,
+    beneficiary: Address,
+    clawback_admin: Address,
+}
+
+fn setup<'a>(e: &Env, grant: &Grant) -> Setup<'a> {
+    e.mock_all_auths();
+    e.cost_estimate().budget().reset_unlimited();
+    e.ledger().set_timestamp(START);
+
+    let funder = Address::generate(e);
+    let beneficiary = Address::generate(e);
+    let clawback_admin = Address::generate(e);
+    let contract_id = e.register(VestingContract, ());
+    let client = VestingContractClient::new(e, &contract_id);
+
+    let token_id = e.register_stellar_asset_contract_v2(Address::generate(e)).address();
+    // Enough for the grant and any top-ups
+    token::StellarAssetClient::new(e, &token_id).mint(&funder, &(grant.amount * 100));
+
+    let terms = TrancheTerms {
+        start_time: START,
+        cliff_seconds: grant.cliff_seconds,
+        duration_seconds: grant.duration_seconds,
+        schedule: schedule(e, &grant.plan, START),
+        cliff_mode: grant.cliff_mode,
+    };
+    client.initialize(&funder, &beneficiary, &token_id, &grant.amount, &clawback_admin, &terms, &DEFAULT_START_BOUNDS);
+
+    Setup { client, token: token::Client::new(e, &token_id), funder, beneficiary, clawback_admin }
+}
+
+fn schedule(e: &Env, plan: &Plan, start_time: u64) -> VestingSchedule {
+    match plan {
+        Plan::Linear => VestingSchedule::Linear,
+        Plan::Periodic(period, per_period) => VestingSchedule::Periodic(*period, *per_period),
+        Plan::Milestones(milestones) => {
+            let mut list = Vec::new(e);
+            for (offset, basis_points) in milestones {
+                list.push_back((start_time + offset, *basis_points));
+            }
+            VestingSchedule::Milestones(list)
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Op {
+    Advance(u64),
+    Claim,
+    /// Tops up on the original schedule.
+    TopUp(i128),
+    /// Tops up with `.0`'s amount as a tranche on `.0`'s terms, starting `.1` seconds from now.
+    AddTranche(Grant, u64),
+    ClawbackPartial(i128),
+    Clawback,
+    Pause,
+    Resume,
+}
+
+fn op(amount: i128) -> impl Strategy<Value = Op> {
+    prop_oneof![
+        3 => (0u64..500_000).prop_map(Op::Advance),
+        3 => Just(Op::Claim),
+        1 => (1..=amount).prop_map(Op::TopUp),
+        1 => (grant_of_at_most(amount), 0u64..100_000).prop_map(|(tranche, delay)| Op::AddTranche(tranche, delay)),
+        1 => (1..=amount).prop_map(Op::ClawbackPartial),
+        1 => Just(Op::Clawback),
+        1 => Just(Op::Pause),
+        1 => Just(Op::Resume),
+    ]
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn vested_is_monotonic_and_bounded(grant in grant(), offsets in prop::collection::vec(0u64..2_000_000, 1..20)) {
+        let e = Env::default();
+        let s = setup(&e, &grant);
+
+        let mut offsets = offsets;
+        offsets.sort_unstable();
+
+        let mut previous = 0;
+        for offset in offsets {
+            e.ledger().set_timestamp(START + offset);
+            let vested = s.client.get_vested_amount();
+            prop_assert!(vested >= previous, "vested went down from {} to {} at +{}", previous, vested, offset);
+            prop_assert!(vested <= grant.amount);
+            previous = vested;
+        }
+
+        e.ledger().set_timestamp(START + grant.duration_seconds);
+        prop_assert_eq!(s.client.get_vested_amount(), grant.amount);
+    }
+
+    #[test]
+    fn funds_are_conserved(
+        grant in grant(),
+        ops in prop::collection::vec(op(1_000_000), 1..20),
+    ) {
+        let e = Env::default();
+        let s = setup(&e, &grant);
+        let mut funded = grant.amount;
+
+        for op in ops {
+            let succeeded = match &op {
+                Op::Advance(seconds) => {
+                    e.ledger().set_timestamp(e.ledger().timestamp() + seconds);
+                    true
+                }
+                Op::Claim => s.client.try_claim().is_ok(),
+                Op::TopUp(amount) => {
+                    // Periodic grants refuse this; their per-period amount is fixed
+                    let ok = s.client.try_top_up(&s.funder, amount, &None).is_ok();
+                    if ok {
+                        funded += amount;
+                    }
+                    ok
+                }
+                Op::AddTranche(tranche, delay) => {
+                    let start_time = e.ledger().timestamp() + delay;
+                    let terms = TrancheTerms {
+                        start_time,
+                        cliff_seconds: tranche.cliff_seconds,
+                        duration_seconds: tranche.duration_seconds,
+                        schedule: schedule(&e, &tranche.plan, start_time),
+                        cliff_mode: tranche.cliff_mode,
+                    };
+                    let ok = s.client.try_top_up(&s.funder, &tranche.amount, &Some(terms)).is_ok();
+                    if ok {
+                        funded += tranche.amount;
+                    }
+                    ok
+                }
+                Op::ClawbackPartial(amount) => s.client.try_clawback_partial(amount).is_ok(),
+                Op::Clawback => s.client.try_clawback().is_ok(),
+                Op::Pause => s.client.try_pause_vesting().is_ok(),
+                Op::Resume => s.client.try_resume_vesting().is_ok(),
+            };
+
+            let config = s.client.get_config();
+            let claimed = s.token.balance(&s.beneficiary);
+            let clawed_back = s.token.balance(&s.clawback_admin);
+            let held = s.token.balance(&s.client.address);
+
+            prop_assert_eq!(claimed + held + clawed_back, funded, "after {:?} (succeeded: {})", op, succeeded);
+            prop_assert_eq!(config.claimed_amount, claimed);
+            prop_assert_eq!(config.clawed_back_amount, clawed_back);
+            prop_assert!(s.client.get_vested_amount() >= config.claimed_amount);
+        }
+    }
+}
@@ -1,9 +1,112 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contracttype, token, Address, Env};
+// `initialize` takes the full grant terms, and the generated client mirrors that signature.
+#![allow(clippy::too_many_arguments)]
+use payd_common::{admin, council, math, storage};
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, token, Address, Env, IntoVal, Symbol, Val, Vec};
+
+pub use payd_common::math::TOTAL_BASIS_POINTS;
+
+/// How far from the current ledger time a grant, or a tranche added to it, may start.
+/// Chosen by the funder at `initialize` to catch mistyped dates.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct StartBounds {
+    /// How far in the past a start may be (backdated hires).
+    pub max_backdate_seconds: u64,
+    /// How far in the future a start may be.
+    pub max_delay_seconds: u64,
+}
+
+/// Five years back and one year ahead.
+pub const DEFAULT_START_BOUNDS: StartBounds = StartBounds {
+    max_backdate_seconds: 5 * 365 * 24 * 60 * 60,
+    max_delay_seconds: 365 * 24 * 60 * 60,
+};
+
+/// What happens when the cliff passes.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CliffMode {
+    /// The schedule runs from `start_time`; everything accrued before the cliff unlocks at once.
+    CatchUp,
+    /// Nothing accrues before the cliff; the schedule runs from the cliff to the end.
+    StartAtCliff,
+}
+
+/// How tokens unlock between the cliff and the end of the grant.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VestingSchedule {
+    /// Per-second release over the whole duration.
+    Linear,
+    /// `(period_seconds, amount_per_period)`: a fixed amount unlocks at the end of every period.
+    Periodic(u64, i128),
+    /// `(timestamp, basis_points)` unlocks, in increasing timestamp order, summing to 10000.
+    Milestones(Vec<(u64, u32)>),
+    /// `(attester, milestones)`: deliverables unlocked by the attester calling `attest_milestone`,
+    /// regardless of time. Basis points sum to 10000.
+    Attested(Address, Vec<AttestedMilestone>),
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MilestoneStatus {
+    Pending,
+    Attested,
+    /// Missed its deadline and returned to the clawback admin.
+    Forfeited,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestedMilestone {
+    pub id: u32,
+    pub basis_points: u32,
+    /// After this time an unattested milestone can no longer be attested and may be clawed back.
+    pub deadline: Option<u64>,
+    pub status: MilestoneStatus,
+}
+
+/// Most tranches `top_up` will stack on one grant; every vesting calculation walks them all.
+pub const MAX_TRANCHES: u32 = 20;
+
+/// Schedule of the original grant at `initialize`, or of a tranche added by `top_up`
+/// independently of it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TrancheTerms {
+    pub start_time: u64,
+    pub cliff_seconds: u64,
+    pub duration_seconds: u64,
+    pub schedule: VestingSchedule,
+    pub cliff_mode: CliffMode,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Tranche {
+    pub amount: i128,
+    pub terms: TrancheTerms,
+    /// Time the grant had spent paused when the tranche was added. Only pauses after
+    /// that delay the tranche.
+    pub paused_offset: u64,
+}
+
+/// Snapshot comparing the contract's token balance with what it still owes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Reconciliation {
+    pub balance: i128,
+    pub liability: i128,
+    pub surplus: i128,
+    pub shortfall: i128,
+}
 
 #[contracttype]
 #[derive(Clone)]
 pub struct VestingConfig {
+    /// Funded the grant at `initialize`; may add to it with `top_up`, as may the clawback admin.
+    pub funder: Address,
     pub beneficiary: Address,
     pub token: Address,
     pub start_time: u64,
@@ -13,11 +116,21 @@ pub struct VestingConfig {
     pub claimed_amount: i128,
     pub clawback_admin: Address,
     pub is_active: bool,
+    pub schedule: VestingSchedule,
+    pub cliff_mode: CliffMode,
+    pub clawed_back_amount: i128,
+    pub termination_time: Option<u64>,
+    pub paused_at: Option<u64>,
+    pub paused_seconds: u64,
+    pub tranches: Vec<Tranche>,
+    pub start_bounds: StartBounds,
 }
 
 #[contracttype]
 pub enum DataKey {
     Config,
+    Keeper,
+    ClaimHolder,
 }
 
 #[contract]
@@ -30,71 +143,222 @@ impl VestingContract {
         funder: Address,
         beneficiary: Address,
         token: Address,
-        start_time: u64,
-        cliff_seconds: u64,
-        duration_seconds: u64,
         amount: i128,
         clawback_admin: Address,
+        terms: TrancheTerms,
+        start_bounds: StartBounds,
     ) {
-        if e.storage().instance().has(&DataKey::Config) {
-            panic!("Already initialized");
-        }
+        storage::guard_init(&e, &DataKey::Config);
         
         funder.require_auth();
 
-        if duration_seconds < cliff_seconds {
-            panic!("Duration must be greater than or equal to cliff");
-        }
-        
         if amount <= 0 {
              panic!("Amount must be positive");
         }
 
+        Self::validate_terms(&e, &terms, &start_bounds);
+
         let config = VestingConfig {
+            funder: funder.clone(),
             beneficiary: beneficiary.clone(),
             token: token.clone(),
-            start_time,
-            cliff_seconds,
-            duration_seconds,
+            start_time: terms.start_time,
+            cliff_seconds: terms.cliff_seconds,
+            duration_seconds: terms.duration_seconds,
             total_amount: amount,
             claimed_amount: 0,
             clawback_admin,
             is_active: true,
+            schedule: terms.schedule,
+            cliff_mode: terms.cliff_mode,
+            clawed_back_amount: 0,
+            termination_time: None,
+            paused_at: None,
+            paused_seconds: 0,
+            tranches: Vec::new(&e),
+            start_bounds,
         };
 
         e.storage().instance().set(&DataKey::Config, &config);
+        // The clawback admin is the grant's `payd_common::admin`, which lets it hand clawbacks to a council
+        admin::init_admin(&e, &config.clawback_admin);
+        storage::extend_instance(&e);
         
         // Transfer tokens from funder to contract
         let client = token::Client::new(&e, &token);
         client.transfer(&funder, &e.current_contract_address(), &amount);
+
+        e.events().publish((symbol_short!("granted"), beneficiary), (token, amount));
     }
 
+    /// Adds `amount` to the grant. With `terms == None` it vests on the original schedule
+    /// (not available for periodic grants, whose per-period amount is fixed); otherwise it is
+    /// stacked as a new tranche with its own start, cliff and duration, up to `MAX_TRANCHES`.
+    /// Only the original funder or the clawback admin can top up, and not once a scheduled
+    /// termination has taken effect.
+    pub fn top_up(e: Env, funder: Address, amount: i128, terms: Option<TrancheTerms>) {
+        let mut config: VestingConfig = storage::read_instance(&e, &DataKey::Config);
+
+        funder.require_auth();
+        if funder != config.funder && funder != config.clawback_admin {
+            panic!("Only the funder or clawback admin can top up");
+        }
+
+        if !config.is_active {
+            panic!("Already revoked/inactive");
+        }
+
+        if config.termination_time.is_some_and(|t| t <= e.ledger().timestamp()) {
+            panic!("Already terminated");
+        }
+
+        if amount <= 0 {
+            panic!("Amount must be positive");
+        }
+
+        match terms {
+            None => {
+                match config.schedule {
+                    VestingSchedule::Periodic(_, _) => panic!("Periodic grants need tranche terms to top up"),
+                    // Raising the total would also raise milestones that were already attested or forfeited
+                    VestingSchedule::Attested(_, _) => panic!("Attested grants need tranche terms to top up"),
+                    _ => {}
+                }
+                config.total_amount = math::add(&e, config.total_amount, amount);
+            }
+            Some(terms) => {
+                if let VestingSchedule::Attested(_, _) = terms.schedule {
+                    panic!("Attested schedules cannot be added as tranches");
+                }
+                if config.tranches.len() >= MAX_TRANCHES {
+                    panic!("Too many tranches");
+                }
+                Self::validate_terms(&e, &terms, &config.start_bounds);
+                let paused_offset = Self::paused_so_far(&e, &config);
+                config.tranches.push_back(Tranche { amount, terms, paused_offset });
+            }
+        }
+        e.storage().instance().set(&DataKey::Config, &config);
+
+        let client = token::Client::new(&e, &config.token);
+        client.transfer(&funder, &e.current_contract_address(), &amount);
+
+        e.events().publish((symbol_short!("top_up"), funder), amount);
+    }
+
+    /// Claims everything vested so far to the claim right holder (the beneficiary unless transferred).
     pub fn claim(e: Env) {
-        let mut config: VestingConfig = e.storage().instance().get(&DataKey::Config).expect("Not initialized");
+        let mut config: VestingConfig = storage::read_instance(&e, &DataKey::Config);
         
-        config.beneficiary.require_auth();
+        let holder = Self::claim_holder(&e, &config);
+        holder.require_auth();
         
-        let vested = Self::calc_vested(&e, &config);
-        let claimable = vested - config.claimed_amount;
+        Self::pay_out(&e, &mut config, &holder);
+    }
 
-        if claimable <= 0 {
-            // Nothing to claim, just return
-            return;
+    /// Claims everything vested so far into a different wallet (claim right holder only).
+    pub fn claim_to(e: Env, destination: Address) {
+        let mut config: VestingConfig = storage::read_instance(&e, &DataKey::Config);
+
+        Self::claim_holder(&e, &config).require_auth();
+
+        Self::pay_out(&e, &mut config, &destination);
+    }
+
+    /// Lets the keeper chosen by the holder trigger a claim. Funds always go to the claim right holder.
+    pub fn claim_for(e: Env) {
+        let mut config: VestingConfig = storage::read_instance(&e, &DataKey::Config);
+
+        let keeper: Address = e.storage().instance().get(&DataKey::Keeper).expect("No keeper set");
+        keeper.require_auth();
+
+        let holder = Self::claim_holder(&e, &config);
+        Self::pay_out(&e, &mut config, &holder);
+    }
+
+    /// Claims to the holder and hands the tokens straight to another contract by calling
+    /// `contract.function(token, holder, amount)`, e.g. `RevenueSplitContract::distribute`.
+    /// The claim is recorded before the call, so the target cannot claim the same tokens twice.
+    pub fn claim_to_contract(e: Env, contract: Address, function: Symbol) -> i128 {
+        let mut config: VestingConfig = storage::read_instance(&e, &DataKey::Config);
+
+        let holder = Self::claim_holder(&e, &config);
+        holder.require_auth();
+
+        let amount = Self::pay_out(&e, &mut config, &holder);
+        if amount > 0 {
+            let args: Vec<Val> = (config.token, holder, amount).into_val(&e);
+            e.invoke_contract::<Val>(&contract, &function, args);
         }
 
-        // Update state
-        config.claimed_amount += claimable;
+        amount
+    }
+
+    /// Sets or removes the keeper allowed to call `claim_for` (claim right holder only).
+    pub fn set_keeper(e: Env, keeper: Option<Address>) {
+        let config: VestingConfig = storage::read_instance(&e, &DataKey::Config);
+
+        let holder = Self::claim_holder(&e, &config);
+        holder.require_auth();
+
+        match &keeper {
+            Some(keeper) => e.storage().instance().set(&DataKey::Keeper, keeper),
+            None => e.storage().instance().remove(&DataKey::Keeper),
+        }
+
+        e.events().publish((symbol_short!("keeper"), holder), keeper);
+    }
+
+    /// Hands the right to claim vested tokens to `new_holder` (current holder only), e.g. to
+    /// pledge a grant as collateral. The beneficiary stays on record for everything else,
+    /// and any keeper set by the previous holder is cleared.
+    pub fn transfer_claim_right(e: Env, new_holder: Address) {
+        let config: VestingConfig = storage::read_instance(&e, &DataKey::Config);
+
+        let holder = Self::claim_holder(&e, &config);
+        holder.require_auth();
+
+        if new_holder == config.beneficiary {
+            e.storage().instance().remove(&DataKey::ClaimHolder);
+        } else {
+            e.storage().instance().set(&DataKey::ClaimHolder, &new_holder);
+        }
+        e.storage().instance().remove(&DataKey::Keeper);
+
+        e.events().publish((symbol_short!("claim_xfr"), holder), new_holder);
+    }
+
+    /// Moves the grant to a new beneficiary. Needs both the current beneficiary and the clawback admin.
+    /// Unless the claim right was transferred away, it moves too and any keeper is cleared.
+    pub fn change_beneficiary(e: Env, new_beneficiary: Address) {
+        let mut config: VestingConfig = storage::read_instance(&e, &DataKey::Config);
+
+        config.beneficiary.require_auth();
+        config.clawback_admin.require_auth();
+
+        let old_beneficiary = config.beneficiary;
+        config.beneficiary = new_beneficiary.clone();
         e.storage().instance().set(&DataKey::Config, &config);
+        if !e.storage().instance().has(&DataKey::ClaimHolder) {
+            e.storage().instance().remove(&DataKey::Keeper);
+        }
 
-        // Transfer tokens
-        let client = token::Client::new(&e, &config.token);
-        client.transfer(&e.current_contract_address(), &config.beneficiary, &claimable);
+        e.events().publish((symbol_short!("ben_upd"), old_beneficiary), new_beneficiary);
+    }
+
+    pub fn get_claim_holder(e: Env) -> Address {
+        let config: VestingConfig = storage::read_instance(&e, &DataKey::Config);
+        Self::claim_holder(&e, &config)
+    }
+
+    pub fn get_keeper(e: Env) -> Option<Address> {
+        e.storage().instance().get(&DataKey::Keeper)
     }
     
+    /// Ends the grant and returns everything unvested to the clawback admin (clawback admin only,
+    /// or the council instead once the day's clawbacks pass the threshold; see `set_council`).
     pub fn clawback(e: Env) {
-        let mut config: VestingConfig = e.storage().instance().get(&DataKey::Config).expect("Not initialized");
-        
-        config.clawback_admin.require_auth();
+        let mut config: VestingConfig = storage::read_instance(&e, &DataKey::Config);
         
         if !config.is_active {
             panic!("Already revoked/inactive");
@@ -103,60 +367,590 @@ impl VestingContract {
         // Calculate what has vested so far
         let vested = Self::calc_vested(&e, &config);
         
-        // The unvested amount is the total scheduled minus what has vested
-        let unvested = config.total_amount - vested;
+        // The unvested amount is what is still scheduled (after partial clawbacks) minus what has vested
+        let unvested = Self::unvested(&e, &config, vested);
         
         // Update config to stop future vesting
         // We set total_amount to vested, so effectively the grant is capped at what was vested at this moment
         config.total_amount = vested;
+        config.tranches = Vec::new(&e);
+        config.clawed_back_amount = math::add(&e, config.clawed_back_amount, unvested);
         config.is_active = false;
         e.storage().instance().set(&DataKey::Config, &config);
 
-        if unvested > 0 {
+        // Vested tokens are paid first: if the balance is short, the admin absorbs the difference
+        let client = token::Client::new(&e, &config.token);
+        let balance = client.balance(&e.current_contract_address());
+        let owed = math::sub(&e, vested, config.claimed_amount);
+        let returned = unvested.min(math::sub(&e, balance, owed)).max(0);
+        Self::require_clawback_auth(&e, &config, returned);
+
+        if returned > 0 {
             // Return unvested tokens to admin
-            let client = token::Client::new(&e, &config.token);
-            client.transfer(&e.current_contract_address(), &config.clawback_admin, &unvested);
+            client.transfer(&e.current_contract_address(), &config.clawback_admin, &returned);
+        }
+
+        // `unvested` is what the grant gave up; `returned` is what actually came back
+        e.events().publish((symbol_short!("clawback"), config.clawback_admin), (unvested, returned));
+    }
+
+    /// Returns `amount` of the unvested tokens to the clawback admin while the grant keeps vesting.
+    /// The schedule keeps its pace and simply stops once the reduced total has vested.
+    /// Authorized like `clawback`.
+    pub fn clawback_partial(e: Env, amount: i128) {
+        let mut config: VestingConfig = storage::read_instance(&e, &DataKey::Config);
+
+        if !config.is_active {
+            panic!("Already revoked/inactive");
+        }
+
+        if amount <= 0 {
+            panic!("Amount must be positive");
+        }
+        Self::require_clawback_auth(&e, &config, amount);
+
+        Self::claw_back_unvested(&e, &mut config, amount);
+
+        e.events().publish((symbol_short!("claw_part"), config.clawback_admin), amount);
+    }
+
+    /// Marks an attested-schedule milestone as delivered (attester only).
+    pub fn attest_milestone(e: Env, id: u32) {
+        let mut config: VestingConfig = storage::read_instance(&e, &DataKey::Config);
+
+        let VestingSchedule::Attested(attester, mut milestones) = config.schedule.clone() else {
+            panic!("Grant has no attested milestones");
+        };
+        attester.require_auth();
+
+        if !config.is_active {
+            panic!("Already revoked/inactive");
+        }
+        if config.paused_at.is_some() {
+            panic!("Vesting paused");
+        }
+
+        let now = e.ledger().timestamp();
+        if config.termination_time.is_some_and(|t| t <= now) {
+            panic!("Already terminated");
+        }
+
+        let index = milestones.iter().position(|m| m.id == id).expect("Milestone not found") as u32;
+        let mut milestone = milestones.get_unchecked(index);
+        if milestone.status != MilestoneStatus::Pending {
+            panic!("Milestone not pending");
+        }
+        if milestone.deadline.is_some_and(|deadline| now > deadline) {
+            panic!("Milestone deadline passed");
+        }
+
+        milestone.status = MilestoneStatus::Attested;
+        milestones.set(index, milestone);
+        config.schedule = VestingSchedule::Attested(attester.clone(), milestones);
+        e.storage().instance().set(&DataKey::Config, &config);
+
+        e.events().publish((symbol_short!("attested"), attester), id);
+    }
+
+    /// Claws back every pending milestone whose deadline has passed, authorized like `clawback`.
+    /// Returns the amount sent back to the admin.
+    pub fn clawback_expired_milestones(e: Env) -> i128 {
+        let mut config: VestingConfig = storage::read_instance(&e, &DataKey::Config);
+
+        if !config.is_active {
+            panic!("Already revoked/inactive");
+        }
+
+        let VestingSchedule::Attested(attester, mut milestones) = config.schedule.clone() else {
+            panic!("Grant has no attested milestones");
+        };
+
+        let now = e.ledger().timestamp();
+        let mut forfeited_bp: u32 = 0;
+        for index in 0..milestones.len() {
+            let mut milestone = milestones.get_unchecked(index);
+            if milestone.status == MilestoneStatus::Pending && milestone.deadline.is_some_and(|deadline| now > deadline) {
+                milestone.status = MilestoneStatus::Forfeited;
+                forfeited_bp = math::add_bps(&e, forfeited_bp, milestone.basis_points);
+                milestones.set(index, milestone);
+            }
+        }
+
+        if forfeited_bp == 0 {
+            panic!("No expired milestones");
+        }
+
+        // A partial clawback may already have taken some of what these milestones were worth
+        let vested = Self::calc_vested(&e, &config);
+        let amount = math::bps(&e, config.total_amount, forfeited_bp).min(Self::unvested(&e, &config, vested));
+        Self::require_clawback_auth(&e, &config, amount);
+        config.schedule = VestingSchedule::Attested(attester, milestones);
+        Self::claw_back_unvested(&e, &mut config, amount);
+
+        e.events().publish((symbol_short!("forfeit"), config.clawback_admin), amount);
+
+        amount
+    }
+
+    /// Stops accrual at `termination_time` (e.g. the end of a notice period). Tokens vested by then stay
+    /// claimable; the admin recovers the rest with `clawback` once the date has passed.
+    pub fn schedule_termination(e: Env, termination_time: u64) {
+        let mut config: VestingConfig = storage::read_instance(&e, &DataKey::Config);
+
+        config.clawback_admin.require_auth();
+
+        if !config.is_active {
+            panic!("Already revoked/inactive");
+        }
+
+        let now = e.ledger().timestamp();
+        if config.termination_time.is_some_and(|t| t <= now) {
+            panic!("Already terminated");
+        }
+        if termination_time < now {
+            panic!("Termination must not be in the past");
         }
+
+        config.termination_time = Some(termination_time);
+        e.storage().instance().set(&DataKey::Config, &config);
+
+        e.events().publish((symbol_short!("term_set"), config.beneficiary), termination_time);
+    }
+
+    /// Stops the vesting clock, e.g. for unpaid leave (clawback admin only).
+    pub fn pause_vesting(e: Env) {
+        let mut config: VestingConfig = storage::read_instance(&e, &DataKey::Config);
+
+        config.clawback_admin.require_auth();
+
+        if !config.is_active {
+            panic!("Already revoked/inactive");
+        }
+        if config.paused_at.is_some() {
+            panic!("Vesting already paused");
+        }
+
+        let now = e.ledger().timestamp();
+        if now < config.start_time {
+            panic!("Vesting has not started");
+        }
+        if config.termination_time.is_some_and(|t| t <= now) {
+            panic!("Already terminated");
+        }
+
+        config.paused_at = Some(now);
+        e.storage().instance().set(&DataKey::Config, &config);
+
+        e.events().publish((symbol_short!("paused"), config.beneficiary), now);
+    }
+
+    /// Restarts the vesting clock. The paused time is added to `paused_seconds`,
+    /// which pushes every remaining unlock, and the end of the grant, back by that much.
+    /// Tranches added during the pause are only pushed back by the part after they were added.
+    pub fn resume_vesting(e: Env) {
+        let mut config: VestingConfig = storage::read_instance(&e, &DataKey::Config);
+
+        config.clawback_admin.require_auth();
+
+        if !config.is_active {
+            panic!("Already revoked/inactive");
+        }
+
+        let paused_at = config.paused_at.expect("Vesting not paused");
+
+        // Time after a termination date does not count as paused; accrual has stopped anyway
+        let mut now = e.ledger().timestamp();
+        if let Some(termination_time) = config.termination_time {
+            now = now.min(termination_time);
+        }
+
+        config.paused_seconds = config.paused_seconds
+            .checked_add(now.saturating_sub(paused_at))
+            .expect("Vesting end time overflows");
+        config.paused_at = None;
+        e.storage().instance().set(&DataKey::Config, &config);
+
+        e.events().publish((symbol_short!("resumed"), config.beneficiary), config.paused_seconds);
+    }
+
+    /// When the last tranche finishes vesting, including time spent paused so far.
+    pub fn get_end_time(e: Env) -> u64 {
+        let config: VestingConfig = storage::read_instance(&e, &DataKey::Config);
+
+        let paused_seconds = Self::paused_so_far(&e, &config);
+
+        let mut end_time = Self::shifted_end(config.start_time, config.duration_seconds, paused_seconds);
+        for tranche in config.tranches.iter() {
+            let tranche_paused = paused_seconds.checked_sub(tranche.paused_offset).expect("Tranche paused after the grant");
+            end_time = end_time.max(Self::shifted_end(tranche.terms.start_time, tranche.terms.duration_seconds, tranche_paused));
+        }
+
+        end_time
+    }
+
+    /// Compares the actual token balance with the outstanding liability (everything granted and
+    /// not yet claimed or clawed back). Tokens sent directly to the contract show up as surplus;
+    /// tokens removed by the issuer show up as shortfall.
+    pub fn reconcile(e: Env) -> Reconciliation {
+        let config: VestingConfig = storage::read_instance(&e, &DataKey::Config);
+
+        let balance = token::Client::new(&e, &config.token).balance(&e.current_contract_address());
+        let liability = Self::liability(&e, &config);
+        let surplus = math::sub(&e, balance, liability);
+
+        Reconciliation {
+            balance,
+            liability,
+            surplus: surplus.max(0),
+            shortfall: math::sub(&e, 0, surplus).max(0),
+        }
+    }
+
+    /// Sends any balance above the outstanding liability to `to`, authorized like `clawback`.
+    pub fn sweep_excess(e: Env, to: Address) -> i128 {
+        let config: VestingConfig = storage::read_instance(&e, &DataKey::Config);
+
+        let client = token::Client::new(&e, &config.token);
+        let surplus = math::sub(&e, client.balance(&e.current_contract_address()), Self::liability(&e, &config));
+        if surplus <= 0 {
+            panic!("No excess to sweep");
+        }
+        Self::require_clawback_auth(&e, &config, surplus);
+
+        client.transfer(&e.current_contract_address(), &to, &surplus);
+
+        e.events().publish((symbol_short!("swept"), to), surplus);
+
+        surplus
+    }
+
+    /// Hands clawbacks and sweeps to `council`, usually a `multisig`, once the day's total passes
+    /// the threshold (clawback admin only until a council is set, then the council only).
+    pub fn set_council(e: Env, council: Address) {
+        council::set_council(&e, &council);
+
+        e.events().publish((symbol_short!("council"),), council);
+    }
+
+    /// Sets how much of the grant's token the clawback admin may take back per day without the
+    /// council. Once a council is set, a grant without a threshold needs it for every clawback.
+    pub fn set_threshold(e: Env, threshold: i128) {
+        let config: VestingConfig = storage::read_instance(&e, &DataKey::Config);
+        council::set_threshold(&e, &config.token, threshold);
+
+        e.events().publish((symbol_short!("threshold"), config.token), threshold);
+    }
+
+    pub fn get_council(e: Env) -> Option<Address> {
+        council::get_council(&e)
+    }
+
+    pub fn get_threshold(e: Env) -> Option<i128> {
+        let config: VestingConfig = storage::read_instance(&e, &DataKey::Config);
+        council::get_threshold(&e, &config.token)
     }
 
     pub fn get_vested_amount(e: Env) -> i128 {
-        let config: VestingConfig = e.storage().instance().get(&DataKey::Config).expect("Not initialized");
+        let config: VestingConfig = storage::read_instance(&e, &DataKey::Config);
         Self::calc_vested(&e, &config)
     }
     
     pub fn get_claimable_amount(e: Env) -> i128 {
-        let config: VestingConfig = e.storage().instance().get(&DataKey::Config).expect("Not initialized");
+        let config: VestingConfig = storage::read_instance(&e, &DataKey::Config);
         let vested = Self::calc_vested(&e, &config);
-        vested - config.claimed_amount
+        math::sub(&e, vested, config.claimed_amount)
     }
     
     pub fn get_config(e: Env) -> VestingConfig {
-        e.storage().instance().get(&DataKey::Config).expect("Not initialized")
+        storage::read_instance(&e, &DataKey::Config)
+    }
+
+    fn claim_holder(e: &Env, config: &VestingConfig) -> Address {
+        e.storage()
+            .instance()
+            .get(&DataKey::ClaimHolder)
+            .unwrap_or(config.beneficiary.clone())
+    }
+
+    /// Pays the claimable amount to `destination` and returns how much was sent.
+    fn pay_out(e: &Env, config: &mut VestingConfig, destination: &Address) -> i128 {
+        let vested = Self::calc_vested(e, config);
+        let claimable = math::sub(e, vested, config.claimed_amount);
+
+        if claimable <= 0 {
+            // Nothing to claim, just return
+            return 0;
+        }
+
+        // If the token balance was reduced from outside (e.g. an issuer clawback), pay what is
+        // there and leave the rest claimable instead of failing the whole claim
+        let client = token::Client::new(e, &config.token);
+        let balance = client.balance(&e.current_contract_address());
+        let payout = claimable.min(balance);
+
+        if payout < claimable {
+            e.events().publish((symbol_short!("shortfall"), config.beneficiary.clone()), math::sub(e, claimable, payout));
+        }
+
+        if payout <= 0 {
+            return 0;
+        }
+
+        // Update state
+        config.claimed_amount = math::add(e, config.claimed_amount, payout);
+        e.storage().instance().set(&DataKey::Config, config);
+        storage::extend_instance(e);
+
+        // Transfer tokens
+        client.transfer(&e.current_contract_address(), destination, &payout);
+
+        e.events().publish((symbol_short!("claimed"), destination.clone()), payout);
+
+        payout
+    }
+
+    /// Requires the council if taking `amount` out of the grant today needs it, and the clawback
+    /// admin otherwise.
+    fn require_clawback_auth(e: &Env, config: &VestingConfig, amount: i128) {
+        if !council::require_for_today(e, &config.token, amount) {
+            config.clawback_admin.require_auth();
+        }
+    }
+
+    /// Returns `amount` of unvested tokens to the clawback admin and saves `config`.
+    fn claw_back_unvested(e: &Env, config: &mut VestingConfig, amount: i128) {
+        let vested = Self::calc_vested(e, config);
+        if amount > Self::unvested(e, config, vested) {
+            panic!("Amount exceeds unvested balance");
+        }
+
+        let client = token::Client::new(e, &config.token);
+        let balance = client.balance(&e.current_contract_address());
+        if amount > math::sub(e, balance, math::sub(e, vested, config.claimed_amount)) {
+            panic!("Amount exceeds available balance");
+        }
+
+        config.clawed_back_amount = math::add(e, config.clawed_back_amount, amount);
+        e.storage().instance().set(&DataKey::Config, config);
+
+        if amount > 0 {
+            client.transfer(&e.current_contract_address(), &config.clawback_admin, &amount);
+        }
+    }
+
+    fn liability(e: &Env, config: &VestingConfig) -> i128 {
+        let total = if config.is_active {
+            math::sub(e, Self::total_granted(e, config), config.clawed_back_amount)
+        } else {
+            // After a clawback total_amount is the final vested amount
+            config.total_amount
+        };
+        math::sub(e, total, config.claimed_amount)
+    }
+
+    /// What is still scheduled to vest, after partial clawbacks, beyond `vested`.
+    fn unvested(e: &Env, config: &VestingConfig, vested: i128) -> i128 {
+        let scheduled = math::sub(e, Self::total_granted(e, config), config.clawed_back_amount);
+        math::sub(e, scheduled, vested)
     }
 
     fn calc_vested(e: &Env, config: &VestingConfig) -> i128 {
+        if !config.is_active {
+            // Frozen by `clawback`: total_amount already holds the final vested amount
+            return config.total_amount;
+        }
+
+        // Paused time does not count towards the schedule
+        let paused_seconds = Self::paused_so_far(e, config);
+        let now = Self::accrual_time(e, config).checked_sub(paused_seconds).expect("Paused longer than vesting ran");
+
+        let base = TrancheTerms {
+            start_time: config.start_time,
+            cliff_seconds: config.cliff_seconds,
+            duration_seconds: config.duration_seconds,
+            schedule: config.schedule.clone(),
+            cliff_mode: config.cliff_mode,
+        };
+        let mut vested = Self::schedule_vested(e, &base, config.total_amount, now);
+        for tranche in config.tranches.iter() {
+            // Pauses from before the tranche was added don't hold it back
+            let tranche_now = now.checked_add(tranche.paused_offset).expect("Vesting end time overflows");
+            vested = math::add(e, vested, Self::schedule_vested(e, &tranche.terms, tranche.amount, tranche_now));
+        }
+
+        // Partial clawbacks cut the end of the schedule off, never what has already vested
+        vested.min(math::sub(e, Self::total_granted(e, config), config.clawed_back_amount))
+    }
+
+    /// The current ledger time, capped at the termination date once accrual has stopped there.
+    fn accrual_time(e: &Env, config: &VestingConfig) -> u64 {
         let now = e.ledger().timestamp();
-        
-        if now < config.start_time + config.cliff_seconds {
+        config.termination_time.map_or(now, |termination_time| now.min(termination_time))
+    }
+
+    /// Total time spent paused, including a pause still in progress.
+    fn paused_so_far(e: &Env, config: &VestingConfig) -> u64 {
+        let ongoing = config.paused_at.map_or(0, |paused_at| Self::accrual_time(e, config).saturating_sub(paused_at));
+        config.paused_seconds.checked_add(ongoing).expect("Vesting end time overflows")
+    }
+
+    fn shifted_end(start_time: u64, duration_seconds: u64, paused_seconds: u64) -> u64 {
+        start_time
+            .checked_add(duration_seconds)
+            .and_then(|end_time| end_time.checked_add(paused_seconds))
+            .expect("Vesting end time overflows")
+    }
+
+    /// Everything ever put into the grant: the original amount plus all tranches.
+    fn total_granted(e: &Env, config: &VestingConfig) -> i128 {
+        let mut total = config.total_amount;
+        for tranche in config.tranches.iter() {
+            total = math::add(e, total, tranche.amount);
+        }
+        total
+    }
+
+    fn schedule_vested(e: &Env, terms: &TrancheTerms, total: i128, now: u64) -> i128 {
+        // Attested milestones unlock on delivery, not on the clock
+        if let VestingSchedule::Attested(_, milestones) = &terms.schedule {
+            let mut unlocked_bp: u32 = 0;
+            for milestone in milestones.iter() {
+                if milestone.status == MilestoneStatus::Attested {
+                    unlocked_bp = math::add_bps(e, unlocked_bp, milestone.basis_points);
+                }
+            }
+            return math::bps(e, total, unlocked_bp);
+        }
+
+        let cliff_end = terms.start_time.checked_add(terms.cliff_seconds).expect("Vesting end time overflows");
+        let end_time = terms.start_time.checked_add(terms.duration_seconds).expect("Vesting end time overflows");
+
+        if now < cliff_end {
             return 0;
         }
         
-        if now >= config.start_time + config.duration_seconds || !config.is_active {
-            return config.total_amount;
+        if now >= end_time {
+            return total;
         }
         
-        // Linear vesting
-        let time_elapsed = now - config.start_time;
-        
-        // vested = total * elapsed / duration
-        // We use i128 for calculation to avoid overflow
-        let total = config.total_amount;
-        let elapsed = time_elapsed as i128;
-        let duration = config.duration_seconds as i128;
-        
-        total.checked_mul(elapsed).unwrap().checked_div(duration).unwrap()
+        // Here cliff_end <= now < end_time, so the curve below always has a positive length
+        let (curve_start, curve_seconds) = match terms.cliff_mode {
+            CliffMode::CatchUp => (terms.start_time, terms.duration_seconds),
+            CliffMode::StartAtCliff => (cliff_end, terms.duration_seconds - terms.cliff_seconds),
+        };
+        let elapsed = now - curve_start;
+
+        match &terms.schedule {
+            VestingSchedule::Linear => {
+                // vested = total * elapsed / duration
+                // We use i128 for calculation to avoid overflow
+                let elapsed = elapsed as i128;
+                let duration = curve_seconds as i128;
+
+                math::mul_div(e, total, elapsed, duration)
+            }
+            VestingSchedule::Periodic(period_seconds, amount_per_period) => {
+                let periods = (elapsed / period_seconds) as i128;
+                let vested = math::mul(e, periods, *amount_per_period);
+                vested.min(total)
+            }
+            // Milestones are absolute timestamps, so the cliff mode only gates them
+            VestingSchedule::Milestones(milestones) => {
+                let mut unlocked_bp: u32 = 0;
+                for (timestamp, basis_points) in milestones.iter() {
+                    if now < timestamp {
+                        break;
+                    }
+                    unlocked_bp = math::add_bps(e, unlocked_bp, basis_points);
+                }
+                math::bps(e, total, unlocked_bp)
+            }
+            VestingSchedule::Attested(_, _) => unreachable!(),
+        }
+    }
+
+    fn validate_terms(e: &Env, terms: &TrancheTerms, bounds: &StartBounds) {
+        if terms.duration_seconds == 0 {
+            panic!("Duration must be positive");
+        }
+
+        if terms.duration_seconds < terms.cliff_seconds {
+            panic!("Duration must be greater than or equal to cliff");
+        }
+
+        let end_time = terms.start_time.checked_add(terms.duration_seconds).expect("Vesting end time overflows");
+
+        let now = e.ledger().timestamp();
+        if terms.start_time < now && now - terms.start_time > bounds.max_backdate_seconds {
+            panic!("Start time too far in the past");
+        }
+        if terms.start_time > now && terms.start_time - now > bounds.max_delay_seconds {
+            panic!("Start time too far in the future");
+        }
+
+        match &terms.schedule {
+            VestingSchedule::Linear => {}
+            VestingSchedule::Periodic(period_seconds, amount_per_period) => {
+                if *period_seconds == 0 {
+                    panic!("Period must be positive");
+                }
+                if *amount_per_period <= 0 {
+                    panic!("Amount per period must be positive");
+                }
+            }
+            VestingSchedule::Milestones(milestones) => {
+                if milestones.is_empty() {
+                    panic!("Milestones must not be empty");
+                }
+
+                let mut total_bp: u32 = 0;
+                let mut previous: Option<u64> = None;
+                for (timestamp, basis_points) in milestones.iter() {
+                    if timestamp < terms.start_time || timestamp > end_time {
+                        panic!("Milestone outside vesting period");
+                    }
+                    if previous.is_some_and(|p| timestamp <= p) {
+                        panic!("Milestones must be in increasing order");
+                    }
+                    if basis_points == 0 {
+                        panic!("Milestone must unlock a positive share");
+                    }
+                    total_bp = math::add_bps(e, total_bp, basis_points);
+                    previous = Some(timestamp);
+                }
+
+                if total_bp != TOTAL_BASIS_POINTS {
+                    panic!("Milestones must sum to 10000 basis points");
+                }
+            }
+            VestingSchedule::Attested(_, milestones) => {
+                if milestones.is_empty() {
+                    panic!("Milestones must not be empty");
+                }
+
+                let mut total_bp: u32 = 0;
+                for (i, milestone) in milestones.iter().enumerate() {
+                    if milestones.iter().skip(i + 1).any(|other| other.id == milestone.id) {
+                        panic!("Duplicate milestone id");
+                    }
+                    if milestone.status != MilestoneStatus::Pending {
+                        panic!("Milestones must start pending");
+                    }
+                    if milestone.basis_points == 0 {
+                        panic!("Milestone must unlock a positive share");
+                    }
+                    total_bp = math::add_bps(e, total_bp, milestone.basis_points);
+                }
+
+                if total_bp != TOTAL_BASIS_POINTS {
+                    panic!("Milestones must sum to 10000 basis points");
+                }
+            }
+        }
     }
 }
 
 #[cfg(test)]
 mod test;
+#[cfg(test)]
+mod invariants;
@@ -1,7 +1,63 @@
 #![cfg(test)]
+extern crate std;
 
 use super::*;
-use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Env, token};
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Env, Vec, token};
+
+fn setup_grant<'a>(
+    e: &Env,
+    cliff_seconds: u64,
+    duration_seconds: u64,
+    amount: i128,
+    schedule: VestingSchedule,
+) -> VestingContractClient<'a> {
+    setup_grant_with_mode(e, e.ledger().timestamp(), cliff_seconds, duration_seconds, amount, schedule, CliffMode::CatchUp)
+}
+
+fn setup_grant_with_mode<'a>(
+    e: &Env,
+    start_time: u64,
+    cliff_seconds: u64,
+    duration_seconds: u64,
+    amount: i128,
+    schedule: VestingSchedule,
+    cliff_mode: CliffMode,
+) -> VestingContractClient<'a> {
+    setup_grant_with_bounds(e, start_time, cliff_seconds, duration_seconds, amount, schedule, cliff_mode, DEFAULT_START_BOUNDS)
+}
+
+fn setup_grant_with_bounds<'a>(
+    e: &Env,
+    start_time: u64,
+    cliff_seconds: u64,
+    duration_seconds: u64,
+    amount: i128,
+    schedule: VestingSchedule,
+    cliff_mode: CliffMode,
+    start_bounds: StartBounds,
+) -> VestingContractClient<'a> {
+    e.mock_all_auths();
+
+    let funder = Address::generate(e);
+    let beneficiary = Address::generate(e);
+    let clawback_admin = Address::generate(e);
+    let contract_id = e.register(VestingContract, ());
+    let client = VestingContractClient::new(e, &contract_id);
+
+    let token_admin = Address::generate(e);
+    let token_contract = e.register_stellar_asset_contract_v2(token_admin).address();
+    token::StellarAssetClient::new(e, &token_contract).mint(&funder, &amount);
+
+    let terms = TrancheTerms { start_time, cliff_seconds, duration_seconds, schedule, cliff_mode };
+    client.initialize(&funder, &beneficiary, &token_contract, &amount, &clawback_admin, &terms, &start_bounds);
+
+    client
+}
+
+fn vested_at(e: &Env, client: &VestingContractClient, timestamp: u64) -> i128 {
+    e.ledger().set_timestamp(timestamp);
+    client.get_vested_amount()
+}
 
 #[test]
 fn test_vesting_flow() {
@@ -30,21 +86,19 @@ fn test_vesting_flow() {
     let amount = 10000;
     
     // Initialize
-    client.initialize(
-        &funder,
-        &beneficiary,
-        &token_contract,
-        &start_time,
-        &cliff_seconds,
-        &duration_seconds,
-        &amount,
-        &clawback_admin,
-    );
+    let terms = TrancheTerms {
+        start_time,
+        cliff_seconds,
+        duration_seconds,
+        schedule: VestingSchedule::Linear,
+        cliff_mode: CliffMode::CatchUp,
+    };
+    client.initialize(&funder, &beneficiary, &token_contract, &amount, &clawback_admin, &terms, &DEFAULT_START_BOUNDS);
     
     // Verify init state
     let config = client.get_config();
     assert_eq!(config.total_amount, amount);
-    assert_eq!(config.is_active, true);
+    assert!(config.is_active);
     
     // Check contract balance
     assert_eq!(token_client.balance(&contract_id), 10000);
@@ -98,7 +152,7 @@ fn test_vesting_flow() {
     
     // Verify config update
     let config_revoked = client.get_config();
-    assert_eq!(config_revoked.is_active, false);
+    assert!(!config_revoked.is_active);
     assert_eq!(config_revoked.total_amount, 5000); // Capped at vested amount
     
     // 6. Advance time to end
@@ -112,3 +166,1292 @@ fn test_vesting_flow() {
     assert_eq!(token_client.balance(&beneficiary), 2000 + 3000);
     assert_eq!(token_client.balance(&contract_id), 0);
 }
+
+#[test]
+fn test_linear_boundaries() {
+    let e = Env::default();
+    let client = setup_grant(&e, 100, 1000, 10000, VestingSchedule::Linear);
+
+    assert_eq!(vested_at(&e, &client, 0), 0);
+    assert_eq!(vested_at(&e, &client, 99), 0);
+    // Catch-up at the cliff
+    assert_eq!(vested_at(&e, &client, 100), 1000);
+    assert_eq!(vested_at(&e, &client, 101), 1010);
+    assert_eq!(vested_at(&e, &client, 999), 9990);
+    assert_eq!(vested_at(&e, &client, 1000), 10000);
+    assert_eq!(vested_at(&e, &client, 5000), 10000);
+}
+
+#[test]
+fn test_periodic_boundaries() {
+    let e = Env::default();
+    // Monthly unlocks of 100 over 10 months, no cliff
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Periodic(100, 100));
+
+    assert_eq!(vested_at(&e, &client, 0), 0);
+    assert_eq!(vested_at(&e, &client, 99), 0);
+    assert_eq!(vested_at(&e, &client, 100), 100);
+    assert_eq!(vested_at(&e, &client, 199), 100);
+    assert_eq!(vested_at(&e, &client, 200), 200);
+    assert_eq!(vested_at(&e, &client, 999), 900);
+    assert_eq!(vested_at(&e, &client, 1000), 1000);
+}
+
+#[test]
+fn test_periodic_cliff_with_lump_sum() {
+    let e = Env::default();
+    // 48 monthly periods with a 12 month cliff: 25% at the cliff, then monthly
+    let month = 30 * 24 * 60 * 60;
+    let client = setup_grant(&e, 12 * month, 48 * month, 4800, VestingSchedule::Periodic(month, 100));
+
+    assert_eq!(vested_at(&e, &client, 12 * month - 1), 0);
+    assert_eq!(vested_at(&e, &client, 12 * month), 1200);
+    assert_eq!(vested_at(&e, &client, 13 * month - 1), 1200);
+    assert_eq!(vested_at(&e, &client, 13 * month), 1300);
+    assert_eq!(vested_at(&e, &client, 47 * month), 4700);
+    assert_eq!(vested_at(&e, &client, 48 * month), 4800);
+}
+
+#[test]
+fn test_periodic_remainder_vests_at_end() {
+    let e = Env::default();
+    // 3 periods of 333 leave 1 unit of dust that unlocks when the grant ends
+    let client = setup_grant(&e, 0, 300, 1000, VestingSchedule::Periodic(100, 333));
+
+    assert_eq!(vested_at(&e, &client, 299), 666);
+    assert_eq!(vested_at(&e, &client, 300), 1000);
+
+    // Periods that over-allocate are capped at the total
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 300, 1000, VestingSchedule::Periodic(100, 600));
+    assert_eq!(vested_at(&e, &client, 100), 600);
+    assert_eq!(vested_at(&e, &client, 200), 1000);
+}
+
+#[test]
+fn test_milestone_boundaries() {
+    let e = Env::default();
+    let milestones = Vec::from_array(&e, [(250u64, 2500u32), (500, 2500), (1000, 5000)]);
+    let client = setup_grant(&e, 0, 1000, 10000, VestingSchedule::Milestones(milestones));
+
+    assert_eq!(vested_at(&e, &client, 0), 0);
+    assert_eq!(vested_at(&e, &client, 249), 0);
+    assert_eq!(vested_at(&e, &client, 250), 2500);
+    assert_eq!(vested_at(&e, &client, 499), 2500);
+    assert_eq!(vested_at(&e, &client, 500), 5000);
+    assert_eq!(vested_at(&e, &client, 999), 5000);
+    assert_eq!(vested_at(&e, &client, 1000), 10000);
+}
+
+#[test]
+fn test_milestones_gated_by_cliff() {
+    let e = Env::default();
+    let milestones = Vec::from_array(&e, [(100u64, 5000u32), (400, 5000)]);
+    let client = setup_grant(&e, 300, 1000, 10000, VestingSchedule::Milestones(milestones));
+
+    assert_eq!(vested_at(&e, &client, 100), 0);
+    assert_eq!(vested_at(&e, &client, 299), 0);
+    assert_eq!(vested_at(&e, &client, 300), 5000);
+    assert_eq!(vested_at(&e, &client, 400), 10000);
+}
+
+#[test]
+fn test_claim_periodic() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Periodic(100, 100));
+    let config = client.get_config();
+    let token_client = token::Client::new(&e, &config.token);
+
+    e.ledger().set_timestamp(250);
+    client.claim();
+    assert_eq!(token_client.balance(&config.beneficiary), 200);
+    assert_eq!(client.get_claimable_amount(), 0);
+
+    e.ledger().set_timestamp(300);
+    assert_eq!(client.get_claimable_amount(), 100);
+}
+
+#[test]
+#[should_panic(expected = "Period must be positive")]
+fn test_periodic_zero_period() {
+    let e = Env::default();
+    setup_grant(&e, 0, 1000, 1000, VestingSchedule::Periodic(0, 100));
+}
+
+#[test]
+#[should_panic(expected = "Amount per period must be positive")]
+fn test_periodic_zero_amount() {
+    let e = Env::default();
+    setup_grant(&e, 0, 1000, 1000, VestingSchedule::Periodic(100, 0));
+}
+
+#[test]
+#[should_panic(expected = "Milestones must sum to 10000 basis points")]
+fn test_milestones_invalid_sum() {
+    let e = Env::default();
+    let milestones = Vec::from_array(&e, [(250u64, 2500u32), (500, 2500)]);
+    setup_grant(&e, 0, 1000, 1000, VestingSchedule::Milestones(milestones));
+}
+
+#[test]
+#[should_panic(expected = "Milestones must be in increasing order")]
+fn test_milestones_out_of_order() {
+    let e = Env::default();
+    let milestones = Vec::from_array(&e, [(500u64, 5000u32), (250, 5000)]);
+    setup_grant(&e, 0, 1000, 1000, VestingSchedule::Milestones(milestones));
+}
+
+#[test]
+#[should_panic(expected = "Milestone outside vesting period")]
+fn test_milestones_after_end() {
+    let e = Env::default();
+    let milestones = Vec::from_array(&e, [(500u64, 5000u32), (1001, 5000)]);
+    setup_grant(&e, 0, 1000, 1000, VestingSchedule::Milestones(milestones));
+}
+
+#[test]
+#[should_panic(expected = "Milestones must not be empty")]
+fn test_milestones_empty() {
+    let e = Env::default();
+    setup_grant(&e, 0, 1000, 1000, VestingSchedule::Milestones(Vec::new(&e)));
+}
+
+#[test]
+fn test_linear_start_at_cliff() {
+    let e = Env::default();
+    let client = setup_grant_with_mode(&e, 0, 100, 1000, 9000, VestingSchedule::Linear, CliffMode::StartAtCliff);
+
+    assert_eq!(vested_at(&e, &client, 99), 0);
+    // No catch-up: the curve starts at the cliff and runs for the remaining 900 seconds
+    assert_eq!(vested_at(&e, &client, 100), 0);
+    assert_eq!(vested_at(&e, &client, 101), 10);
+    assert_eq!(vested_at(&e, &client, 550), 4500);
+    assert_eq!(vested_at(&e, &client, 999), 8990);
+    assert_eq!(vested_at(&e, &client, 1000), 9000);
+}
+
+#[test]
+fn test_periodic_start_at_cliff() {
+    let e = Env::default();
+    let client = setup_grant_with_mode(&e, 0, 300, 1300, 1000, VestingSchedule::Periodic(100, 100), CliffMode::StartAtCliff);
+
+    assert_eq!(vested_at(&e, &client, 300), 0);
+    assert_eq!(vested_at(&e, &client, 399), 0);
+    assert_eq!(vested_at(&e, &client, 400), 100);
+    assert_eq!(vested_at(&e, &client, 1299), 900);
+    assert_eq!(vested_at(&e, &client, 1300), 1000);
+}
+
+#[test]
+fn test_cliff_equal_to_duration() {
+    let e = Env::default();
+    let client = setup_grant_with_mode(&e, 0, 1000, 1000, 1000, VestingSchedule::Linear, CliffMode::StartAtCliff);
+
+    assert_eq!(vested_at(&e, &client, 999), 0);
+    assert_eq!(vested_at(&e, &client, 1000), 1000);
+}
+
+#[test]
+#[should_panic(expected = "Duration must be positive")]
+fn test_zero_duration() {
+    let e = Env::default();
+    setup_grant(&e, 0, 0, 1000, VestingSchedule::Linear);
+}
+
+#[test]
+#[should_panic(expected = "Vesting end time overflows")]
+fn test_end_time_overflow() {
+    let e = Env::default();
+    let start_time = u64::MAX - 10;
+    e.ledger().set_timestamp(start_time);
+    setup_grant_with_mode(&e, start_time, 0, 100, 1000, VestingSchedule::Linear, CliffMode::CatchUp);
+}
+
+#[test]
+fn test_start_time_bounds() {
+    let e = Env::default();
+    let StartBounds { max_backdate_seconds, max_delay_seconds } = DEFAULT_START_BOUNDS;
+    let now = 10 * max_backdate_seconds;
+    e.ledger().set_timestamp(now);
+
+    // Both bounds are inclusive
+    let backdated = setup_grant_with_mode(&e, now - max_backdate_seconds, 0, 100, 1000, VestingSchedule::Linear, CliffMode::CatchUp);
+    assert_eq!(backdated.get_vested_amount(), 1000);
+
+    let delayed = setup_grant_with_mode(&e, now + max_delay_seconds, 0, 100, 1000, VestingSchedule::Linear, CliffMode::CatchUp);
+    assert_eq!(delayed.get_vested_amount(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Start time too far in the past")]
+fn test_start_time_too_far_in_past() {
+    let e = Env::default();
+    let max_backdate_seconds = DEFAULT_START_BOUNDS.max_backdate_seconds;
+    let now = 10 * max_backdate_seconds;
+    e.ledger().set_timestamp(now);
+    setup_grant_with_mode(&e, now - max_backdate_seconds - 1, 0, 100, 1000, VestingSchedule::Linear, CliffMode::CatchUp);
+}
+
+#[test]
+#[should_panic(expected = "Start time too far in the future")]
+fn test_start_time_too_far_in_future() {
+    let e = Env::default();
+    setup_grant_with_mode(&e, DEFAULT_START_BOUNDS.max_delay_seconds + 1, 0, 100, 1000, VestingSchedule::Linear, CliffMode::CatchUp);
+}
+
+#[test]
+fn test_start_bounds_are_configurable() {
+    let e = Env::default();
+    e.ledger().set_timestamp(10_000);
+    let tight = StartBounds { max_backdate_seconds: 100, max_delay_seconds: 50 };
+    let terms = |start_time: u64| TrancheTerms {
+        start_time,
+        cliff_seconds: 0,
+        duration_seconds: 100,
+        schedule: VestingSchedule::Linear,
+        cliff_mode: CliffMode::CatchUp,
+    };
+    let grant = |start_time: u64| {
+        e.mock_all_auths();
+        let funder = Address::generate(&e);
+        let token_id = e.register_stellar_asset_contract_v2(Address::generate(&e)).address();
+        token::StellarAssetClient::new(&e, &token_id).mint(&funder, &1000);
+        let client = VestingContractClient::new(&e, &e.register(VestingContract, ()));
+        let result = client.try_initialize(
+            &funder,
+            &Address::generate(&e),
+            &token_id,
+            &1000,
+            &Address::generate(&e),
+            &terms(start_time),
+            &tight,
+        );
+        (client, result.is_ok())
+    };
+
+    // Well inside the default window, but outside this grant's own
+    assert!(!grant(9_899).1);
+    assert!(!grant(10_051).1);
+    let (client, ok) = grant(9_900);
+    assert!(ok);
+    assert_eq!(client.get_config().start_bounds, tight);
+
+    // Tranches added later are held to the same window
+    let funder = top_up_funder(&e, &client, 2000);
+    assert!(client.try_top_up(&funder, &1000, &Some(terms(10_051))).is_err());
+    client.top_up(&funder, &1000, &Some(terms(10_050)));
+}
+
+#[test]
+fn test_change_beneficiary() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+    let config = client.get_config();
+    let token_client = token::Client::new(&e, &config.token);
+
+    e.ledger().set_timestamp(300);
+    client.claim();
+    assert_eq!(token_client.balance(&config.beneficiary), 300);
+
+    let new_beneficiary = Address::generate(&e);
+    client.change_beneficiary(&new_beneficiary);
+
+    // Both the outgoing beneficiary and the clawback admin had to sign
+    let signers: std::vec::Vec<Address> = e.auths().iter().map(|(address, _)| address.clone()).collect();
+    assert_eq!(signers.len(), 2);
+    assert!(signers.contains(&config.beneficiary));
+    assert!(signers.contains(&config.clawback_admin));
+
+    // Future claims go to the new wallet; the old one keeps what it already received
+    e.ledger().set_timestamp(1000);
+    client.claim();
+    assert_eq!(client.get_config().beneficiary, new_beneficiary);
+    assert_eq!(token_client.balance(&new_beneficiary), 700);
+    assert_eq!(token_client.balance(&config.beneficiary), 300);
+}
+
+#[test]
+fn test_claim_to() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+    let config = client.get_config();
+    let token_client = token::Client::new(&e, &config.token);
+
+    let destination = Address::generate(&e);
+    e.ledger().set_timestamp(400);
+    client.claim_to(&destination);
+
+    assert_eq!(token_client.balance(&destination), 400);
+    assert_eq!(token_client.balance(&config.beneficiary), 0);
+    assert_eq!(client.get_config().claimed_amount, 400);
+    assert_eq!(client.get_claimable_amount(), 0);
+}
+
+#[test]
+fn test_claim_for_pays_beneficiary() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+    let config = client.get_config();
+    let token_client = token::Client::new(&e, &config.token);
+
+    let keeper = Address::generate(&e);
+    client.set_keeper(&Some(keeper.clone()));
+    assert_eq!(client.get_keeper(), Some(keeper.clone()));
+
+    e.ledger().set_timestamp(250);
+    client.claim_for();
+
+    let signers: std::vec::Vec<Address> = e.auths().iter().map(|(address, _)| address.clone()).collect();
+    assert_eq!(signers, std::vec![keeper.clone()]);
+    assert_eq!(token_client.balance(&config.beneficiary), 250);
+    assert_eq!(token_client.balance(&keeper), 0);
+
+    // Removing the keeper disables `claim_for`
+    client.set_keeper(&None);
+    assert_eq!(client.get_keeper(), None);
+    assert!(client.try_claim_for().is_err());
+}
+
+#[test]
+fn test_change_beneficiary_clears_keeper() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+
+    client.set_keeper(&Some(Address::generate(&e)));
+    client.change_beneficiary(&Address::generate(&e));
+
+    assert_eq!(client.get_keeper(), None);
+}
+
+#[test]
+#[should_panic(expected = "No keeper set")]
+fn test_claim_for_without_keeper() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+    client.claim_for();
+}
+
+#[test]
+fn test_clawback_partial_keeps_vesting() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+    let config = client.get_config();
+    let token_client = token::Client::new(&e, &config.token);
+
+    e.ledger().set_timestamp(200);
+    client.clawback_partial(&300);
+    assert_eq!(token_client.balance(&config.clawback_admin), 300);
+    assert_eq!(client.get_config().clawed_back_amount, 300);
+
+    // Already vested tokens are untouched and the schedule keeps its pace...
+    assert_eq!(client.get_vested_amount(), 200);
+    assert_eq!(vested_at(&e, &client, 500), 500);
+    assert_eq!(vested_at(&e, &client, 700), 700);
+    // ...until the reduced total is reached
+    assert_eq!(vested_at(&e, &client, 701), 700);
+    assert_eq!(vested_at(&e, &client, 1000), 700);
+
+    client.claim();
+    assert_eq!(token_client.balance(&config.beneficiary), 700);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_clawbacks_past_threshold_need_council() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 10 * council::DAY_SECONDS, 1000, VestingSchedule::Linear);
+    let config = client.get_config();
+    let council = Address::generate(&e);
+    client.set_council(&council);
+    client.set_threshold(&300);
+    let signer = |e: &Env| e.auths()[0].0.clone();
+
+    // The clawback admin handles small clawbacks alone, but not a series of them past the threshold
+    client.clawback_partial(&200);
+    assert_eq!(signer(&e), config.clawback_admin);
+    client.clawback_partial(&100);
+    assert_eq!(signer(&e), config.clawback_admin);
+    client.clawback_partial(&1);
+    assert_eq!(signer(&e), council);
+
+    // A full clawback moving more than the threshold needs the council too
+    e.ledger().set_timestamp(council::DAY_SECONDS);
+    client.clawback();
+    assert_eq!(signer(&e), council);
+    assert_eq!(client.get_config().clawed_back_amount, 301 + 599);
+}
+
+#[test]
+fn test_clawback_after_partial() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+    let config = client.get_config();
+    let token_client = token::Client::new(&e, &config.token);
+
+    e.ledger().set_timestamp(100);
+    client.clawback_partial(&200);
+
+    // Full clawback only returns what the partial clawback left unvested
+    e.ledger().set_timestamp(400);
+    client.clawback();
+    assert_eq!(token_client.balance(&config.clawback_admin), 200 + 400);
+    assert_eq!(client.get_config().clawed_back_amount, 600);
+
+    client.claim();
+    assert_eq!(token_client.balance(&config.beneficiary), 400);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+#[should_panic(expected = "Amount exceeds unvested balance")]
+fn test_clawback_partial_exceeds_unvested() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+
+    e.ledger().set_timestamp(600);
+    client.clawback_partial(&401);
+}
+
+#[test]
+#[should_panic(expected = "Already revoked/inactive")]
+fn test_clawback_partial_after_revoke() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+
+    client.clawback();
+    client.clawback_partial(&1);
+}
+
+#[test]
+fn test_scheduled_termination() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+    let config = client.get_config();
+    let token_client = token::Client::new(&e, &config.token);
+
+    // Notice given at 100, employment ends at 600
+    e.ledger().set_timestamp(100);
+    client.schedule_termination(&600);
+
+    // Claims during the notice period work as usual
+    e.ledger().set_timestamp(400);
+    client.claim();
+    assert_eq!(token_client.balance(&config.beneficiary), 400);
+
+    // Accrual stops at the termination date
+    assert_eq!(vested_at(&e, &client, 600), 600);
+    assert_eq!(vested_at(&e, &client, 900), 600);
+    client.claim();
+    assert_eq!(token_client.balance(&config.beneficiary), 600);
+
+    // The admin recovers the remainder afterwards
+    client.clawback();
+    assert_eq!(token_client.balance(&config.clawback_admin), 400);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_reschedule_termination_before_it_passes() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+
+    client.schedule_termination(&600);
+    e.ledger().set_timestamp(500);
+    client.schedule_termination(&800);
+
+    assert_eq!(client.get_config().termination_time, Some(800));
+    assert_eq!(vested_at(&e, &client, 900), 800);
+}
+
+#[test]
+#[should_panic(expected = "Already terminated")]
+fn test_reschedule_termination_after_it_passes() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+
+    client.schedule_termination(&600);
+    e.ledger().set_timestamp(600);
+    client.schedule_termination(&800);
+}
+
+#[test]
+#[should_panic(expected = "Termination must not be in the past")]
+fn test_termination_in_past() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+
+    e.ledger().set_timestamp(500);
+    client.schedule_termination(&499);
+}
+
+#[test]
+fn test_pause_windows_shift_schedule() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+    let config = client.get_config();
+    let token_client = token::Client::new(&e, &config.token);
+
+    // First leave: 200..300
+    e.ledger().set_timestamp(200);
+    client.pause_vesting();
+    assert_eq!(vested_at(&e, &client, 250), 200);
+    assert_eq!(vested_at(&e, &client, 300), 200);
+    client.resume_vesting();
+    assert_eq!(client.get_config().paused_seconds, 100);
+    assert_eq!(vested_at(&e, &client, 400), 300);
+
+    // Second leave: 500..700
+    e.ledger().set_timestamp(500);
+    client.pause_vesting();
+
+    // Claims still work while paused, up to what vested before the pause
+    e.ledger().set_timestamp(600);
+    client.claim();
+    assert_eq!(token_client.balance(&config.beneficiary), 400);
+    assert_eq!(client.get_end_time(), 1200);
+
+    e.ledger().set_timestamp(700);
+    client.resume_vesting();
+    assert_eq!(client.get_config().paused_seconds, 300);
+    assert_eq!(client.get_end_time(), 1300);
+
+    assert_eq!(vested_at(&e, &client, 1000), 700);
+    assert_eq!(vested_at(&e, &client, 1299), 999);
+    assert_eq!(vested_at(&e, &client, 1300), 1000);
+
+    client.claim();
+    assert_eq!(token_client.balance(&config.beneficiary), 1000);
+}
+
+#[test]
+fn test_pause_shifts_periodic_and_cliff() {
+    let e = Env::default();
+    let client = setup_grant(&e, 200, 1000, 1000, VestingSchedule::Periodic(100, 100));
+
+    // Pausing before the cliff pushes the cliff out too
+    e.ledger().set_timestamp(50);
+    client.pause_vesting();
+    e.ledger().set_timestamp(150);
+    client.resume_vesting();
+
+    assert_eq!(vested_at(&e, &client, 299), 0);
+    assert_eq!(vested_at(&e, &client, 300), 200);
+    assert_eq!(vested_at(&e, &client, 399), 200);
+    assert_eq!(vested_at(&e, &client, 400), 300);
+    assert_eq!(vested_at(&e, &client, 1099), 900);
+    assert_eq!(vested_at(&e, &client, 1100), 1000);
+}
+
+#[test]
+fn test_pause_across_termination() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+
+    e.ledger().set_timestamp(300);
+    client.schedule_termination(&600);
+    e.ledger().set_timestamp(400);
+    client.pause_vesting();
+
+    // Resuming after the termination date only counts the pause up to that date
+    e.ledger().set_timestamp(800);
+    client.resume_vesting();
+    assert_eq!(client.get_config().paused_seconds, 200);
+    assert_eq!(vested_at(&e, &client, 900), 400);
+}
+
+#[test]
+fn test_clawback_while_paused() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+    let config = client.get_config();
+    let token_client = token::Client::new(&e, &config.token);
+
+    e.ledger().set_timestamp(300);
+    client.pause_vesting();
+    e.ledger().set_timestamp(900);
+    client.clawback();
+
+    assert_eq!(token_client.balance(&config.clawback_admin), 700);
+    assert_eq!(client.get_vested_amount(), 300);
+}
+
+#[test]
+#[should_panic(expected = "Already revoked/inactive")]
+fn test_resume_after_clawback() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+
+    e.ledger().set_timestamp(300);
+    client.pause_vesting();
+    client.clawback();
+    client.resume_vesting();
+}
+
+#[test]
+#[should_panic(expected = "Vesting already paused")]
+fn test_pause_twice() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+
+    client.pause_vesting();
+    client.pause_vesting();
+}
+
+#[test]
+#[should_panic(expected = "Vesting not paused")]
+fn test_resume_without_pause() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+
+    client.resume_vesting();
+}
+
+#[test]
+#[should_panic(expected = "Vesting has not started")]
+fn test_pause_before_start() {
+    let e = Env::default();
+    let client = setup_grant_with_mode(&e, 100, 0, 1000, 1000, VestingSchedule::Linear, CliffMode::CatchUp);
+
+    client.pause_vesting();
+}
+
+/// The grant's funder, minted `amount` to top up with.
+fn top_up_funder(e: &Env, client: &VestingContractClient, amount: i128) -> Address {
+    let config = client.get_config();
+    token::StellarAssetClient::new(e, &config.token).mint(&config.funder, &amount);
+    config.funder
+}
+
+#[test]
+fn test_top_up_original_schedule() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+    let token_client = token::Client::new(&e, &client.get_config().token);
+
+    e.ledger().set_timestamp(400);
+    let funder = top_up_funder(&e, &client, 500);
+    client.top_up(&funder, &500, &None);
+
+    assert_eq!(token_client.balance(&client.address), 1500);
+    assert_eq!(client.get_config().total_amount, 1500);
+    // The raise follows the original curve, including the part already elapsed
+    assert_eq!(client.get_vested_amount(), 600);
+    assert_eq!(vested_at(&e, &client, 1000), 1500);
+}
+
+#[test]
+fn test_top_up_new_tranche() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+    let config = client.get_config();
+    let token_client = token::Client::new(&e, &config.token);
+
+    // Refresh grant: 2000 more starting at 500, with a 100s cliff, over 500s
+    e.ledger().set_timestamp(450);
+    let funder = top_up_funder(&e, &client, 2000);
+    let terms = TrancheTerms {
+        start_time: 500,
+        cliff_seconds: 100,
+        duration_seconds: 500,
+        schedule: VestingSchedule::Linear,
+        cliff_mode: CliffMode::CatchUp,
+    };
+    client.top_up(&funder, &2000, &Some(terms.clone()));
+    assert_eq!(client.get_config().tranches, Vec::from_array(&e, [Tranche { amount: 2000, terms, paused_offset: 0 }]));
+    assert_eq!(client.get_end_time(), 1000);
+
+    assert_eq!(vested_at(&e, &client, 599), 599);
+    assert_eq!(vested_at(&e, &client, 600), 600 + 400);
+    assert_eq!(vested_at(&e, &client, 800), 800 + 1200);
+    assert_eq!(vested_at(&e, &client, 1000), 1000 + 2000);
+
+    client.claim();
+    assert_eq!(token_client.balance(&config.beneficiary), 3000);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_tranche_respects_pause_and_clawback() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+    let config = client.get_config();
+    let token_client = token::Client::new(&e, &config.token);
+
+    let funder = top_up_funder(&e, &client, 1000);
+    let terms = TrancheTerms {
+        start_time: 0,
+        cliff_seconds: 0,
+        duration_seconds: 500,
+        schedule: VestingSchedule::Periodic(100, 200),
+        cliff_mode: CliffMode::CatchUp,
+    };
+    client.top_up(&funder, &1000, &Some(terms));
+
+    // A pause applies to every tranche
+    e.ledger().set_timestamp(200);
+    client.pause_vesting();
+    assert_eq!(vested_at(&e, &client, 300), 200 + 400);
+    client.resume_vesting();
+
+    // Full clawback returns the unvested part of all tranches
+    e.ledger().set_timestamp(400);
+    client.clawback();
+    assert_eq!(token_client.balance(&config.clawback_admin), 2000 - (300 + 600));
+    assert_eq!(client.get_vested_amount(), 900);
+    assert_eq!(client.get_config().tranches.len(), 0);
+}
+
+#[test]
+fn test_tranche_added_after_pause() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 4000, 4000, VestingSchedule::Linear);
+
+    // A long leave, then a refresh grant that starts the day the employee is back
+    e.ledger().set_timestamp(1000);
+    client.pause_vesting();
+    e.ledger().set_timestamp(2000);
+    client.resume_vesting();
+
+    let funder = top_up_funder(&e, &client, 1000);
+    let terms = TrancheTerms {
+        start_time: 2000,
+        cliff_seconds: 0,
+        duration_seconds: 1000,
+        schedule: VestingSchedule::Linear,
+        cliff_mode: CliffMode::CatchUp,
+    };
+    client.top_up(&funder, &1000, &Some(terms));
+    assert_eq!(client.get_config().tranches.get_unchecked(0).paused_offset, 1000);
+
+    // The original grant is still shifted by the leave, the refresh is not
+    assert_eq!(vested_at(&e, &client, 2500), 1500 + 500);
+    assert_eq!(client.get_end_time(), 5000);
+
+    // A later leave delays both
+    e.ledger().set_timestamp(2500);
+    client.pause_vesting();
+    e.ledger().set_timestamp(2700);
+    assert_eq!(vested_at(&e, &client, 2700), 1500 + 500);
+    client.resume_vesting();
+    assert_eq!(vested_at(&e, &client, 3200), 2000 + 1000);
+    assert_eq!(client.get_end_time(), 5200);
+}
+
+#[test]
+fn test_tranche_added_during_pause() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+
+    e.ledger().set_timestamp(200);
+    client.pause_vesting();
+
+    // Added mid-leave: it waits for the rest of the leave like everything else
+    e.ledger().set_timestamp(300);
+    let funder = top_up_funder(&e, &client, 1000);
+    let terms = TrancheTerms {
+        start_time: 300,
+        cliff_seconds: 0,
+        duration_seconds: 1000,
+        schedule: VestingSchedule::Linear,
+        cliff_mode: CliffMode::CatchUp,
+    };
+    client.top_up(&funder, &1000, &Some(terms));
+    assert_eq!(vested_at(&e, &client, 400), 200);
+
+    e.ledger().set_timestamp(500);
+    client.resume_vesting();
+    assert_eq!(vested_at(&e, &client, 600), 300 + 100);
+    assert_eq!(client.get_end_time(), 1500);
+}
+
+#[test]
+#[should_panic(expected = "Periodic grants need tranche terms to top up")]
+fn test_top_up_periodic_without_terms() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Periodic(100, 100));
+
+    let funder = top_up_funder(&e, &client, 100);
+    client.top_up(&funder, &100, &None);
+}
+
+#[test]
+#[should_panic(expected = "Duration must be greater than or equal to cliff")]
+fn test_top_up_invalid_tranche() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+
+    let funder = top_up_funder(&e, &client, 100);
+    let terms = TrancheTerms {
+        start_time: 0,
+        cliff_seconds: 600,
+        duration_seconds: 500,
+        schedule: VestingSchedule::Linear,
+        cliff_mode: CliffMode::CatchUp,
+    };
+    client.top_up(&funder, &100, &Some(terms));
+}
+
+#[test]
+#[should_panic(expected = "Already revoked/inactive")]
+fn test_top_up_after_clawback() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+
+    client.clawback();
+    let funder = top_up_funder(&e, &client, 100);
+    client.top_up(&funder, &100, &None);
+}
+
+#[test]
+#[should_panic(expected = "Only the funder or clawback admin can top up")]
+fn test_top_up_by_stranger() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+
+    let stranger = Address::generate(&e);
+    token::StellarAssetClient::new(&e, &client.get_config().token).mint(&stranger, &100);
+    client.top_up(&stranger, &100, &None);
+}
+
+#[test]
+fn test_top_up_by_clawback_admin() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+    let config = client.get_config();
+
+    token::StellarAssetClient::new(&e, &config.token).mint(&config.clawback_admin, &100);
+    client.top_up(&config.clawback_admin, &100, &None);
+    assert_eq!(client.get_config().total_amount, 1100);
+}
+
+#[test]
+#[should_panic(expected = "Already terminated")]
+fn test_top_up_after_termination() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+
+    client.schedule_termination(&600);
+    let funder = top_up_funder(&e, &client, 200);
+    // Before the termination date a top-up still counts
+    e.ledger().set_timestamp(500);
+    client.top_up(&funder, &100, &None);
+
+    e.ledger().set_timestamp(600);
+    client.top_up(&funder, &100, &None);
+}
+
+#[test]
+fn test_top_up_tranche_cap() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+
+    let funder = top_up_funder(&e, &client, i128::from(MAX_TRANCHES) * 100 + 100);
+    let terms = TrancheTerms {
+        start_time: 0,
+        cliff_seconds: 0,
+        duration_seconds: 1000,
+        schedule: VestingSchedule::Linear,
+        cliff_mode: CliffMode::CatchUp,
+    };
+    for _ in 0..MAX_TRANCHES {
+        client.top_up(&funder, &100, &Some(terms.clone()));
+    }
+    assert!(client.try_top_up(&funder, &100, &Some(terms)).is_err());
+    // Raising the original schedule adds no tranche, so it still works
+    client.top_up(&funder, &100, &None);
+    assert_eq!(client.get_config().tranches.len(), MAX_TRANCHES);
+}
+
+#[test]
+fn test_reconcile_and_sweep_excess() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+    let config = client.get_config();
+    let token_client = token::Client::new(&e, &config.token);
+
+    e.ledger().set_timestamp(300);
+    client.claim();
+    assert_eq!(
+        client.reconcile(),
+        Reconciliation { balance: 700, liability: 700, surplus: 0, shortfall: 0 }
+    );
+
+    // Someone sends tokens straight to the escrow
+    token::StellarAssetClient::new(&e, &config.token).mint(&client.address, &250);
+    assert_eq!(
+        client.reconcile(),
+        Reconciliation { balance: 950, liability: 700, surplus: 250, shortfall: 0 }
+    );
+
+    let treasury = Address::generate(&e);
+    assert_eq!(client.sweep_excess(&treasury), 250);
+    assert_eq!(token_client.balance(&treasury), 250);
+    assert_eq!(client.reconcile().surplus, 0);
+
+    // The grant itself is unaffected
+    e.ledger().set_timestamp(1000);
+    client.claim();
+    assert_eq!(token_client.balance(&config.beneficiary), 1000);
+}
+
+#[test]
+fn test_reconcile_after_clawback() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+
+    e.ledger().set_timestamp(400);
+    client.clawback_partial(&100);
+    assert_eq!(client.reconcile().liability, 900);
+
+    client.clawback();
+    assert_eq!(
+        client.reconcile(),
+        Reconciliation { balance: 400, liability: 400, surplus: 0, shortfall: 0 }
+    );
+}
+
+#[test]
+#[should_panic(expected = "No excess to sweep")]
+fn test_sweep_without_excess() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+
+    client.sweep_excess(&Address::generate(&e));
+}
+
+#[test]
+fn test_claim_with_short_balance() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+    let config = client.get_config();
+    let token_client = token::Client::new(&e, &config.token);
+
+    // The token issuer pulls 800 out of the escrow (simulated with a burn)
+    token_client.burn(&client.address, &800);
+    assert_eq!(client.reconcile().shortfall, 800);
+
+    // Only what is there gets paid; the rest stays claimable
+    e.ledger().set_timestamp(500);
+    client.claim();
+    assert_eq!(token_client.balance(&config.beneficiary), 200);
+    assert_eq!(client.get_config().claimed_amount, 200);
+    assert_eq!(client.get_claimable_amount(), 300);
+
+    // Nothing left to pay out, so the claim is a no-op rather than a failure
+    client.claim();
+    assert_eq!(client.get_config().claimed_amount, 200);
+
+    // Once the shortfall is made good the beneficiary catches up
+    token::StellarAssetClient::new(&e, &config.token).mint(&client.address, &800);
+    client.claim();
+    assert_eq!(token_client.balance(&config.beneficiary), 500);
+}
+
+#[test]
+fn test_clawback_with_short_balance_pays_vested_first() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+    let config = client.get_config();
+    let token_client = token::Client::new(&e, &config.token);
+
+    token_client.burn(&client.address, &300);
+
+    // 400 vested stays for the beneficiary, so only 300 of the 600 unvested can go back
+    e.ledger().set_timestamp(400);
+    client.clawback();
+    assert_eq!(token_client.balance(&config.clawback_admin), 300);
+
+    client.claim();
+    assert_eq!(token_client.balance(&config.beneficiary), 400);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+#[should_panic(expected = "Amount exceeds available balance")]
+fn test_clawback_partial_with_short_balance() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+
+    token::Client::new(&e, &client.get_config().token).burn(&client.address, &500);
+
+    e.ledger().set_timestamp(400);
+    client.clawback_partial(&200);
+}
+
+fn attested_schedule(e: &Env, attester: &Address, deadlines: [Option<u64>; 3]) -> VestingSchedule {
+    let milestones = Vec::from_array(e, [
+        AttestedMilestone { id: 1, basis_points: 2000, deadline: deadlines[0], status: MilestoneStatus::Pending },
+        AttestedMilestone { id: 2, basis_points: 3000, deadline: deadlines[1], status: MilestoneStatus::Pending },
+        AttestedMilestone { id: 3, basis_points: 5000, deadline: deadlines[2], status: MilestoneStatus::Pending },
+    ]);
+    VestingSchedule::Attested(attester.clone(), milestones)
+}
+
+#[test]
+fn test_attested_milestones_unlock_on_attestation() {
+    let e = Env::default();
+    let attester = Address::generate(&e);
+    let client = setup_grant(&e, 0, 1000, 1000, attested_schedule(&e, &attester, [None, None, None]));
+    let config = client.get_config();
+    let token_client = token::Client::new(&e, &config.token);
+
+    // Time alone unlocks nothing, even past the end of the duration
+    assert_eq!(vested_at(&e, &client, 5000), 0);
+
+    client.attest_milestone(&2);
+    let signers: std::vec::Vec<Address> = e.auths().iter().map(|(address, _)| address.clone()).collect();
+    assert_eq!(signers, std::vec![attester.clone()]);
+    assert_eq!(client.get_vested_amount(), 300);
+
+    client.claim();
+    assert_eq!(token_client.balance(&config.beneficiary), 300);
+
+    client.attest_milestone(&1);
+    client.attest_milestone(&3);
+    assert_eq!(client.get_vested_amount(), 1000);
+    client.claim();
+    assert_eq!(token_client.balance(&config.beneficiary), 1000);
+}
+
+#[test]
+fn test_expired_milestones_clawed_back() {
+    let e = Env::default();
+    let attester = Address::generate(&e);
+    let schedule = attested_schedule(&e, &attester, [Some(100), Some(200), None]);
+    let client = setup_grant(&e, 0, 1000, 1000, schedule);
+    let config = client.get_config();
+    let token_client = token::Client::new(&e, &config.token);
+
+    e.ledger().set_timestamp(100);
+    client.attest_milestone(&1);
+
+    // Milestone 2 misses its deadline; milestone 3 has none and stays open
+    e.ledger().set_timestamp(201);
+    assert_eq!(client.clawback_expired_milestones(), 300);
+    assert_eq!(token_client.balance(&config.clawback_admin), 300);
+    assert_eq!(client.get_config().clawed_back_amount, 300);
+
+    let VestingSchedule::Attested(_, milestones) = client.get_config().schedule else {
+        panic!("schedule changed");
+    };
+    assert_eq!(milestones.get_unchecked(0).status, MilestoneStatus::Attested);
+    assert_eq!(milestones.get_unchecked(1).status, MilestoneStatus::Forfeited);
+    assert_eq!(milestones.get_unchecked(2).status, MilestoneStatus::Pending);
+
+    client.attest_milestone(&3);
+    client.claim();
+    assert_eq!(token_client.balance(&config.beneficiary), 700);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_expired_milestones_after_partial_clawback() {
+    let e = Env::default();
+    let attester = Address::generate(&e);
+    let schedule = attested_schedule(&e, &attester, [None, Some(200), None]);
+    let client = setup_grant(&e, 0, 1000, 1000, schedule);
+    let config = client.get_config();
+    let token_client = token::Client::new(&e, &config.token);
+
+    // Only 200 is still unvested when milestone 2, worth 300, expires
+    client.clawback_partial(&800);
+    e.ledger().set_timestamp(201);
+    assert_eq!(client.clawback_expired_milestones(), 200);
+
+    assert_eq!(token_client.balance(&config.clawback_admin), 1000);
+    assert_eq!(token_client.balance(&client.address), 0);
+    assert_eq!(client.get_config().clawed_back_amount, 1000);
+    assert_eq!(client.reconcile().shortfall, 0);
+}
+
+#[test]
+fn test_clawback_returns_pending_milestones() {
+    let e = Env::default();
+    let attester = Address::generate(&e);
+    let client = setup_grant(&e, 0, 1000, 1000, attested_schedule(&e, &attester, [None, None, None]));
+    let config = client.get_config();
+    let token_client = token::Client::new(&e, &config.token);
+
+    client.attest_milestone(&3);
+    client.clawback();
+
+    assert_eq!(token_client.balance(&config.clawback_admin), 500);
+    assert_eq!(client.get_claimable_amount(), 500);
+}
+
+#[test]
+#[should_panic(expected = "Milestone deadline passed")]
+fn test_attest_after_deadline() {
+    let e = Env::default();
+    let attester = Address::generate(&e);
+    let client = setup_grant(&e, 0, 1000, 1000, attested_schedule(&e, &attester, [Some(100), None, None]));
+
+    e.ledger().set_timestamp(101);
+    client.attest_milestone(&1);
+}
+
+#[test]
+#[should_panic(expected = "Milestone not pending")]
+fn test_attest_twice() {
+    let e = Env::default();
+    let attester = Address::generate(&e);
+    let client = setup_grant(&e, 0, 1000, 1000, attested_schedule(&e, &attester, [None, None, None]));
+
+    client.attest_milestone(&1);
+    client.attest_milestone(&1);
+}
+
+#[test]
+#[should_panic(expected = "Milestone not found")]
+fn test_attest_unknown_milestone() {
+    let e = Env::default();
+    let attester = Address::generate(&e);
+    let client = setup_grant(&e, 0, 1000, 1000, attested_schedule(&e, &attester, [None, None, None]));
+
+    client.attest_milestone(&4);
+}
+
+#[test]
+#[should_panic(expected = "No expired milestones")]
+fn test_clawback_expired_before_deadline() {
+    let e = Env::default();
+    let attester = Address::generate(&e);
+    let client = setup_grant(&e, 0, 1000, 1000, attested_schedule(&e, &attester, [Some(100), None, None]));
+
+    e.ledger().set_timestamp(100);
+    client.clawback_expired_milestones();
+}
+
+#[test]
+#[should_panic(expected = "Duplicate milestone id")]
+fn test_attested_duplicate_ids() {
+    let e = Env::default();
+    let attester = Address::generate(&e);
+    let milestones = Vec::from_array(&e, [
+        AttestedMilestone { id: 1, basis_points: 5000, deadline: None, status: MilestoneStatus::Pending },
+        AttestedMilestone { id: 1, basis_points: 5000, deadline: None, status: MilestoneStatus::Pending },
+    ]);
+    setup_grant(&e, 0, 1000, 1000, VestingSchedule::Attested(attester, milestones));
+}
+
+#[test]
+#[should_panic(expected = "Attested grants need tranche terms to top up")]
+fn test_top_up_attested_without_terms() {
+    let e = Env::default();
+    let attester = Address::generate(&e);
+    let client = setup_grant(&e, 0, 1000, 1000, attested_schedule(&e, &attester, [None, None, None]));
+
+    client.attest_milestone(&1);
+    let funder = top_up_funder(&e, &client, 1000);
+    client.top_up(&funder, &1000, &None);
+}
+
+#[test]
+#[should_panic(expected = "Milestones must start pending")]
+fn test_attested_preapproved() {
+    let e = Env::default();
+    let attester = Address::generate(&e);
+    let milestones = Vec::from_array(&e, [
+        AttestedMilestone { id: 1, basis_points: 10000, deadline: None, status: MilestoneStatus::Attested },
+    ]);
+    setup_grant(&e, 0, 1000, 1000, VestingSchedule::Attested(attester, milestones));
+}
+
+/// Stand-in for a contract that receives routed claims, with the same
+/// `(token, from, amount)` shape as `RevenueSplitContract::distribute`.
+#[contract]
+struct Collector;
+
+#[contractimpl]
+impl Collector {
+    pub fn collect(e: Env, token: Address, from: Address, amount: i128) {
+        from.require_auth();
+        token::Client::new(&e, &token).transfer(&from, &e.current_contract_address(), &amount);
+    }
+}
+
+#[test]
+fn test_transfer_claim_right() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+    let config = client.get_config();
+    let token_client = token::Client::new(&e, &config.token);
+    assert_eq!(client.get_claim_holder(), config.beneficiary);
+
+    client.set_keeper(&Some(Address::generate(&e)));
+
+    let lender = Address::generate(&e);
+    client.transfer_claim_right(&lender);
+    let signers: std::vec::Vec<Address> = e.auths().iter().map(|(address, _)| address.clone()).collect();
+    assert_eq!(signers, std::vec![config.beneficiary.clone()]);
+    assert_eq!(client.get_claim_holder(), lender);
+    assert_eq!(client.get_keeper(), None);
+
+    // Claims now need the lender and pay the lender; the beneficiary is still on record
+    e.ledger().set_timestamp(400);
+    client.claim();
+    let signers: std::vec::Vec<Address> = e.auths().iter().map(|(address, _)| address.clone()).collect();
+    assert_eq!(signers, std::vec![lender.clone()]);
+    assert_eq!(token_client.balance(&lender), 400);
+    assert_eq!(token_client.balance(&config.beneficiary), 0);
+    assert_eq!(client.get_config().beneficiary, config.beneficiary);
+
+    // Handing the right back to the beneficiary restores the default
+    client.transfer_claim_right(&config.beneficiary);
+    let signers: std::vec::Vec<Address> = e.auths().iter().map(|(address, _)| address.clone()).collect();
+    assert_eq!(signers, std::vec![lender]);
+    assert_eq!(client.get_claim_holder(), config.beneficiary);
+
+    e.ledger().set_timestamp(1000);
+    client.claim();
+    assert_eq!(token_client.balance(&config.beneficiary), 600);
+}
+
+#[test]
+fn test_change_beneficiary_keeps_transferred_claim_right() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+
+    let lender = Address::generate(&e);
+    let keeper = Address::generate(&e);
+    client.transfer_claim_right(&lender);
+    client.set_keeper(&Some(keeper.clone()));
+
+    client.change_beneficiary(&Address::generate(&e));
+
+    assert_eq!(client.get_claim_holder(), lender);
+    assert_eq!(client.get_keeper(), Some(keeper));
+}
+
+#[test]
+fn test_claim_to_contract() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+    let config = client.get_config();
+    let token_client = token::Client::new(&e, &config.token);
+    let collector = e.register(Collector, ());
+
+    e.ledger().set_timestamp(300);
+    let routed = client.claim_to_contract(&collector, &Symbol::new(&e, "collect"));
+
+    assert_eq!(routed, 300);
+    assert_eq!(token_client.balance(&collector), 300);
+    assert_eq!(token_client.balance(&config.beneficiary), 0);
+    assert_eq!(client.get_config().claimed_amount, 300);
+
+    // Nothing claimable means the target is not called at all
+    assert_eq!(client.claim_to_contract(&collector, &Symbol::new(&e, "collect")), 0);
+    assert_eq!(token_client.balance(&collector), 300);
+}
+
+#[test]
+fn test_claim_to_contract_failure_rolls_back() {
+    let e = Env::default();
+    let client = setup_grant(&e, 0, 1000, 1000, VestingSchedule::Linear);
+    let collector = e.register(Collector, ());
+
+    e.ledger().set_timestamp(300);
+    assert!(client.try_claim_to_contract(&collector, &Symbol::new(&e, "missing")).is_err());
+    assert_eq!(client.get_config().claimed_amount, 0);
+    assert_eq!(client.get_claimable_amount(), 300);
+}
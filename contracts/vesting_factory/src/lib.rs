@@ -0,0 +1,169 @@
+#![no_std]
+
+use payd_common::{factory, list};
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, BytesN, Env, IntoVal, Symbol, Val, Vec};
+
+#[cfg(test)]
+mod test;
+
+#[contracttype]
+pub enum DataKey {
+    /// Grants created by a funder, oldest first, as a `payd_common::list`.
+    FunderGrants(Address),
+    /// Grants created for a beneficiary, oldest first, as a `payd_common::list`.
+    BeneficiaryGrants(Address),
+}
+
+/// Mirror of `vesting_escrow::VestingSchedule`, forwarded unchanged to the deployed escrow.
+#[derive(Clone)]
+#[contracttype]
+pub enum VestingSchedule {
+    Linear,
+    Periodic(u64, i128),
+    Milestones(Vec<(u64, u32)>),
+    Attested(Address, Vec<AttestedMilestone>),
+}
+
+/// Mirror of `vesting_escrow::MilestoneStatus`.
+#[derive(Clone, Copy)]
+#[contracttype]
+pub enum MilestoneStatus {
+    Pending,
+    Attested,
+    Forfeited,
+}
+
+/// Mirror of `vesting_escrow::AttestedMilestone`.
+#[derive(Clone)]
+#[contracttype]
+pub struct AttestedMilestone {
+    pub id: u32,
+    pub basis_points: u32,
+    pub deadline: Option<u64>,
+    pub status: MilestoneStatus,
+}
+
+/// Mirror of `vesting_escrow::CliffMode`.
+#[derive(Clone, Copy)]
+#[contracttype]
+pub enum CliffMode {
+    CatchUp,
+    StartAtCliff,
+}
+
+/// Mirror of `vesting_escrow::TrancheTerms`.
+#[derive(Clone)]
+#[contracttype]
+pub struct TrancheTerms {
+    pub start_time: u64,
+    pub cliff_seconds: u64,
+    pub duration_seconds: u64,
+    pub schedule: VestingSchedule,
+    pub cliff_mode: CliffMode,
+}
+
+/// Mirror of `vesting_escrow::StartBounds`.
+#[derive(Clone, Copy)]
+#[contracttype]
+pub struct StartBounds {
+    pub max_backdate_seconds: u64,
+    pub max_delay_seconds: u64,
+}
+
+/// Terms of a single grant, forwarded to `VestingContract::initialize` of the deployed instance.
+#[derive(Clone)]
+#[contracttype]
+pub struct GrantTerms {
+    pub beneficiary: Address,
+    pub token: Address,
+    pub start_time: u64,
+    pub cliff_seconds: u64,
+    pub duration_seconds: u64,
+    pub amount: i128,
+    pub clawback_admin: Address,
+    pub schedule: VestingSchedule,
+    pub cliff_mode: CliffMode,
+    pub start_bounds: StartBounds,
+}
+
+#[contract]
+pub struct VestingFactoryContract;
+
+#[contractimpl]
+impl VestingFactoryContract {
+    /// Initialize the factory with an admin and the uploaded `vesting_escrow` WASM hash.
+    pub fn init(env: Env, admin: Address, wasm_hash: BytesN<32>) {
+        factory::init(&env, &admin, &wasm_hash);
+    }
+
+    /// Replaces the template used for future grants (admin only).
+    /// Grants that were already created keep running their original code.
+    pub fn set_wasm_hash(env: Env, new_wasm_hash: BytesN<32>) {
+        factory::set_wasm_hash(&env, &new_wasm_hash);
+    }
+
+    /// Deploys a vesting escrow for one grant and funds it from `funder` in the same call.
+    /// The salt is derived from the funder and their grant count, so addresses are predictable.
+    pub fn create_grant(env: Env, funder: Address, terms: GrantTerms) -> Address {
+        funder.require_auth();
+
+        let funder_key = DataKey::FunderGrants(funder.clone());
+        let grant = factory::deploy(&env, &funder, list::len(&env, &funder_key));
+
+        let init_args: Vec<Val> = (
+            funder.clone(),
+            terms.beneficiary.clone(),
+            terms.token,
+            terms.amount,
+            terms.clawback_admin,
+            TrancheTerms {
+                start_time: terms.start_time,
+                cliff_seconds: terms.cliff_seconds,
+                duration_seconds: terms.duration_seconds,
+                schedule: terms.schedule,
+                cliff_mode: terms.cliff_mode,
+            },
+            terms.start_bounds,
+        )
+            .into_val(&env);
+        env.invoke_contract::<()>(&grant, &Symbol::new(&env, "initialize"), init_args);
+
+        list::push(&env, &funder_key, &grant);
+        list::push(&env, &DataKey::BeneficiaryGrants(terms.beneficiary.clone()), &grant);
+
+        env.events().publish(
+            (symbol_short!("grant_new"), funder, terms.beneficiary),
+            grant.clone(),
+        );
+
+        grant
+    }
+
+    /// Returns the address the funder's `index`-th grant is (or will be) deployed at.
+    pub fn grant_address(env: Env, funder: Address, index: u32) -> Address {
+        factory::address(&env, &funder, index)
+    }
+
+    /// Get up to `limit` grants created by a funder, oldest first, starting at `start`.
+    pub fn get_grants_by_funder(env: Env, funder: Address, start: u32, limit: u32) -> Vec<Address> {
+        list::page(&env, &DataKey::FunderGrants(funder), start, limit)
+    }
+
+    pub fn get_funder_grant_count(env: Env, funder: Address) -> u32 {
+        list::len(&env, &DataKey::FunderGrants(funder))
+    }
+
+    /// Get up to `limit` grants created for a beneficiary, oldest first, starting at `start`.
+    /// This is the beneficiary at creation; later `change_beneficiary` calls on a grant are not tracked here.
+    pub fn get_grants_by_beneficiary(env: Env, beneficiary: Address, start: u32, limit: u32) -> Vec<Address> {
+        list::page(&env, &DataKey::BeneficiaryGrants(beneficiary), start, limit)
+    }
+
+    pub fn get_beneficiary_grant_count(env: Env, beneficiary: Address) -> u32 {
+        list::len(&env, &DataKey::BeneficiaryGrants(beneficiary))
+    }
+
+    pub fn get_wasm_hash(env: Env) -> BytesN<32> {
+        factory::get_wasm_hash(&env)
+    }
+}
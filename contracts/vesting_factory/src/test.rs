@@ -0,0 +1,176 @@
+#![cfg(test)]
+
+use crate::{AttestedMilestone, CliffMode, GrantTerms, MilestoneStatus, StartBounds, VestingFactoryContract, VestingSchedule, VestingFactoryContractClient};
+use payd_testutils::Asset;
+use soroban_sdk::{testutils::{Address as _, BytesN as _, Ledger}, Address, BytesN, Env, Vec};
+use vesting_escrow::VestingContractClient;
+
+fn setup<'a>(env: &Env) -> (VestingFactoryContractClient<'a>, Address) {
+    let contract_id = env.register(VestingFactoryContract, ());
+    let client = VestingFactoryContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let wasm_hash = env.deployer().upload_contract_wasm(payd_wasm::VESTING_ESCROW);
+    client.init(&admin, &wasm_hash);
+
+    (client, admin)
+}
+
+fn terms(env: &Env, beneficiary: &Address, token: &Address, amount: i128) -> GrantTerms {
+    GrantTerms {
+        beneficiary: beneficiary.clone(),
+        token: token.clone(),
+        start_time: env.ledger().timestamp(),
+        cliff_seconds: 100,
+        duration_seconds: 1000,
+        amount,
+        clawback_admin: Address::generate(env),
+        schedule: VestingSchedule::Linear,
+        cliff_mode: CliffMode::CatchUp,
+        start_bounds: StartBounds { max_backdate_seconds: 0, max_delay_seconds: 1000 },
+    }
+}
+
+#[test]
+fn test_create_grant_funds_and_initializes() {
+    let env = payd_testutils::env();
+
+    let (factory, _) = setup(&env);
+
+    let funder = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+    let asset = Asset::funded(&env, &funder, 10000);
+
+    let expected = factory.grant_address(&funder, &0);
+    let grant_id = factory.create_grant(&funder, &terms(&env, &beneficiary, &asset.address, 10000));
+    assert_eq!(grant_id, expected);
+
+    // Tokens moved straight from the funder into the new escrow
+    assert_eq!(asset.balance(&funder), 0);
+    assert_eq!(asset.balance(&grant_id), 10000);
+
+    let grant = VestingContractClient::new(&env, &grant_id);
+    let config = grant.get_config();
+    assert_eq!(config.beneficiary, beneficiary);
+    assert_eq!(config.total_amount, 10000);
+
+    // The deployed escrow vests and pays out independently
+    env.ledger().set_timestamp(500);
+    grant.claim();
+    assert_eq!(asset.balance(&beneficiary), 5000);
+}
+
+#[test]
+fn test_registries() {
+    let env = payd_testutils::env();
+
+    let (factory, _) = setup(&env);
+
+    let asset = Asset::new(&env);
+
+    let employer_a = Address::generate(&env);
+    let employer_b = Address::generate(&env);
+    let employee1 = Address::generate(&env);
+    let employee2 = Address::generate(&env);
+    asset.mint(&employer_a, 3000);
+    asset.mint(&employer_b, 1000);
+
+    let a1 = factory.create_grant(&employer_a, &terms(&env, &employee1, &asset.address, 1000));
+    let a2 = factory.create_grant(&employer_a, &terms(&env, &employee2, &asset.address, 1000));
+    let a3 = factory.create_grant(&employer_a, &terms(&env, &employee1, &asset.address, 1000));
+    let b1 = factory.create_grant(&employer_b, &terms(&env, &employee1, &asset.address, 1000));
+
+    assert_eq!(factory.get_grants_by_funder(&employer_a, &0, &10), Vec::from_array(&env, [a1.clone(), a2.clone(), a3.clone()]));
+    assert_eq!(factory.get_grants_by_funder(&employer_a, &1, &1), Vec::from_array(&env, [a2.clone()]));
+    assert_eq!(factory.get_grants_by_funder(&employer_b, &0, &10), Vec::from_array(&env, [b1.clone()]));
+    assert_eq!(factory.get_grants_by_beneficiary(&employee1, &0, &10), Vec::from_array(&env, [a1, a3, b1]));
+    assert_eq!(factory.get_grants_by_beneficiary(&employee2, &0, &10), Vec::from_array(&env, [a2]));
+    assert_eq!(factory.get_funder_grant_count(&employer_a), 3);
+    assert_eq!(factory.get_beneficiary_grant_count(&employee1), 3);
+}
+
+#[test]
+fn test_create_grant_rejects_invalid_terms() {
+    let env = payd_testutils::env();
+
+    let (factory, _) = setup(&env);
+
+    let funder = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+    let asset = Asset::funded(&env, &funder, 1000);
+
+    let mut bad_terms = terms(&env, &beneficiary, &asset.address, 1000);
+    bad_terms.cliff_seconds = bad_terms.duration_seconds + 1;
+
+    // `initialize` panics, which rolls back the deployment and leaves the registries empty
+    assert!(factory.try_create_grant(&funder, &bad_terms).is_err());
+    assert_eq!(factory.get_funder_grant_count(&funder), 0);
+    assert_eq!(factory.get_beneficiary_grant_count(&beneficiary), 0);
+}
+
+#[test]
+fn test_create_grant_with_periodic_schedule() {
+    let env = payd_testutils::env();
+
+    let (factory, _) = setup(&env);
+
+    let funder = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+    let asset = Asset::funded(&env, &funder, 1000);
+
+    let mut quarterly = terms(&env, &beneficiary, &asset.address, 1000);
+    quarterly.cliff_seconds = 0;
+    quarterly.schedule = VestingSchedule::Periodic(250, 250);
+
+    let grant_id = factory.create_grant(&funder, &quarterly);
+    let grant = VestingContractClient::new(&env, &grant_id);
+
+    env.ledger().set_timestamp(499);
+    assert_eq!(grant.get_vested_amount(), 250);
+    env.ledger().set_timestamp(500);
+    assert_eq!(grant.get_vested_amount(), 500);
+}
+
+#[test]
+fn test_create_contractor_grant_with_attester() {
+    let env = payd_testutils::env();
+
+    let (factory, _) = setup(&env);
+
+    let funder = Address::generate(&env);
+    let contractor = Address::generate(&env);
+    let attester = Address::generate(&env);
+    let asset = Asset::funded(&env, &funder, 1000);
+
+    let mut contract_terms = terms(&env, &contractor, &asset.address, 1000);
+    contract_terms.schedule = VestingSchedule::Attested(attester, Vec::from_array(&env, [
+        AttestedMilestone { id: 1, basis_points: 4000, deadline: None, status: MilestoneStatus::Pending },
+        AttestedMilestone { id: 2, basis_points: 6000, deadline: Some(5000), status: MilestoneStatus::Pending },
+    ]));
+
+    let grant_id = factory.create_grant(&funder, &contract_terms);
+    let grant = VestingContractClient::new(&env, &grant_id);
+
+    grant.attest_milestone(&1);
+    assert_eq!(grant.get_vested_amount(), 400);
+}
+
+#[test]
+fn test_set_wasm_hash() {
+    let env = payd_testutils::env();
+
+    let (factory, _) = setup(&env);
+
+    let new_hash = BytesN::<32>::random(&env);
+    factory.set_wasm_hash(&new_hash);
+    assert_eq!(factory.get_wasm_hash(), new_hash);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn test_init_twice() {
+    let env = Env::default();
+    let (factory, admin) = setup(&env);
+
+    factory.init(&admin, &BytesN::<32>::random(&env));
+}
@@ -0,0 +1,522 @@
+//! One measurement per public entry point. Calls that loop over stored data are measured at
+//! the sizes listed below rather than on a toy setup.
+
+use cross_asset_payment::{CrossAssetPaymentContract, CrossAssetPaymentContractClient};
+use merkle_payroll::{merkle, MerklePayrollContract, MerklePayrollContractClient};
+use multisig::{Action, MultisigContract, MultisigContractClient};
+use payroll::{PayrollContract, PayrollContractClient, RosterEntry};
+use payd_testutils::Asset;
+use payslip::{Payslip, PayslipContract, PayslipContractClient};
+use revenue_split::{RecipientShare, RevenueSplitContract, RevenueSplitContractClient};
+use revenue_split_factory::{RevenueSplitFactoryContract, RevenueSplitFactoryContractClient};
+use salary_stream::{SalaryStreamContract, SalaryStreamContractClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{symbol_short, vec, Address, Env, IntoVal, String, Symbol, Vec, token};
+use treasury_vault::{TreasuryVaultContract, TreasuryVaultContractClient};
+use vesting_escrow::{
+    AttestedMilestone, CliffMode, MilestoneStatus, TrancheTerms, VestingContract, VestingContractClient,
+    VestingSchedule, DEFAULT_START_BOUNDS, TOTAL_BASIS_POINTS,
+};
+use vesting_factory::{GrantTerms, VestingFactoryContract, VestingFactoryContractClient};
+
+use crate::{Baseline, Meter};
+
+/// Recipients of a revenue split, as many as it accepts. Every payment in `distribute` writes
+/// the recipient's balance, and a transaction may write at most 50 ledger entries; 44 is the
+/// most a direct `distribute` can pay, and the cap leaves room for the vault or a grant
+/// routing funds into the split.
+pub const SPLIT_RECIPIENTS: u32 = revenue_split::MAX_RECIPIENTS;
+/// Employees on the payroll roster, paid `payroll::PAGE_SIZE` per `run_payroll` call.
+pub const ROSTER_SIZE: u32 = 200;
+/// Open salary streams. The liability is a running total, so no call walks them.
+pub const STREAMS: u32 = 50;
+/// Milestones of a milestone-scheduled vesting grant.
+pub const MILESTONES: u32 = 50;
+/// Tranches stacked on a vesting grant by `top_up`, as many as it accepts.
+pub const TRANCHES: u32 = vesting_escrow::MAX_TRANCHES;
+/// Leaves in a Merkle payroll tree. A claim's proof grows with log2 of this, so
+/// its cost barely moves between ten and ten thousand employees.
+pub const MERKLE_LEAVES: u32 = 10_000;
+/// Payslips a worker holds when a lender checks their income: two years of monthly pay.
+pub const PAYSLIP_HISTORY: u32 = 24;
+/// Multisig signers; the threshold is a simple majority.
+pub const SIGNERS: u32 = 10;
+
+const START: u64 = 1_000_000;
+const DAY: u64 = 24 * 60 * 60;
+
+/// Measures every entry point and returns the costs by name.
+pub fn measure_all() -> Baseline {
+    let mut meter = Meter::default();
+    payment(&mut meter);
+    split(&mut meter);
+    split_factory(&mut meter);
+    payroll(&mut meter);
+    payslip(&mut meter);
+    merkle_payroll(&mut meter);
+    salary_stream(&mut meter);
+    treasury_vault(&mut meter);
+    multisig(&mut meter);
+    vesting(&mut meter);
+    vesting_milestones(&mut meter);
+    vesting_tranches(&mut meter);
+    vesting_attested(&mut meter);
+    vesting_factory(&mut meter);
+    meter.into_costs()
+}
+
+fn env() -> Env {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.cost_estimate().budget().reset_unlimited();
+    e.ledger().set_timestamp(START);
+    e
+}
+
+fn create_token<'a>(e: &Env, holder: &Address, amount: i128) -> token::Client<'a> {
+    Asset::funded(e, holder, amount).client
+}
+
+fn shares(e: &Env, count: u32) -> Vec<RecipientShare> {
+    let mut shares = Vec::new(e);
+    for i in 0..count {
+        // Equal shares, with the remainder on the last recipient
+        let basis_points = if i + 1 == count {
+            TOTAL_BASIS_POINTS - (count - 1) * (TOTAL_BASIS_POINTS / count)
+        } else {
+            TOTAL_BASIS_POINTS / count
+        };
+        shares.push_back(RecipientShare { destination: Address::generate(e), basis_points });
+    }
+    shares
+}
+
+fn payment(meter: &mut Meter) {
+    let e = env();
+    let admin = Address::generate(&e);
+    let sender = Address::generate(&e);
+    let token = create_token(&e, &sender, 1_000_000);
+    let client = CrossAssetPaymentContractClient::new(&e, &e.register(CrossAssetPaymentContract, ()));
+
+    meter.measure(&e, "payment::init", || client.init(&admin));
+    let id = meter.measure(&e, "payment::initiate_payment", || {
+        client.initiate_payment(
+            &sender,
+            &1_000,
+            &token.address,
+            &String::from_str(&e, "emp-42"),
+            &String::from_str(&e, "NGN"),
+            &String::from_str(&e, "anchor-1"),
+        )
+    });
+    meter.measure(&e, "payment::update_status", || client.update_status(&id, &symbol_short!("settled")));
+    meter.measure(&e, "payment::get_payment", || client.get_payment(&id));
+}
+
+fn split(meter: &mut Meter) {
+    let e = env();
+    let admin = Address::generate(&e);
+    let payer = Address::generate(&e);
+    let token = create_token(&e, &payer, 1_000_000_000);
+    let client = RevenueSplitContractClient::new(&e, &e.register(RevenueSplitContract, ()));
+
+    let initial = shares(&e, SPLIT_RECIPIENTS);
+    meter.measure(&e, "split::init/40", || client.init(&admin, &initial));
+    let replacement = shares(&e, SPLIT_RECIPIENTS);
+    meter.measure(&e, "split::update_recipients/40", || client.update_recipients(&replacement));
+    meter.measure(&e, "split::set_admin", || client.set_admin(&Address::generate(&e)));
+
+    meter.measure(&e, "split::distribute/40", || client.distribute(&token.address, &payer, &1_000_000));
+
+    let single = shares(&e, 1);
+    client.update_recipients(&single);
+    meter.measure(&e, "split::distribute/1", || client.distribute(&token.address, &payer, &1_000_000));
+
+    let new_wasm_hash = e.deployer().upload_contract_wasm(payd_wasm::REVENUE_SPLIT_WITH_LOGS);
+    meter.measure(&e, "split::upgrade", || client.upgrade(&new_wasm_hash));
+}
+
+fn split_factory(meter: &mut Meter) {
+    let e = env();
+    let admin = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let client = RevenueSplitFactoryContractClient::new(&e, &e.register(RevenueSplitFactoryContract, ()));
+    let wasm_hash = e.deployer().upload_contract_wasm(payd_wasm::REVENUE_SPLIT);
+
+    meter.measure(&e, "split_factory::init", || client.init(&admin, &wasm_hash));
+    meter.measure(&e, "split_factory::set_wasm_hash", || client.set_wasm_hash(&wasm_hash));
+
+    let mut factory_shares = Vec::new(&e);
+    for share in shares(&e, SPLIT_RECIPIENTS) {
+        factory_shares.push_back(revenue_split_factory::RecipientShare {
+            destination: share.destination,
+            basis_points: share.basis_points,
+        });
+    }
+    meter.measure(&e, "split_factory::deploy_split/40", || client.deploy_split(&owner, &factory_shares));
+    meter.measure(&e, "split_factory::get_splits", || client.get_splits(&owner, &0, &50));
+}
+
+fn payroll(meter: &mut Meter) {
+    let e = env();
+    let employer = Address::generate(&e);
+    let client = PayrollContractClient::new(&e, &e.register(PayrollContract, ()));
+    let token = create_token(&e, &employer, 1_000_000_000);
+
+    meter.measure(&e, "payroll::init", || client.init(&employer, &START, &(30 * DAY)));
+    meter.measure(&e, "payroll::deposit", || client.deposit(&employer, &token.address, &1_000_000_000));
+
+    let entry = || RosterEntry {
+        employee: Address::generate(&e),
+        asset: token.address.clone(),
+        amount: 1_000,
+        frequency: 1,
+        start_period: 0,
+    };
+    for _ in 1..ROSTER_SIZE {
+        client.set_employee(&entry());
+    }
+    let last = entry();
+    meter.measure(&e, "payroll::set_employee/200", || client.set_employee(&last));
+
+    // The first page, a page from the middle of the roster and the page that completes the run
+    let pages = ROSTER_SIZE.div_ceil(payroll::PAGE_SIZE);
+    meter.measure(&e, "payroll::run_payroll/first_page", || client.run_payroll(&0));
+    for _ in 1..pages / 2 {
+        client.run_payroll(&0);
+    }
+    meter.measure(&e, "payroll::run_payroll/middle_page", || client.run_payroll(&0));
+    for _ in pages / 2 + 1..pages - 1 {
+        client.run_payroll(&0);
+    }
+    let done = meter.measure(&e, "payroll::run_payroll/last_page", || client.run_payroll(&0));
+    assert!(done);
+
+    meter.measure(&e, "payroll::remove_employee/200", || client.remove_employee(&last.employee));
+    meter.measure(&e, "payroll::withdraw", || client.withdraw(&token.address, &employer, &1_000));
+
+    // With payslips on, each payment of the next period's first page also mints one
+    let payslips = PayslipContractClient::new(&e, &e.register(PayslipContract, ()));
+    payslips.init(&employer);
+    payslips.set_issuer(&client.address, &true);
+    meter.measure(&e, "payroll::set_payslips", || client.set_payslips(&Some(payslips.address.clone())));
+    e.ledger().set_timestamp(START + 30 * DAY);
+    meter.measure(&e, "payroll::run_payroll/payslip_page", || client.run_payroll(&1));
+}
+
+fn payslip(meter: &mut Meter) {
+    let e = env();
+    let admin = Address::generate(&e);
+    let issuer = Address::generate(&e);
+    let worker = Address::generate(&e);
+    let asset = Address::generate(&e);
+    let client = PayslipContractClient::new(&e, &e.register(PayslipContract, ()));
+
+    meter.measure(&e, "payslip::init", || client.init(&admin));
+    meter.measure(&e, "payslip::set_issuer", || client.set_issuer(&issuer, &true));
+
+    let payslip = |month: u64| Payslip {
+        issuer: issuer.clone(),
+        payer: admin.clone(),
+        asset: asset.clone(),
+        period_start: START + month * 30 * DAY,
+        period_end: START + (month + 1) * 30 * DAY,
+        gross: 5_000,
+        net: 4_000,
+    };
+    for month in 1..PAYSLIP_HISTORY as u64 {
+        client.mint(&worker, &payslip(month));
+    }
+    let latest = payslip(PAYSLIP_HISTORY as u64);
+    meter.measure(&e, "payslip::mint/24", || client.mint(&worker, &latest));
+
+    let end = START + (PAYSLIP_HISTORY as u64 + 1) * 30 * DAY;
+    meter.measure(&e, "payslip::payslip_count", || client.payslip_count(&worker));
+    meter.measure(&e, "payslip::payslips/24", || client.payslips(&worker, &START, &end, &0, &PAYSLIP_HISTORY));
+    meter.measure(&e, "payslip::income/24", || client.income(&worker, &asset, &START, &end, &0, &PAYSLIP_HISTORY));
+}
+
+fn merkle_payroll(meter: &mut Meter) {
+    let e = env();
+    let employer = Address::generate(&e);
+    let token = create_token(&e, &employer, 1_000_000_000);
+    let client = MerklePayrollContractClient::new(&e, &e.register(MerklePayrollContract, ()));
+
+    let employees: std::vec::Vec<Address> = (0..MERKLE_LEAVES).map(|_| Address::generate(&e)).collect();
+    let mut leaves = Vec::new(&e);
+    for (index, employee) in employees.iter().enumerate() {
+        leaves.push_back(merkle::leaf(&e, index as u32, employee, 1_000));
+    }
+    let root = merkle::root(&e, &leaves);
+    let total = MERKLE_LEAVES as i128 * 1_000;
+    let expires_at = START + 30 * DAY;
+
+    meter.measure(&e, "merkle_payroll::init", || client.init(&employer));
+    meter.measure(&e, "merkle_payroll::fund/10000", || {
+        client.fund(&0, &token.address, &root, &MERKLE_LEAVES, &total, &expires_at)
+    });
+
+    let last = MERKLE_LEAVES - 1;
+    let proof = merkle::proof(&e, &leaves, last);
+    meter.measure(&e, "merkle_payroll::claim/10000", || {
+        client.claim(&0, &employees[last as usize], &last, &1_000, &proof)
+    });
+
+    e.ledger().set_timestamp(expires_at);
+    meter.measure(&e, "merkle_payroll::reclaim", || client.reclaim(&0));
+}
+
+fn salary_stream(meter: &mut Meter) {
+    let e = env();
+    let employer = Address::generate(&e);
+    let token = create_token(&e, &employer, 1_000_000_000);
+    let client = SalaryStreamContractClient::new(&e, &e.register(SalaryStreamContract, ()));
+
+    meter.measure(&e, "salary_stream::init", || client.init(&employer, &token.address));
+    meter.measure(&e, "salary_stream::deposit", || client.deposit(&employer, &1_000_000_000));
+
+    let mut employees = std::vec::Vec::new();
+    for _ in 1..STREAMS {
+        let employee = Address::generate(&e);
+        client.open_stream(&employee, &10);
+        employees.push(employee);
+    }
+    let employee = Address::generate(&e);
+    meter.measure(&e, "salary_stream::open_stream/50", || client.open_stream(&employee, &10));
+
+    e.ledger().set_timestamp(START + DAY);
+    meter.measure(&e, "salary_stream::set_rate", || client.set_rate(&employee, &20));
+    meter.measure(&e, "salary_stream::withdraw", || client.withdraw(&employee));
+    meter.measure(&e, "salary_stream::get_liability/50", || client.get_liability());
+    meter.measure(&e, "salary_stream::cancel_stream", || client.cancel_stream(&employees[0]));
+    meter.measure(&e, "salary_stream::withdraw_treasury/50", || client.withdraw_treasury(&employer, &1_000));
+}
+
+fn treasury_vault(meter: &mut Meter) {
+    let e = env();
+    let admin = Address::generate(&e);
+    let member = Address::generate(&e);
+    let token = create_token(&e, &admin, 1_000_000_000);
+    let vault_id = e.register(TreasuryVaultContract, ());
+    let client = TreasuryVaultContractClient::new(&e, &vault_id);
+    let role = symbol_short!("payroll");
+
+    meter.measure(&e, "treasury_vault::init", || client.init(&admin));
+    meter.measure(&e, "treasury_vault::deposit", || client.deposit(&admin, &token.address, &1_000_000_000));
+    meter.measure(&e, "treasury_vault::set_role", || client.set_role(&member, &Some(role.clone())));
+    meter.measure(&e, "treasury_vault::set_daily_limit", || client.set_daily_limit(&role, &token.address, &1_000_000));
+    meter.measure(&e, "treasury_vault::withdraw", || client.withdraw(&member, &token.address, &member, &1_000));
+
+    // Spending into a split is the heaviest thing the vault pays for
+    let split = RevenueSplitContractClient::new(&e, &e.register(RevenueSplitContract, ()));
+    split.init(&admin, &shares(&e, SPLIT_RECIPIENTS));
+    let distribute = Symbol::new(&e, "distribute");
+    meter.measure(&e, "treasury_vault::approve", || {
+        client.approve(&member, &split.address, &distribute, &token.address, &1_000_000)
+    });
+    meter.measure(&e, "treasury_vault::spend/split_40", || {
+        client.spend(
+            &member,
+            &split.address,
+            &distribute,
+            &token.address,
+            &1_000_000,
+            &vec![&e, token.address.into_val(&e), vault_id.into_val(&e), 1_000_000_i128.into_val(&e)],
+        )
+    });
+}
+
+fn multisig(meter: &mut Meter) {
+    let e = env();
+    let mut signers = Vec::new(&e);
+    for _ in 0..SIGNERS {
+        signers.push_back(Address::generate(&e));
+    }
+    let threshold = SIGNERS / 2 + 1;
+    let client = MultisigContractClient::new(&e, &e.register(MultisigContract, ()));
+    meter.measure(&e, "multisig::init/10", || client.init(&signers, &threshold));
+
+    // The multisig administers a payment contract and settles a payment through it
+    let payments = CrossAssetPaymentContractClient::new(&e, &e.register(CrossAssetPaymentContract, ()));
+    payments.init(&client.address);
+    let sender = Address::generate(&e);
+    let token = create_token(&e, &sender, 1_000);
+    let id = payments.initiate_payment(
+        &sender,
+        &1_000,
+        &token.address,
+        &String::from_str(&e, "emp-42"),
+        &String::from_str(&e, "NGN"),
+        &String::from_str(&e, "anchor-1"),
+    );
+
+    let action = Action::Call(
+        payments.address.clone(),
+        Symbol::new(&e, "update_status"),
+        vec![&e, id.into_val(&e), symbol_short!("settled").into_val(&e)],
+    );
+    let proposer = signers.get(0).unwrap();
+    let proposal = meter.measure(&e, "multisig::propose/10", || client.propose(&proposer, &action, &(START + DAY)));
+    for i in 1..threshold - 1 {
+        client.approve(&signers.get(i).unwrap(), &proposal);
+    }
+    let last = signers.get(threshold - 1).unwrap();
+    meter.measure(&e, "multisig::approve/10", || client.approve(&last, &proposal));
+    meter.measure(&e, "multisig::execute/10", || client.execute(&proposal));
+
+    let spare = client.propose(&proposer, &action, &(START + DAY));
+    meter.measure(&e, "multisig::revoke/10", || client.revoke(&proposer, &spare));
+}
+
+struct Grant<'a> {
+    e: Env,
+    client: VestingContractClient<'a>,
+    funder: Address,
+    token: token::Client<'a>,
+}
+
+fn grant<'a>(meter: &mut Meter, e: Env, name: &str, schedule: VestingSchedule) -> Grant<'a> {
+    let funder = Address::generate(&e);
+    let token = create_token(&e, &funder, 1_000_000_000);
+    let client = VestingContractClient::new(&e, &e.register(VestingContract, ()));
+
+    meter.measure(&e, name, || {
+        let terms = TrancheTerms {
+            start_time: START,
+            cliff_seconds: 30 * DAY,
+            duration_seconds: 365 * DAY,
+            schedule,
+            cliff_mode: CliffMode::CatchUp,
+        };
+        client.initialize(
+            &funder,
+            &Address::generate(&e),
+            &token.address,
+            &1_000_000,
+            &Address::generate(&e),
+            &terms,
+            &DEFAULT_START_BOUNDS,
+        )
+    });
+
+    Grant { e, client, funder, token }
+}
+
+fn vesting(meter: &mut Meter) {
+    let Grant { e, client, funder, token } = grant(meter, env(), "vesting::initialize/linear", VestingSchedule::Linear);
+
+    e.ledger().set_timestamp(START + 100 * DAY);
+    meter.measure(&e, "vesting::get_vested_amount/linear", || client.get_vested_amount());
+    meter.measure(&e, "vesting::claim/linear", || client.claim());
+    e.ledger().set_timestamp(START + 102 * DAY);
+    meter.measure(&e, "vesting::claim_to", || client.claim_to(&Address::generate(&e)));
+
+    // Routing a claim into a split pays every recipient in the same transaction
+    let split = RevenueSplitContractClient::new(&e, &e.register(RevenueSplitContract, ()));
+    split.init(&Address::generate(&e), &shares(&e, SPLIT_RECIPIENTS));
+    let distribute = Symbol::new(&e, "distribute");
+    e.ledger().set_timestamp(START + 104 * DAY);
+    meter.measure(&e, "vesting::claim_to_contract/split_40", || client.claim_to_contract(&split.address, &distribute));
+
+    meter.measure(&e, "vesting::top_up", || client.top_up(&funder, &1_000, &None));
+    meter.measure(&e, "vesting::pause_vesting", || client.pause_vesting());
+    e.ledger().set_timestamp(START + 110 * DAY);
+    meter.measure(&e, "vesting::resume_vesting", || client.resume_vesting());
+    meter.measure(&e, "vesting::reconcile", || client.reconcile());
+    token.transfer(&funder, &client.address, &500);
+    meter.measure(&e, "vesting::sweep_excess", || client.sweep_excess(&funder));
+    meter.measure(&e, "vesting::transfer_claim_right", || client.transfer_claim_right(&Address::generate(&e)));
+    meter.measure(&e, "vesting::set_keeper", || client.set_keeper(&Some(Address::generate(&e))));
+    e.ledger().set_timestamp(START + 120 * DAY);
+    meter.measure(&e, "vesting::claim_for", || client.claim_for());
+    meter.measure(&e, "vesting::change_beneficiary", || client.change_beneficiary(&Address::generate(&e)));
+    meter.measure(&e, "vesting::clawback_partial", || client.clawback_partial(&1_000));
+    meter.measure(&e, "vesting::schedule_termination", || client.schedule_termination(&(START + 200 * DAY)));
+    e.ledger().set_timestamp(START + 200 * DAY);
+    meter.measure(&e, "vesting::clawback", || client.clawback());
+}
+
+fn vesting_milestones(meter: &mut Meter) {
+    let e = env();
+    let mut milestones = Vec::new(&e);
+    for i in 0..MILESTONES as u64 {
+        milestones.push_back((START + (i + 1) * 7 * DAY, TOTAL_BASIS_POINTS / MILESTONES));
+    }
+    let Grant { e, client, .. } = grant(meter, e, "vesting::initialize/milestones_50", VestingSchedule::Milestones(milestones));
+
+    e.ledger().set_timestamp(START + 200 * DAY);
+    meter.measure(&e, "vesting::get_vested_amount/milestones_50", || client.get_vested_amount());
+    meter.measure(&e, "vesting::claim/milestones_50", || client.claim());
+}
+
+fn vesting_tranches(meter: &mut Meter) {
+    let Grant { e, client, funder, .. } = grant(meter, env(), "vesting::initialize/tranches", VestingSchedule::Linear);
+
+    for i in 0..TRANCHES as u64 {
+        let terms = TrancheTerms {
+            start_time: START + i * 15 * DAY,
+            cliff_seconds: 0,
+            duration_seconds: 365 * DAY,
+            schedule: VestingSchedule::Linear,
+            cliff_mode: CliffMode::CatchUp,
+        };
+        client.top_up(&funder, &10_000, &Some(terms));
+    }
+
+    e.ledger().set_timestamp(START + 200 * DAY);
+    meter.measure(&e, "vesting::get_vested_amount/tranches_20", || client.get_vested_amount());
+    meter.measure(&e, "vesting::claim/tranches_20", || client.claim());
+}
+
+fn vesting_attested(meter: &mut Meter) {
+    let e = env();
+    let attester = Address::generate(&e);
+    let mut milestones = Vec::new(&e);
+    for id in 0..MILESTONES {
+        milestones.push_back(AttestedMilestone {
+            id,
+            basis_points: TOTAL_BASIS_POINTS / MILESTONES,
+            deadline: Some(START + (id as u64 + 1) * 7 * DAY),
+            status: MilestoneStatus::Pending,
+        });
+    }
+    let schedule = VestingSchedule::Attested(attester, milestones);
+    let Grant { e, client, .. } = grant(meter, e, "vesting::initialize/attested_50", schedule);
+
+    // The last milestone is found at the end of the list; every other one then expires
+    let last = MILESTONES - 1;
+    e.ledger().set_timestamp(START + 100 * DAY);
+    meter.measure(&e, "vesting::attest_milestone/attested_50", || client.attest_milestone(&last));
+    e.ledger().set_timestamp(START + 400 * DAY);
+    meter.measure(&e, "vesting::clawback_expired_milestones/attested_50", || client.clawback_expired_milestones());
+}
+
+fn vesting_factory(meter: &mut Meter) {
+    let e = env();
+    let admin = Address::generate(&e);
+    let funder = Address::generate(&e);
+    let token = create_token(&e, &funder, 1_000_000_000);
+    let client = VestingFactoryContractClient::new(&e, &e.register(VestingFactoryContract, ()));
+    let wasm_hash = e.deployer().upload_contract_wasm(payd_wasm::VESTING_ESCROW);
+
+    meter.measure(&e, "vesting_factory::init", || client.init(&admin, &wasm_hash));
+    meter.measure(&e, "vesting_factory::set_wasm_hash", || client.set_wasm_hash(&wasm_hash));
+
+    let terms = GrantTerms {
+        beneficiary: Address::generate(&e),
+        token: token.address.clone(),
+        start_time: START,
+        cliff_seconds: 30 * DAY,
+        duration_seconds: 365 * DAY,
+        amount: 1_000_000,
+        clawback_admin: admin,
+        schedule: vesting_factory::VestingSchedule::Linear,
+        cliff_mode: vesting_factory::CliffMode::CatchUp,
+        start_bounds: vesting_factory::StartBounds {
+            max_backdate_seconds: DEFAULT_START_BOUNDS.max_backdate_seconds,
+            max_delay_seconds: DEFAULT_START_BOUNDS.max_delay_seconds,
+        },
+    };
+    meter.measure(&e, "vesting_factory::create_grant", || client.create_grant(&funder, &terms));
+    meter.measure(&e, "vesting_factory::get_grants_by_funder", || client.get_grants_by_funder(&funder, &0, &50));
+}
@@ -0,0 +1,98 @@
+//! CPU and memory budget measurements for the PayD contract entry points.
+//!
+//! Every entry point is called at a realistic size (50 split recipients, a 200-person
+//! payroll roster, 50 vesting milestones), and a call that no longer fits in a transaction
+//! fails outright. `src/test.rs` compares the results with `baseline.json`; after an
+//! intended change, refresh it with
+//!
+//! ```sh
+//! UPDATE_BUDGET_BASELINE=1 cargo test -p payd-budget
+//! ```
+
+mod entry_points;
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use soroban_sdk::Env;
+
+pub use entry_points::measure_all;
+
+/// How much a cost may grow over the baseline before it counts as a regression.
+pub const TOLERANCE_PERCENT: u64 = 5;
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Cost {
+    pub cpu: u64,
+    pub memory: u64,
+}
+
+/// Entry point name (`contract::function/size`) to its cost.
+pub type Baseline = BTreeMap<String, Cost>;
+
+#[derive(Default)]
+pub struct Meter {
+    costs: Baseline,
+}
+
+impl Meter {
+    /// Records what `call`, a single contract call, used under `name`.
+    ///
+    /// The test environment starts every top-level call on a fresh budget and fails it if it
+    /// goes over the network's transaction limits (instructions, memory, ledger entries, event
+    /// size), so whatever the budget shows afterwards is that call's cost alone.
+    pub fn measure<T>(&mut self, e: &Env, name: &str, call: impl FnOnce() -> T) -> T {
+        let result = call();
+
+        let budget = e.cost_estimate().budget();
+        let cost = Cost { cpu: budget.cpu_instruction_cost(), memory: budget.memory_bytes_cost() };
+        if self.costs.insert(name.to_string(), cost).is_some() {
+            panic!("{name} measured twice");
+        }
+
+        result
+    }
+
+    pub fn into_costs(self) -> Baseline {
+        self.costs
+    }
+}
+
+pub fn parse_baseline(json: &str) -> Result<Baseline, serde_json::Error> {
+    serde_json::from_str(json)
+}
+
+pub fn format_baseline(baseline: &Baseline) -> String {
+    let mut json = serde_json::to_string_pretty(baseline).expect("costs serialize");
+    json.push('\n');
+    json
+}
+
+/// Everything that should fail the check: regressions beyond the tolerance, entry points
+/// missing from the baseline, and baseline entries that are no longer measured.
+pub fn compare(baseline: &Baseline, measured: &Baseline) -> Vec<String> {
+    let mut problems = Vec::new();
+
+    for (name, cost) in measured {
+        let Some(expected) = baseline.get(name) else {
+            problems.push(format!("{name}: not in the baseline"));
+            continue;
+        };
+        for (what, actual, allowed) in [("cpu", cost.cpu, expected.cpu), ("memory", cost.memory, expected.memory)] {
+            if actual > allowed + allowed * TOLERANCE_PERCENT / 100 {
+                problems.push(format!("{name}: {what} went from {allowed} to {actual}"));
+            }
+        }
+    }
+
+    for name in baseline.keys() {
+        if !measured.contains_key(name) {
+            problems.push(format!("{name}: in the baseline but no longer measured"));
+        }
+    }
+
+    problems
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,55 @@
+#![cfg(test)]
+
+use crate::*;
+
+const BASELINE: &str = include_str!("../baseline.json");
+
+fn cost(cpu: u64, memory: u64) -> Cost {
+    Cost { cpu, memory }
+}
+
+#[test]
+fn test_entry_points_within_baseline() {
+    let measured = measure_all();
+
+    if std::env::var_os("UPDATE_BUDGET_BASELINE").is_some() {
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("baseline.json");
+        std::fs::write(path, format_baseline(&measured)).unwrap();
+        return;
+    }
+
+    let problems = compare(&parse_baseline(BASELINE).unwrap(), &measured);
+    assert!(
+        problems.is_empty(),
+        "budget regressions (rerun with UPDATE_BUDGET_BASELINE=1 if intended):\n{}",
+        problems.join("\n")
+    );
+}
+
+#[test]
+fn test_compare_tolerance() {
+    let baseline = Baseline::from([("a".to_string(), cost(1_000, 2_000))]);
+
+    let within = Baseline::from([("a".to_string(), cost(1_050, 1_500))]);
+    assert!(compare(&baseline, &within).is_empty());
+
+    let over = Baseline::from([("a".to_string(), cost(1_051, 2_101))]);
+    assert_eq!(compare(&baseline, &over), ["a: cpu went from 1000 to 1051", "a: memory went from 2000 to 2101"]);
+}
+
+#[test]
+fn test_compare_entry_point_sets() {
+    let baseline = Baseline::from([("old".to_string(), cost(1, 1))]);
+    let measured = Baseline::from([("new".to_string(), cost(1, 1))]);
+
+    assert_eq!(compare(&baseline, &measured), [
+        "new: not in the baseline",
+        "old: in the baseline but no longer measured",
+    ]);
+}
+
+#[test]
+fn test_baseline_round_trip() {
+    let baseline = parse_baseline(BASELINE).unwrap();
+    assert_eq!(format_baseline(&baseline), BASELINE);
+}
@@ -0,0 +1,36 @@
+use core::fmt;
+
+use soroban_sdk::xdr;
+
+#[derive(Debug)]
+pub enum Error {
+    /// A strkey that is not a valid account (`G...`) or contract (`C...`) address.
+    InvalidAddress(String),
+    /// The transaction source must be a plain account.
+    InvalidSource(String),
+    Xdr(xdr::Error),
+    /// A value could not be converted to or from the expected contract type.
+    Conversion(&'static str),
+    /// A name that is not one of the `ContractKind`s.
+    UnknownContractKind(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidAddress(strkey) => write!(f, "invalid address: {strkey}"),
+            Error::InvalidSource(strkey) => write!(f, "invalid source account: {strkey}"),
+            Error::Xdr(e) => write!(f, "xdr error: {e}"),
+            Error::Conversion(what) => write!(f, "could not convert {what}"),
+            Error::UnknownContractKind(name) => write!(f, "unknown contract kind: {name}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<xdr::Error> for Error {
+    fn from(e: xdr::Error) -> Self {
+        Error::Xdr(e)
+    }
+}
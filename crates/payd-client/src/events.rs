@@ -0,0 +1,449 @@
+//! Typed decoding of the events emitted by the PayD contracts.
+//!
+//! Topic names are only unique within one contract (`clawback`, `deposit` and `withdraw`
+//! each mean something different in different contracts), so an event is always decoded
+//! against the kind of contract that emitted it. `Contracts` keeps track of which deployed
+//! contract is which.
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use soroban_sdk::xdr::{ContractEvent, ContractEventBody, Limits, ReadXdr, ScAddress, ScVal};
+use soroban_sdk::{Address, BytesN, Env, Symbol, TryFromVal, Val};
+
+use crate::{Distribution, Error, PaymentRecord, RecipientShare, RosterEntry};
+
+/// The PayD contracts, by what they do.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub enum ContractKind {
+    Payment,
+    Split,
+    SplitFactory,
+    Vesting,
+    VestingFactory,
+    SalaryStream,
+    Payroll,
+    TreasuryVault,
+    Multisig,
+    MerklePayroll,
+    OrgUsd,
+    Payslip,
+}
+
+impl ContractKind {
+    pub const ALL: [ContractKind; 12] = [
+        ContractKind::Payment,
+        ContractKind::Split,
+        ContractKind::SplitFactory,
+        ContractKind::Vesting,
+        ContractKind::VestingFactory,
+        ContractKind::SalaryStream,
+        ContractKind::Payroll,
+        ContractKind::TreasuryVault,
+        ContractKind::Multisig,
+        ContractKind::MerklePayroll,
+        ContractKind::OrgUsd,
+        ContractKind::Payslip,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ContractKind::Payment => "payment",
+            ContractKind::Split => "split",
+            ContractKind::SplitFactory => "split_factory",
+            ContractKind::Vesting => "vesting",
+            ContractKind::VestingFactory => "vesting_factory",
+            ContractKind::SalaryStream => "salary_stream",
+            ContractKind::Payroll => "payroll",
+            ContractKind::TreasuryVault => "treasury_vault",
+            ContractKind::Multisig => "multisig",
+            ContractKind::MerklePayroll => "merkle_payroll",
+            ContractKind::OrgUsd => "orgusd",
+            ContractKind::Payslip => "payslip",
+        }
+    }
+}
+
+impl FromStr for ContractKind {
+    type Err = Error;
+
+    fn from_str(name: &str) -> Result<Self, Error> {
+        ContractKind::ALL
+            .into_iter()
+            .find(|kind| kind.name() == name)
+            .ok_or_else(|| Error::UnknownContractKind(name.to_string()))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PaydEvent {
+    // CrossAssetPaymentContract
+    PaymentInitiated { payment_id: u64, record: Box<PaymentRecord> },
+    PaymentStatusUpdated { payment_id: u64, status: String },
+    // CrossAssetPaymentContract and TreasuryVaultContract
+    CouncilSet { council: Address },
+    ThresholdSet { asset: Address, threshold: i128 },
+    // RevenueSplitContract
+    SharesUpdated { shares: Vec<RecipientShare> },
+    Distributed { token: Address, from: Address, amount: i128 },
+    Upgraded { wasm_hash: BytesN<32> },
+    // RevenueSplitFactory and VestingFactory
+    TemplateUpdated { wasm_hash: BytesN<32> },
+    SplitCreated { owner: Address, split: Address },
+    GrantCreated { funder: Address, beneficiary: Address, grant: Address },
+    // VestingContract
+    Granted { beneficiary: Address, token: Address, amount: i128 },
+    Claimed { destination: Address, amount: i128 },
+    ToppedUp { funder: Address, amount: i128 },
+    KeeperSet { holder: Address, keeper: Option<Address> },
+    ClaimRightTransferred { from: Address, to: Address },
+    BeneficiaryChanged { old: Address, new: Address },
+    /// `unvested` is what the grant gave up; `returned` is what was paid back, which is less
+    /// when the grant's balance was short.
+    ClawedBack { admin: Address, unvested: i128, returned: i128 },
+    PartiallyClawedBack { admin: Address, amount: i128 },
+    MilestoneAttested { attester: Address, milestone_id: u32 },
+    MilestonesForfeited { admin: Address, amount: i128 },
+    TerminationScheduled { beneficiary: Address, termination_time: u64 },
+    Paused { beneficiary: Address, at: u64 },
+    Resumed { beneficiary: Address, paused_seconds: u64 },
+    ExcessSwept { to: Address, amount: i128 },
+    Shortfall { beneficiary: Address, amount: i128 },
+    // SalaryStreamContract
+    StreamFunded { from: Address, amount: i128 },
+    StreamCreated { employee: Address, rate_per_second: i128 },
+    StreamRateUpdated { employee: Address, rate_per_second: i128 },
+    StreamCancelled { employee: Address, accrued: i128 },
+    StreamWithdrawn { employee: Address, amount: i128 },
+    StreamUnderpaid { employee: Address, amount: i128 },
+    TreasuryWithdrawn { to: Address, amount: i128 },
+    // PayrollContract
+    PayrollFunded { from: Address, asset: Address, amount: i128 },
+    PayrollWithdrawn { to: Address, asset: Address, amount: i128 },
+    EmployeeSet { entry: RosterEntry },
+    EmployeeRemoved { employee: Address },
+    EmployeePaid { period: u32, employee: Address, amount: i128 },
+    PayrollRunCompleted { period: u32, roster_size: u32 },
+    PayslipIssued { period: u32, employee: Address, payslip_id: u32 },
+    // TreasuryVaultContract
+    VaultDeposited { from: Address, asset: Address, amount: i128 },
+    RoleSet { member: Address, role: Option<String> },
+    DailyLimitSet { role: String, asset: Address, limit: i128 },
+    VaultWithdrawn { member: Address, asset: Address, to: Address, amount: i128 },
+    SpendApproved { spender: Address, target: Address, function: String, asset: Address, amount: i128 },
+    Spent { spender: Address, target: Address, asset: Address, amount: i128 },
+    // MultisigContract
+    Proposed { proposal_id: u32, proposer: Address, call_hash: BytesN<32> },
+    ProposalApproved { proposal_id: u32, signer: Address },
+    ApprovalRevoked { proposal_id: u32, signer: Address },
+    ProposalExecuted { proposal_id: u32, call_hash: BytesN<32> },
+    SignersSet { signers: Vec<Address>, threshold: u32 },
+    // MerklePayrollContract
+    DistributionFunded { period: u32, distribution: Box<Distribution> },
+    DistributionClaimed { period: u32, employee: Address, index: u32, amount: i128 },
+    DistributionReclaimed { period: u32, amount: i128 },
+    // OrgUsdContract
+    TokenClawedBack { admin: Address, from: Address, amount: i128 },
+    FreezeSet { account: Address, frozen: bool },
+    // PayslipContract
+    IssuerSet { issuer: Address, approved: bool },
+    PayslipMinted { to: Address, payer: Address, payslip_id: u32, asset: Address, net: i128 },
+}
+
+/// An event together with the contract that emitted it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecodedEvent {
+    pub contract: Option<Address>,
+    pub kind: ContractKind,
+    pub event: PaydEvent,
+}
+
+/// The contracts being followed, by address. Grants and splits deployed by a followed
+/// factory are followed from the event that announces them.
+#[derive(Clone, Debug, Default)]
+pub struct Contracts {
+    kinds: BTreeMap<ScAddress, ContractKind>,
+}
+
+impl Contracts {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, contract: ScAddress, kind: ContractKind) {
+        self.kinds.insert(contract, kind);
+    }
+
+    pub fn kind(&self, contract: &ScAddress) -> Option<ContractKind> {
+        self.kinds.get(contract).copied()
+    }
+
+    /// Decodes an event from a followed contract. Returns `Ok(None)` for events from
+    /// other contracts, such as token transfers, and for topics the contract doesn't emit.
+    pub fn decode(&mut self, env: &Env, event: &ContractEvent) -> Result<Option<DecodedEvent>, Error> {
+        let Some(id) = &event.contract_id else {
+            return Ok(None);
+        };
+        let Some(kind) = self.kind(&ScAddress::Contract(id.clone())) else {
+            return Ok(None);
+        };
+
+        let decoded = decode_event(env, kind, event)?;
+        match decoded.as_ref().map(|decoded| &decoded.event) {
+            Some(PaydEvent::GrantCreated { grant, .. }) => self.insert(grant.into(), ContractKind::Vesting),
+            Some(PaydEvent::SplitCreated { split, .. }) => self.insert(split.into(), ContractKind::Split),
+            _ => {}
+        }
+        Ok(decoded)
+    }
+
+    /// Decodes a base64 `ContractEvent`, as found in transaction meta.
+    pub fn decode_base64(&mut self, env: &Env, xdr: &str) -> Result<Option<DecodedEvent>, Error> {
+        let event = ContractEvent::from_xdr_base64(xdr, Limits::none())?;
+        self.decode(env, &event)
+    }
+}
+
+/// Decodes an event emitted by a `kind` contract. Returns `Ok(None)` for topics that
+/// contract doesn't emit, and an error when a known event has an unexpected shape.
+pub fn decode_event(env: &Env, kind: ContractKind, event: &ContractEvent) -> Result<Option<DecodedEvent>, Error> {
+    let ContractEventBody::V0(body) = &event.body;
+    let fields = Fields { env, topics: &body.topics, data: &body.data };
+
+    let Some(ScVal::Symbol(name)) = body.topics.first() else {
+        return Ok(None);
+    };
+    let Ok(name) = name.to_utf8_string() else {
+        return Ok(None);
+    };
+
+    let decoded = match kind {
+        ContractKind::Payment => payment_event(&fields, &name)?,
+        ContractKind::Split => split_event(&fields, &name)?,
+        ContractKind::SplitFactory | ContractKind::VestingFactory => factory_event(&fields, &name)?,
+        ContractKind::Vesting => vesting_event(&fields, &name)?,
+        ContractKind::SalaryStream => stream_event(&fields, &name)?,
+        ContractKind::Payroll => payroll_event(&fields, &name)?,
+        ContractKind::TreasuryVault => vault_event(&fields, &name)?,
+        ContractKind::Multisig => multisig_event(&fields, &name)?,
+        ContractKind::MerklePayroll => merkle_payroll_event(&fields, &name)?,
+        ContractKind::OrgUsd => orgusd_event(&fields, &name)?,
+        ContractKind::Payslip => payslip_event(&fields, &name)?,
+    };
+    let Some(decoded) = decoded else {
+        return Ok(None);
+    };
+
+    let contract = match &event.contract_id {
+        Some(id) => Some(convert(env, &ScVal::Address(ScAddress::Contract(id.clone())))?),
+        None => None,
+    };
+
+    Ok(Some(DecodedEvent { contract, kind, event: decoded }))
+}
+
+/// Decodes a base64 `ContractEvent` emitted by a `kind` contract.
+pub fn decode_event_base64(env: &Env, kind: ContractKind, xdr: &str) -> Result<Option<DecodedEvent>, Error> {
+    let event = ContractEvent::from_xdr_base64(xdr, Limits::none())?;
+    decode_event(env, kind, &event)
+}
+
+/// The topics and data of one event.
+struct Fields<'a> {
+    env: &'a Env,
+    topics: &'a [ScVal],
+    data: &'a ScVal,
+}
+
+impl Fields<'_> {
+    fn topic<T: TryFromVal<Env, Val>>(&self, index: usize) -> Result<T, Error> {
+        convert(self.env, self.topics.get(index).ok_or(Error::Conversion("event topic"))?)
+    }
+
+    fn data<T: TryFromVal<Env, Val>>(&self) -> Result<T, Error> {
+        convert(self.env, self.data)
+    }
+
+    fn symbol_data(&self) -> Result<String, Error> {
+        Ok(self.data::<Symbol>()?.to_string())
+    }
+}
+
+fn payment_event(f: &Fields, name: &str) -> Result<Option<PaydEvent>, Error> {
+    let event = match name {
+        "pay_init" => PaydEvent::PaymentInitiated { payment_id: f.topic(1)?, record: Box::new(f.data()?) },
+        "pay_upd" => PaydEvent::PaymentStatusUpdated { payment_id: f.topic(1)?, status: f.symbol_data()? },
+        _ => return council_event(f, name),
+    };
+    Ok(Some(event))
+}
+
+fn council_event(f: &Fields, name: &str) -> Result<Option<PaydEvent>, Error> {
+    let event = match name {
+        "council" => PaydEvent::CouncilSet { council: f.data()? },
+        "threshold" => PaydEvent::ThresholdSet { asset: f.topic(1)?, threshold: f.data()? },
+        _ => return Ok(None),
+    };
+    Ok(Some(event))
+}
+
+fn split_event(f: &Fields, name: &str) -> Result<Option<PaydEvent>, Error> {
+    let event = match name {
+        "shares" => PaydEvent::SharesUpdated {
+            shares: f.data::<soroban_sdk::Vec<RecipientShare>>()?.iter().collect(),
+        },
+        "distrib" => PaydEvent::Distributed { token: f.topic(1)?, from: f.topic(2)?, amount: f.data()? },
+        "upgraded" => PaydEvent::Upgraded { wasm_hash: f.data()? },
+        _ => return council_event(f, name),
+    };
+    Ok(Some(event))
+}
+
+fn factory_event(f: &Fields, name: &str) -> Result<Option<PaydEvent>, Error> {
+    let event = match name {
+        "tmpl_upd" => PaydEvent::TemplateUpdated { wasm_hash: f.data()? },
+        "split_new" => PaydEvent::SplitCreated { owner: f.topic(1)?, split: f.data()? },
+        "grant_new" => PaydEvent::GrantCreated { funder: f.topic(1)?, beneficiary: f.topic(2)?, grant: f.data()? },
+        _ => return Ok(None),
+    };
+    Ok(Some(event))
+}
+
+fn vesting_event(f: &Fields, name: &str) -> Result<Option<PaydEvent>, Error> {
+    let event = match name {
+        "granted" => {
+            let (token, amount) = f.data()?;
+            PaydEvent::Granted { beneficiary: f.topic(1)?, token, amount }
+        }
+        "claimed" => PaydEvent::Claimed { destination: f.topic(1)?, amount: f.data()? },
+        "top_up" => PaydEvent::ToppedUp { funder: f.topic(1)?, amount: f.data()? },
+        "keeper" => PaydEvent::KeeperSet { holder: f.topic(1)?, keeper: f.data()? },
+        "claim_xfr" => PaydEvent::ClaimRightTransferred { from: f.topic(1)?, to: f.data()? },
+        "ben_upd" => PaydEvent::BeneficiaryChanged { old: f.topic(1)?, new: f.data()? },
+        "clawback" => {
+            let (unvested, returned) = f.data()?;
+            PaydEvent::ClawedBack { admin: f.topic(1)?, unvested, returned }
+        }
+        "claw_part" => PaydEvent::PartiallyClawedBack { admin: f.topic(1)?, amount: f.data()? },
+        "attested" => PaydEvent::MilestoneAttested { attester: f.topic(1)?, milestone_id: f.data()? },
+        "forfeit" => PaydEvent::MilestonesForfeited { admin: f.topic(1)?, amount: f.data()? },
+        "term_set" => PaydEvent::TerminationScheduled { beneficiary: f.topic(1)?, termination_time: f.data()? },
+        "paused" => PaydEvent::Paused { beneficiary: f.topic(1)?, at: f.data()? },
+        "resumed" => PaydEvent::Resumed { beneficiary: f.topic(1)?, paused_seconds: f.data()? },
+        "swept" => PaydEvent::ExcessSwept { to: f.topic(1)?, amount: f.data()? },
+        "shortfall" => PaydEvent::Shortfall { beneficiary: f.topic(1)?, amount: f.data()? },
+        _ => return council_event(f, name),
+    };
+    Ok(Some(event))
+}
+
+fn stream_event(f: &Fields, name: &str) -> Result<Option<PaydEvent>, Error> {
+    let event = match name {
+        "deposit" => PaydEvent::StreamFunded { from: f.topic(1)?, amount: f.data()? },
+        "strm_new" => PaydEvent::StreamCreated { employee: f.topic(1)?, rate_per_second: f.data()? },
+        "rate_upd" => PaydEvent::StreamRateUpdated { employee: f.topic(1)?, rate_per_second: f.data()? },
+        "cancel" => PaydEvent::StreamCancelled { employee: f.topic(1)?, accrued: f.data()? },
+        "withdraw" => PaydEvent::StreamWithdrawn { employee: f.topic(1)?, amount: f.data()? },
+        "underpaid" => PaydEvent::StreamUnderpaid { employee: f.topic(1)?, amount: f.data()? },
+        "treas_out" => PaydEvent::TreasuryWithdrawn { to: f.topic(1)?, amount: f.data()? },
+        _ => return Ok(None),
+    };
+    Ok(Some(event))
+}
+
+fn payroll_event(f: &Fields, name: &str) -> Result<Option<PaydEvent>, Error> {
+    let event = match name {
+        "deposit" => PaydEvent::PayrollFunded { from: f.topic(1)?, asset: f.topic(2)?, amount: f.data()? },
+        "withdraw" => PaydEvent::PayrollWithdrawn { to: f.topic(1)?, asset: f.topic(2)?, amount: f.data()? },
+        "emp_set" => PaydEvent::EmployeeSet { entry: f.data()? },
+        "emp_rm" => PaydEvent::EmployeeRemoved { employee: f.topic(1)? },
+        "paid" => PaydEvent::EmployeePaid { period: f.topic(1)?, employee: f.topic(2)?, amount: f.data()? },
+        "run_done" => PaydEvent::PayrollRunCompleted { period: f.topic(1)?, roster_size: f.data()? },
+        "payslip" => PaydEvent::PayslipIssued { period: f.topic(1)?, employee: f.topic(2)?, payslip_id: f.data()? },
+        _ => return Ok(None),
+    };
+    Ok(Some(event))
+}
+
+fn vault_event(f: &Fields, name: &str) -> Result<Option<PaydEvent>, Error> {
+    let event = match name {
+        "deposit" => PaydEvent::VaultDeposited { from: f.topic(1)?, asset: f.topic(2)?, amount: f.data()? },
+        "role" => PaydEvent::RoleSet {
+            member: f.topic(1)?,
+            role: f.data::<Option<Symbol>>()?.map(|role| role.to_string()),
+        },
+        "limit" => PaydEvent::DailyLimitSet {
+            role: f.topic::<Symbol>(1)?.to_string(),
+            asset: f.topic(2)?,
+            limit: f.data()?,
+        },
+        "withdraw" => {
+            let (to, amount) = f.data()?;
+            PaydEvent::VaultWithdrawn { member: f.topic(1)?, asset: f.topic(2)?, to, amount }
+        }
+        "approve" => {
+            let (function, asset, amount): (Symbol, Address, i128) = f.data()?;
+            PaydEvent::SpendApproved { spender: f.topic(1)?, target: f.topic(2)?, function: function.to_string(), asset, amount }
+        }
+        "spend" => {
+            let (asset, amount) = f.data()?;
+            PaydEvent::Spent { spender: f.topic(1)?, target: f.topic(2)?, asset, amount }
+        }
+        _ => return council_event(f, name),
+    };
+    Ok(Some(event))
+}
+
+fn multisig_event(f: &Fields, name: &str) -> Result<Option<PaydEvent>, Error> {
+    let event = match name {
+        "proposed" => PaydEvent::Proposed { proposal_id: f.topic(1)?, proposer: f.topic(2)?, call_hash: f.data()? },
+        "approved" => PaydEvent::ProposalApproved { proposal_id: f.topic(1)?, signer: f.data()? },
+        "revoked" => PaydEvent::ApprovalRevoked { proposal_id: f.topic(1)?, signer: f.data()? },
+        "executed" => PaydEvent::ProposalExecuted { proposal_id: f.topic(1)?, call_hash: f.data()? },
+        "signers" => {
+            let (signers, threshold): (soroban_sdk::Vec<Address>, u32) = f.data()?;
+            PaydEvent::SignersSet { signers: signers.iter().collect(), threshold }
+        }
+        _ => return Ok(None),
+    };
+    Ok(Some(event))
+}
+
+fn merkle_payroll_event(f: &Fields, name: &str) -> Result<Option<PaydEvent>, Error> {
+    let event = match name {
+        "funded" => PaydEvent::DistributionFunded { period: f.topic(1)?, distribution: Box::new(f.data()?) },
+        "claim" => {
+            let (index, amount) = f.data()?;
+            PaydEvent::DistributionClaimed { period: f.topic(1)?, employee: f.topic(2)?, index, amount }
+        }
+        "reclaim" => PaydEvent::DistributionReclaimed { period: f.topic(1)?, amount: f.data()? },
+        _ => return Ok(None),
+    };
+    Ok(Some(event))
+}
+
+fn orgusd_event(f: &Fields, name: &str) -> Result<Option<PaydEvent>, Error> {
+    let event = match name {
+        "clawback" => PaydEvent::TokenClawedBack { admin: f.topic(1)?, from: f.topic(2)?, amount: f.data()? },
+        "frozen" => PaydEvent::FreezeSet { account: f.topic(1)?, frozen: f.data()? },
+        _ => return Ok(None),
+    };
+    Ok(Some(event))
+}
+
+fn payslip_event(f: &Fields, name: &str) -> Result<Option<PaydEvent>, Error> {
+    let event = match name {
+        "issuer" => PaydEvent::IssuerSet { issuer: f.topic(1)?, approved: f.data()? },
+        "payslip" => {
+            let (payslip_id, asset, net) = f.data()?;
+            PaydEvent::PayslipMinted { to: f.topic(1)?, payer: f.topic(2)?, payslip_id, asset, net }
+        }
+        _ => return Ok(None),
+    };
+    Ok(Some(event))
+}
+
+fn convert<T: TryFromVal<Env, Val>>(env: &Env, value: &ScVal) -> Result<T, Error> {
+    let value = Val::try_from_val(env, value).map_err(|_| Error::Conversion("event value"))?;
+    T::try_from_val(env, &value).map_err(|_| Error::Conversion("event value"))
+}
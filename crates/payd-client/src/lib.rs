@@ -0,0 +1,46 @@
+//! Off-chain client for the PayD contracts.
+//!
+//! Builds unsigned `InvokeHostFunction` transactions for the contract entry points and
+//! decodes their return values and events, all from XDR and without network access.
+//! The contracts' own `#[contracttype]` types are re-exported so services use exactly
+//! the shapes the contracts encode.
+//!
+//! Transactions are built without Soroban resource data or authorization entries;
+//! run them through `simulateTransaction` and assemble the result before signing.
+
+mod error;
+mod tx;
+
+pub mod events;
+pub mod merkle_payroll;
+pub mod multisig;
+pub mod orgusd;
+pub mod payment;
+pub mod payroll;
+pub mod payslip;
+pub mod salary_stream;
+pub mod split;
+pub mod split_factory;
+pub mod treasury_vault;
+pub mod vesting;
+pub mod vesting_factory;
+
+pub use error::Error;
+pub use tx::{decode_return, decode_return_base64, envelope_from_base64, envelope_to_base64, TxBuilder};
+
+pub use cross_asset_payment::PaymentRecord;
+pub use ::merkle_payroll::Distribution;
+pub use ::multisig::{Action, Proposal};
+pub use ::payroll::RosterEntry;
+pub use ::payslip::Payslip;
+pub use ::salary_stream::Stream;
+pub use revenue_split::RecipientShare;
+pub use vesting_escrow::{
+    AttestedMilestone, CliffMode, MilestoneStatus, Reconciliation, StartBounds, Tranche, TrancheTerms,
+    VestingConfig, VestingSchedule, DEFAULT_START_BOUNDS,
+};
+
+pub use soroban_sdk::xdr;
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,67 @@
+//! Calls to `MerklePayrollContract`.
+
+use soroban_sdk::xdr::InvokeContractArgs;
+use soroban_sdk::{Address, BytesN, Env, IntoVal, Vec};
+
+use crate::tx::invocation;
+use crate::Error;
+
+/// Arguments of `fund`.
+#[derive(Clone, Debug)]
+pub struct Fund {
+    pub period: u32,
+    pub asset: Address,
+    pub root: BytesN<32>,
+    pub leaf_count: u32,
+    pub total: i128,
+    pub expires_at: u64,
+}
+
+pub fn init(env: &Env, contract: &Address, employer: &Address) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "init", (employer.clone(),).into_val(env))
+}
+
+pub fn fund(env: &Env, contract: &Address, distribution: &Fund) -> Result<InvokeContractArgs, Error> {
+    let args = (
+        distribution.period,
+        distribution.asset.clone(),
+        distribution.root.clone(),
+        distribution.leaf_count,
+        distribution.total,
+        distribution.expires_at,
+    );
+    invocation(env, contract, "fund", args.into_val(env))
+}
+
+pub fn claim(
+    env: &Env,
+    contract: &Address,
+    period: u32,
+    employee: &Address,
+    index: u32,
+    amount: i128,
+    proof: &[BytesN<32>],
+) -> Result<InvokeContractArgs, Error> {
+    let proof = Vec::from_slice(env, proof);
+    invocation(env, contract, "claim", (period, employee.clone(), index, amount, proof).into_val(env))
+}
+
+/// Returns the amount reclaimed, as `i128`.
+pub fn reclaim(env: &Env, contract: &Address, period: u32) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "reclaim", (period,).into_val(env))
+}
+
+/// Returns `Option<Distribution>`.
+pub fn get_distribution(env: &Env, contract: &Address, period: u32) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "get_distribution", (period,).into_val(env))
+}
+
+/// Returns `bool`.
+pub fn is_claimed(env: &Env, contract: &Address, period: u32, index: u32) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "is_claimed", (period, index).into_val(env))
+}
+
+/// Returns the leaf hash, as `BytesN<32>`.
+pub fn leaf(env: &Env, contract: &Address, index: u32, employee: &Address, amount: i128) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "leaf", (index, employee.clone(), amount).into_val(env))
+}
@@ -0,0 +1,52 @@
+//! Calls to `MultisigContract`.
+
+use soroban_sdk::xdr::InvokeContractArgs;
+use soroban_sdk::{Address, Env, IntoVal, Vec};
+
+use crate::tx::{invocation, nested_call, no_args};
+use crate::{Action, Error};
+
+pub fn init(env: &Env, contract: &Address, signers: &[Address], threshold: u32) -> Result<InvokeContractArgs, Error> {
+    let signers = Vec::from_slice(env, signers);
+    invocation(env, contract, "init", (signers, threshold).into_val(env))
+}
+
+/// The action that makes `call` once a proposal for it has passed, e.g. a treasury vault
+/// `withdraw` above the council threshold built by this crate.
+pub fn call_action(env: &Env, call: &InvokeContractArgs) -> Result<Action, Error> {
+    let (contract, function, args) = nested_call(env, call)?;
+    Ok(Action::Call(contract, function, args))
+}
+
+/// Returns the proposal id, as `u32`.
+pub fn propose(env: &Env, contract: &Address, proposer: &Address, action: &Action, expires_at: u64) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "propose", (proposer.clone(), action.clone(), expires_at).into_val(env))
+}
+
+pub fn approve(env: &Env, contract: &Address, signer: &Address, proposal_id: u32) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "approve", (signer.clone(), proposal_id).into_val(env))
+}
+
+pub fn revoke(env: &Env, contract: &Address, signer: &Address, proposal_id: u32) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "revoke", (signer.clone(), proposal_id).into_val(env))
+}
+
+/// Returns whatever the proposed call returns.
+pub fn execute(env: &Env, contract: &Address, proposal_id: u32) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "execute", (proposal_id,).into_val(env))
+}
+
+/// Returns `Option<Proposal>`.
+pub fn get_proposal(env: &Env, contract: &Address, proposal_id: u32) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "get_proposal", (proposal_id,).into_val(env))
+}
+
+/// Returns `Vec<Address>`.
+pub fn get_signers(env: &Env, contract: &Address) -> Result<InvokeContractArgs, Error> {
+    no_args(env, contract, "get_signers")
+}
+
+/// Returns `u32`.
+pub fn get_threshold(env: &Env, contract: &Address) -> Result<InvokeContractArgs, Error> {
+    no_args(env, contract, "get_threshold")
+}
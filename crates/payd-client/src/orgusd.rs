@@ -0,0 +1,57 @@
+//! Calls to `OrgUsdContract`. It is deployed with its constructor arguments, so there is no
+//! `init`; the SEP-41 calls below are the ones the PayD services make.
+
+use soroban_sdk::xdr::InvokeContractArgs;
+use soroban_sdk::{Address, Env, IntoVal};
+
+use crate::tx::invocation;
+use crate::Error;
+
+pub fn mint(env: &Env, contract: &Address, to: &Address, amount: i128) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "mint", (to.clone(), amount).into_val(env))
+}
+
+pub fn clawback(env: &Env, contract: &Address, from: &Address, amount: i128) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "clawback", (from.clone(), amount).into_val(env))
+}
+
+/// `operator` must hold the `compliance` role.
+pub fn freeze(env: &Env, contract: &Address, account: &Address, operator: &Address) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "freeze", (account.clone(), operator.clone()).into_val(env))
+}
+
+pub fn unfreeze(env: &Env, contract: &Address, account: &Address, operator: &Address) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "unfreeze", (account.clone(), operator.clone()).into_val(env))
+}
+
+/// `operator` must hold the `compliance` role.
+pub fn allow_user(env: &Env, contract: &Address, user: &Address, operator: &Address) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "allow_user", (user.clone(), operator.clone()).into_val(env))
+}
+
+pub fn disallow_user(env: &Env, contract: &Address, user: &Address, operator: &Address) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "disallow_user", (user.clone(), operator.clone()).into_val(env))
+}
+
+pub fn transfer(env: &Env, contract: &Address, from: &Address, to: &Address, amount: i128) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "transfer", (from.clone(), to.clone(), amount).into_val(env))
+}
+
+pub fn burn(env: &Env, contract: &Address, from: &Address, amount: i128) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "burn", (from.clone(), amount).into_val(env))
+}
+
+/// Returns `i128`.
+pub fn balance(env: &Env, contract: &Address, account: &Address) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "balance", (account.clone(),).into_val(env))
+}
+
+/// Returns `bool`.
+pub fn allowed(env: &Env, contract: &Address, account: &Address) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "allowed", (account.clone(),).into_val(env))
+}
+
+/// Returns `bool`.
+pub fn is_frozen(env: &Env, contract: &Address, account: &Address) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "is_frozen", (account.clone(),).into_val(env))
+}
@@ -0,0 +1,44 @@
+//! Calls to `CrossAssetPaymentContract`.
+
+use soroban_sdk::xdr::InvokeContractArgs;
+use soroban_sdk::{Address, Env, IntoVal, String};
+
+use crate::tx::{invocation, symbol};
+use crate::Error;
+
+/// Arguments of `initiate_payment`.
+#[derive(Clone, Debug)]
+pub struct InitiatePayment {
+    pub from: Address,
+    pub amount: i128,
+    pub asset: Address,
+    pub receiver_id: std::string::String,
+    pub target_asset: std::string::String,
+    pub anchor_id: std::string::String,
+}
+
+pub fn init(env: &Env, contract: &Address, admin: &Address) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "init", (admin.clone(),).into_val(env))
+}
+
+pub fn initiate_payment(env: &Env, contract: &Address, payment: &InitiatePayment) -> Result<InvokeContractArgs, Error> {
+    let args = (
+        payment.from.clone(),
+        payment.amount,
+        payment.asset.clone(),
+        String::from_str(env, &payment.receiver_id),
+        String::from_str(env, &payment.target_asset),
+        String::from_str(env, &payment.anchor_id),
+    );
+    invocation(env, contract, "initiate_payment", args.into_val(env))
+}
+
+pub fn update_status(env: &Env, contract: &Address, payment_id: u64, new_status: &str) -> Result<InvokeContractArgs, Error> {
+    let status = symbol(env, new_status)?;
+    invocation(env, contract, "update_status", (payment_id, status).into_val(env))
+}
+
+/// Returns `Option<PaymentRecord>`.
+pub fn get_payment(env: &Env, contract: &Address, payment_id: u64) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "get_payment", (payment_id,).into_val(env))
+}
@@ -0,0 +1,76 @@
+//! Calls to `PayrollContract`.
+
+use soroban_sdk::xdr::InvokeContractArgs;
+use soroban_sdk::{Address, Env, IntoVal};
+
+use crate::tx::{invocation, no_args};
+use crate::{Error, RosterEntry};
+
+pub fn init(env: &Env, contract: &Address, employer: &Address, genesis: u64, period_seconds: u64) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "init", (employer.clone(), genesis, period_seconds).into_val(env))
+}
+
+pub fn deposit(env: &Env, contract: &Address, from: &Address, asset: &Address, amount: i128) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "deposit", (from.clone(), asset.clone(), amount).into_val(env))
+}
+
+pub fn withdraw(env: &Env, contract: &Address, asset: &Address, to: &Address, amount: i128) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "withdraw", (asset.clone(), to.clone(), amount).into_val(env))
+}
+
+pub fn set_employee(env: &Env, contract: &Address, entry: &RosterEntry) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "set_employee", (entry.clone(),).into_val(env))
+}
+
+pub fn remove_employee(env: &Env, contract: &Address, employee: &Address) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "remove_employee", (employee.clone(),).into_val(env))
+}
+
+/// Pays the next page of the roster. Returns `true`, as `bool`, once the run is complete.
+pub fn run_payroll(env: &Env, contract: &Address, period: u32) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "run_payroll", (period,).into_val(env))
+}
+
+pub fn set_payslips(env: &Env, contract: &Address, payslips: Option<&Address>) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "set_payslips", (payslips.cloned(),).into_val(env))
+}
+
+/// Returns `Option<u32>`.
+pub fn get_payslip(env: &Env, contract: &Address, period: u32, employee: &Address) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "get_payslip", (period, employee.clone()).into_val(env))
+}
+
+/// Returns `u32`.
+pub fn current_period(env: &Env, contract: &Address) -> Result<InvokeContractArgs, Error> {
+    no_args(env, contract, "current_period")
+}
+
+/// Returns `bool`.
+pub fn is_paid(env: &Env, contract: &Address, period: u32, employee: &Address) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "is_paid", (period, employee.clone()).into_val(env))
+}
+
+/// Returns `bool`.
+pub fn is_completed(env: &Env, contract: &Address, period: u32) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "is_completed", (period,).into_val(env))
+}
+
+/// Returns `Option<u32>`.
+pub fn get_open_run(env: &Env, contract: &Address) -> Result<InvokeContractArgs, Error> {
+    no_args(env, contract, "get_open_run")
+}
+
+/// Returns `Option<RosterEntry>`.
+pub fn get_employee(env: &Env, contract: &Address, employee: &Address) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "get_employee", (employee.clone(),).into_val(env))
+}
+
+/// Returns `Vec<Address>`.
+pub fn get_roster(env: &Env, contract: &Address, start: u32, limit: u32) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "get_roster", (start, limit).into_val(env))
+}
+
+/// Returns `u32`.
+pub fn get_roster_size(env: &Env, contract: &Address) -> Result<InvokeContractArgs, Error> {
+    no_args(env, contract, "get_roster_size")
+}
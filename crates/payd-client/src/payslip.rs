@@ -0,0 +1,65 @@
+//! Calls to `PayslipContract`.
+
+use std::ops::Range;
+
+use soroban_sdk::xdr::InvokeContractArgs;
+use soroban_sdk::{Address, Env, IntoVal};
+
+use crate::tx::invocation;
+use crate::{Error, Payslip};
+
+pub fn init(env: &Env, contract: &Address, admin: &Address) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "init", (admin.clone(),).into_val(env))
+}
+
+pub fn set_issuer(env: &Env, contract: &Address, issuer: &Address, approved: bool) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "set_issuer", (issuer.clone(), approved).into_val(env))
+}
+
+/// Returns the new payslip's id, as `u32`.
+pub fn mint(env: &Env, contract: &Address, to: &Address, payslip: &Payslip) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "mint", (to.clone(), payslip.clone()).into_val(env))
+}
+
+/// Returns `bool`.
+pub fn is_issuer(env: &Env, contract: &Address, issuer: &Address) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "is_issuer", (issuer.clone(),).into_val(env))
+}
+
+/// Returns `Option<Payslip>`.
+pub fn get_payslip(env: &Env, contract: &Address, id: u32) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "get_payslip", (id,).into_val(env))
+}
+
+/// Returns `u32`.
+pub fn payslip_count(env: &Env, contract: &Address, holder: &Address) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "payslip_count", (holder.clone(),).into_val(env))
+}
+
+/// `holder`'s payslips `start` to `start + limit` for pay periods within `periods`.
+/// Returns `Vec<Payslip>`.
+pub fn payslips(
+    env: &Env,
+    contract: &Address,
+    holder: &Address,
+    periods: Range<u64>,
+    start: u32,
+    limit: u32,
+) -> Result<InvokeContractArgs, Error> {
+    let args = (holder.clone(), periods.start, periods.end, start, limit);
+    invocation(env, contract, "payslips", args.into_val(env))
+}
+
+/// Net pay in `asset` on the same page of payslips as `payslips`. Returns `i128`.
+pub fn income(
+    env: &Env,
+    contract: &Address,
+    holder: &Address,
+    asset: &Address,
+    periods: Range<u64>,
+    start: u32,
+    limit: u32,
+) -> Result<InvokeContractArgs, Error> {
+    let args = (holder.clone(), asset.clone(), periods.start, periods.end, start, limit);
+    invocation(env, contract, "income", args.into_val(env))
+}
@@ -0,0 +1,62 @@
+//! Calls to `SalaryStreamContract`.
+
+use soroban_sdk::xdr::InvokeContractArgs;
+use soroban_sdk::{Address, Env, IntoVal};
+
+use crate::tx::{invocation, no_args};
+use crate::Error;
+
+pub fn init(env: &Env, contract: &Address, employer: &Address, token: &Address) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "init", (employer.clone(), token.clone()).into_val(env))
+}
+
+pub fn deposit(env: &Env, contract: &Address, from: &Address, amount: i128) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "deposit", (from.clone(), amount).into_val(env))
+}
+
+pub fn open_stream(env: &Env, contract: &Address, employee: &Address, rate_per_second: i128) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "open_stream", (employee.clone(), rate_per_second).into_val(env))
+}
+
+pub fn set_rate(env: &Env, contract: &Address, employee: &Address, rate_per_second: i128) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "set_rate", (employee.clone(), rate_per_second).into_val(env))
+}
+
+/// Returns the amount paid, as `i128`.
+pub fn withdraw(env: &Env, contract: &Address, employee: &Address) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "withdraw", (employee.clone(),).into_val(env))
+}
+
+/// Returns the amount paid out on cancellation, as `i128`.
+pub fn cancel_stream(env: &Env, contract: &Address, employee: &Address) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "cancel_stream", (employee.clone(),).into_val(env))
+}
+
+pub fn withdraw_treasury(env: &Env, contract: &Address, to: &Address, amount: i128) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "withdraw_treasury", (to.clone(), amount).into_val(env))
+}
+
+/// Returns `i128`.
+pub fn get_accrued(env: &Env, contract: &Address, employee: &Address) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "get_accrued", (employee.clone(),).into_val(env))
+}
+
+/// Returns `i128`.
+pub fn get_liability(env: &Env, contract: &Address) -> Result<InvokeContractArgs, Error> {
+    no_args(env, contract, "get_liability")
+}
+
+/// Returns `Option<Stream>`.
+pub fn get_stream(env: &Env, contract: &Address, employee: &Address) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "get_stream", (employee.clone(),).into_val(env))
+}
+
+/// Returns `Vec<Address>`.
+pub fn get_employees(env: &Env, contract: &Address, start: u32, limit: u32) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "get_employees", (start, limit).into_val(env))
+}
+
+/// Returns `u32`.
+pub fn get_employee_count(env: &Env, contract: &Address) -> Result<InvokeContractArgs, Error> {
+    no_args(env, contract, "get_employee_count")
+}
@@ -0,0 +1,33 @@
+//! Calls to `RevenueSplitContract`.
+
+use soroban_sdk::xdr::InvokeContractArgs;
+use soroban_sdk::{Address, BytesN, Env, IntoVal, Vec};
+
+use crate::tx::invocation;
+use crate::{Error, RecipientShare};
+
+pub fn init(env: &Env, contract: &Address, admin: &Address, shares: &[RecipientShare]) -> Result<InvokeContractArgs, Error> {
+    let shares = Vec::from_slice(env, shares);
+    invocation(env, contract, "init", (admin.clone(), shares).into_val(env))
+}
+
+pub fn set_admin(env: &Env, contract: &Address, new_admin: &Address) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "set_admin", (new_admin.clone(),).into_val(env))
+}
+
+pub fn set_council(env: &Env, contract: &Address, council: &Address) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "set_council", (council.clone(),).into_val(env))
+}
+
+pub fn update_recipients(env: &Env, contract: &Address, shares: &[RecipientShare]) -> Result<InvokeContractArgs, Error> {
+    let shares = Vec::from_slice(env, shares);
+    invocation(env, contract, "update_recipients", (shares,).into_val(env))
+}
+
+pub fn upgrade(env: &Env, contract: &Address, new_wasm_hash: &BytesN<32>) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "upgrade", (new_wasm_hash.clone(),).into_val(env))
+}
+
+pub fn distribute(env: &Env, contract: &Address, token: &Address, from: &Address, amount: i128) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "distribute", (token.clone(), from.clone(), amount).into_val(env))
+}
@@ -0,0 +1,41 @@
+//! Calls to `RevenueSplitFactory`.
+
+use soroban_sdk::xdr::InvokeContractArgs;
+use soroban_sdk::{Address, BytesN, Env, IntoVal, Vec};
+
+use crate::tx::{invocation, no_args};
+use crate::{Error, RecipientShare};
+
+pub fn init(env: &Env, contract: &Address, admin: &Address, wasm_hash: &BytesN<32>) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "init", (admin.clone(), wasm_hash.clone()).into_val(env))
+}
+
+pub fn set_wasm_hash(env: &Env, contract: &Address, new_wasm_hash: &BytesN<32>) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "set_wasm_hash", (new_wasm_hash.clone(),).into_val(env))
+}
+
+/// Returns the new split's `Address`.
+pub fn deploy_split(env: &Env, contract: &Address, owner: &Address, shares: &[RecipientShare]) -> Result<InvokeContractArgs, Error> {
+    let shares = Vec::from_slice(env, shares);
+    invocation(env, contract, "deploy_split", (owner.clone(), shares).into_val(env))
+}
+
+/// Returns the `Address` of the owner's `index`th split, deployed or not.
+pub fn split_address(env: &Env, contract: &Address, owner: &Address, index: u32) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "split_address", (owner.clone(), index).into_val(env))
+}
+
+/// Returns `Vec<Address>`.
+pub fn get_splits(env: &Env, contract: &Address, owner: &Address, start: u32, limit: u32) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "get_splits", (owner.clone(), start, limit).into_val(env))
+}
+
+/// Returns `u32`.
+pub fn get_split_count(env: &Env, contract: &Address, owner: &Address) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "get_split_count", (owner.clone(),).into_val(env))
+}
+
+/// Returns `BytesN<32>`.
+pub fn get_wasm_hash(env: &Env, contract: &Address) -> Result<InvokeContractArgs, Error> {
+    no_args(env, contract, "get_wasm_hash")
+}
@@ -0,0 +1,347 @@
+#![cfg(test)]
+
+use crate::events::{decode_event, decode_event_base64, ContractKind, Contracts, PaydEvent};
+use crate::xdr::{
+    ContractEvent, ContractEventBody, ContractEventType, ContractEventV0, ExtensionPoint, HostFunction, OperationBody,
+    ScAddress, ScVal, TransactionEnvelope,
+};
+use crate::*;
+use soroban_sdk::{vec, Address, Env, IntoVal, String, Symbol, TryFromVal, Val};
+
+// Recorded from the contracts running in the SDK test environment.
+const INITIATE_PAYMENT_ENVELOPE: &str = include_str!("../fixtures/initiate_payment_envelope.txt");
+const PAY_INIT_EVENT: &str = include_str!("../fixtures/pay_init_event.txt");
+const TOKEN_TRANSFER_EVENT: &str = include_str!("../fixtures/token_transfer_event.txt");
+const GET_PAYMENT_RETURN: &str = include_str!("../fixtures/get_payment_return.txt");
+
+const SOURCE: &str = "GABQGAYDAMBQGAYDAMBQGAYDAMBQGAYDAMBQGAYDAMBQGAYDAMBQHGPC";
+const CONTRACT: &str = "CAEQSCIJBEEQSCIJBEEQSCIJBEEQSCIJBEEQSCIJBEEQSCIJBEEQTD2L";
+const OTHER_CONTRACT: &str = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAMDR4";
+const THIRD_CONTRACT: &str = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAITA4";
+
+fn initiate_payment_envelope(builder: &TxBuilder) -> TransactionEnvelope {
+    let contract = builder.address(CONTRACT).unwrap();
+    let payment = payment::InitiatePayment {
+        from: builder.address(SOURCE).unwrap(),
+        amount: 250,
+        asset: contract.clone(),
+        receiver_id: "emp-42".into(),
+        target_asset: "NGN".into(),
+        anchor_id: "anchor-1".into(),
+    };
+    let call = payment::initiate_payment(builder.env(), &contract, &payment).unwrap();
+    builder.build(12345, call).unwrap()
+}
+
+#[test]
+fn test_initiate_payment_envelope_matches_fixture() {
+    let builder = TxBuilder::new(SOURCE, 100).unwrap();
+    let envelope = initiate_payment_envelope(&builder);
+
+    assert_eq!(envelope_to_base64(&envelope).unwrap(), INITIATE_PAYMENT_ENVELOPE.trim());
+    assert_eq!(envelope_from_base64(INITIATE_PAYMENT_ENVELOPE.trim()).unwrap(), envelope);
+}
+
+#[test]
+fn test_envelope_carries_one_unauthorized_invocation() {
+    let builder = TxBuilder::new(SOURCE, 100).unwrap();
+    let TransactionEnvelope::Tx(envelope) = initiate_payment_envelope(&builder) else {
+        panic!("expected a v1 envelope");
+    };
+
+    assert!(envelope.signatures.is_empty());
+    assert_eq!(envelope.tx.fee, 100);
+    assert_eq!(envelope.tx.operations.len(), 1);
+
+    let OperationBody::InvokeHostFunction(op) = &envelope.tx.operations[0].body else {
+        panic!("expected an invocation");
+    };
+    assert!(op.auth.is_empty());
+    let HostFunction::InvokeContract(call) = &op.host_function else {
+        panic!("expected a contract call");
+    };
+    assert_eq!(call.function_name.to_utf8_string().unwrap(), "initiate_payment");
+
+    // Arguments decode back to what was passed in
+    let env = builder.env();
+    let amount: i128 = decode_return(env, &call.args[1]).unwrap();
+    let receiver_id: String = decode_return(env, &call.args[3]).unwrap();
+    assert_eq!(amount, 250);
+    assert_eq!(receiver_id, String::from_str(env, "emp-42"));
+}
+
+#[test]
+fn test_vault_spend_nests_the_call() {
+    let builder = TxBuilder::new(SOURCE, 100).unwrap();
+    let env = builder.env();
+    let spender = builder.address(SOURCE).unwrap();
+    let [vault, split, token] = [THIRD_CONTRACT, CONTRACT, OTHER_CONTRACT]
+        .map(|strkey| builder.address(strkey).unwrap());
+
+    let distribute = split::distribute(env, &split, &token, &vault, 500).unwrap();
+    let spend = treasury_vault::spend(env, &vault, &spender, &token, 500, &distribute).unwrap();
+
+    assert_eq!(spend.function_name.to_utf8_string().unwrap(), "spend");
+    assert_eq!(decode_return::<Address>(env, &spend.args[1]).unwrap(), split);
+    assert_eq!(decode_return::<Symbol>(env, &spend.args[2]).unwrap(), Symbol::new(env, "distribute"));
+    assert_eq!(decode_return::<Address>(env, &spend.args[3]).unwrap(), token);
+    assert_eq!(decode_return::<i128>(env, &spend.args[4]).unwrap(), 500);
+    let args: soroban_sdk::Vec<Val> = decode_return(env, &spend.args[5]).unwrap();
+    assert_eq!(args.len(), 3);
+    assert_eq!(i128::try_from_val(env, &args.get(2).unwrap()).unwrap(), 500);
+}
+
+#[test]
+fn test_invalid_symbols_are_errors() {
+    let builder = TxBuilder::new(SOURCE, 100).unwrap();
+    let env = builder.env();
+    let [vault, asset] = [CONTRACT, OTHER_CONTRACT].map(|strkey| builder.address(strkey).unwrap());
+
+    assert!(treasury_vault::set_daily_limit(env, &vault, "payroll", &asset, 100).is_ok());
+    for role in ["pay roll", "payroll-team", &"x".repeat(33)] {
+        let result = treasury_vault::set_daily_limit(env, &vault, role, &asset, 100);
+        assert!(matches!(result, Err(Error::Conversion("symbol"))));
+    }
+    assert!(matches!(payment::update_status(env, &vault, 1, "dönë"), Err(Error::Conversion("symbol"))));
+}
+
+#[test]
+fn test_payslip_arguments() {
+    let builder = TxBuilder::new(SOURCE, 100).unwrap();
+    let env = builder.env();
+    let worker = builder.address(SOURCE).unwrap();
+    let [contract, asset] = [CONTRACT, OTHER_CONTRACT].map(|strkey| builder.address(strkey).unwrap());
+
+    let income = payslip::income(env, &contract, &worker, &asset, 100..200, 50, 25).unwrap();
+    assert_eq!(income.function_name.to_utf8_string().unwrap(), "income");
+    assert_eq!(decode_return::<Address>(env, &income.args[1]).unwrap(), asset);
+    assert_eq!(decode_return::<u64>(env, &income.args[3]).unwrap(), 200);
+    assert_eq!(decode_return::<u32>(env, &income.args[4]).unwrap(), 50);
+
+    let transfer = orgusd::transfer(env, &asset, &worker, &contract, 250).unwrap();
+    assert_eq!(decode_return::<Address>(env, &transfer.args[1]).unwrap(), contract);
+    assert_eq!(decode_return::<i128>(env, &transfer.args[2]).unwrap(), 250);
+}
+
+#[test]
+fn test_council_proposes_built_call() {
+    let builder = TxBuilder::new(SOURCE, 100).unwrap();
+    let env = builder.env();
+    let signer = builder.address(SOURCE).unwrap();
+    let [council, vault] = [OTHER_CONTRACT, CONTRACT].map(|strkey| builder.address(strkey).unwrap());
+
+    let withdraw = treasury_vault::withdraw(env, &vault, &signer, &vault, &signer, 10_000).unwrap();
+    let action = multisig::call_action(env, &withdraw).unwrap();
+    let Action::Call(target, function, args) = &action else {
+        panic!("expected a call");
+    };
+    assert_eq!(target, &vault);
+    assert_eq!(function, &Symbol::new(env, "withdraw"));
+    assert_eq!(args.len(), 4);
+
+    let propose = multisig::propose(env, &council, &signer, &action, 1_000).unwrap();
+    assert_eq!(propose.args.len(), 3);
+    assert!(decode_return::<Action>(env, &propose.args[1]).is_ok());
+}
+
+#[test]
+fn test_initialize_grant_arguments() {
+    let builder = TxBuilder::new(SOURCE, 100).unwrap();
+    let env = builder.env();
+    let account = builder.address(SOURCE).unwrap();
+    let grant = builder.address(CONTRACT).unwrap();
+    let initialize = vesting::Initialize {
+        funder: account.clone(),
+        beneficiary: account.clone(),
+        token: grant.clone(),
+        amount: 1_000,
+        clawback_admin: account,
+        terms: TrancheTerms {
+            start_time: 10,
+            cliff_seconds: 20,
+            duration_seconds: 30,
+            schedule: VestingSchedule::Periodic(5, 100),
+            cliff_mode: CliffMode::StartAtCliff,
+        },
+        start_bounds: DEFAULT_START_BOUNDS,
+    };
+
+    let call = vesting::initialize(env, &grant, &initialize).unwrap();
+    assert_eq!(call.args.len(), 7);
+    assert_eq!(decode_return::<i128>(env, &call.args[3]).unwrap(), 1_000);
+    assert_eq!(decode_return::<TrancheTerms>(env, &call.args[5]).unwrap(), initialize.terms);
+    assert_eq!(decode_return::<StartBounds>(env, &call.args[6]).unwrap(), DEFAULT_START_BOUNDS);
+}
+
+#[test]
+fn test_decode_get_payment_return() {
+    let env = Env::default();
+    let record: Option<PaymentRecord> = decode_return_base64(&env, GET_PAYMENT_RETURN.trim()).unwrap();
+    let record = record.unwrap();
+
+    assert_eq!(record.amount, 250);
+    assert_eq!(record.receiver_id, String::from_str(&env, "emp-42"));
+    assert_eq!(record.target_asset, String::from_str(&env, "NGN"));
+    assert_eq!(record.status, Symbol::new(&env, "pending"));
+
+    // The wrong type is an error rather than a panic
+    assert!(decode_return_base64::<u64>(&env, GET_PAYMENT_RETURN.trim()).is_err());
+}
+
+#[test]
+fn test_decode_pay_init_event() {
+    let env = Env::default();
+    let decoded = decode_event_base64(&env, ContractKind::Payment, PAY_INIT_EVENT.trim()).unwrap().unwrap();
+
+    assert!(decoded.contract.is_some());
+    assert_eq!(decoded.kind, ContractKind::Payment);
+    let PaydEvent::PaymentInitiated { payment_id, record } = decoded.event else {
+        panic!("expected a pay_init event");
+    };
+    assert_eq!(payment_id, 1);
+    assert_eq!(record.amount, 250);
+    assert_eq!(record.anchor_id, String::from_str(&env, "anchor-1"));
+}
+
+#[test]
+fn test_unknown_events_are_skipped() {
+    let env = Env::default();
+    assert!(decode_event_base64(&env, ContractKind::Payment, TOKEN_TRANSFER_EVENT.trim()).unwrap().is_none());
+
+    // Nothing is followed yet, so the payment event is someone else's too
+    let mut contracts = Contracts::new();
+    assert!(contracts.decode_base64(&env, PAY_INIT_EVENT.trim()).unwrap().is_none());
+}
+
+fn event(env: &Env, contract: Option<&Address>, topics: soroban_sdk::Vec<Val>, data: Val) -> ContractEvent {
+    let contract_id = contract.map(|contract| match ScAddress::from(contract) {
+        ScAddress::Contract(id) => id,
+        _ => panic!("expected a contract"),
+    });
+    let topics: std::vec::Vec<ScVal> = topics.iter().map(|topic| ScVal::try_from_val(env, &topic).unwrap()).collect();
+    ContractEvent {
+        ext: ExtensionPoint::V0,
+        contract_id,
+        type_: ContractEventType::Contract,
+        body: ContractEventBody::V0(ContractEventV0 {
+            topics: topics.try_into().unwrap(),
+            data: ScVal::try_from_val(env, &data).unwrap(),
+        }),
+    }
+}
+
+fn topics(env: &Env, name: &str, rest: &[Val]) -> soroban_sdk::Vec<Val> {
+    let mut topics = vec![env, Symbol::new(env, name).to_val()];
+    for topic in rest {
+        topics.push_back(*topic);
+    }
+    topics
+}
+
+#[test]
+fn test_decode_vesting_events() {
+    let builder = TxBuilder::new(SOURCE, 100).unwrap();
+    let env = builder.env();
+    let beneficiary = builder.address(SOURCE).unwrap();
+
+    let shortfall = event(env, None, topics(env, "shortfall", &[beneficiary.to_val()]), 40i128.into_val(env));
+    assert_eq!(
+        decode_event(env, ContractKind::Vesting, &shortfall).unwrap().unwrap().event,
+        PaydEvent::Shortfall { beneficiary: beneficiary.clone(), amount: 40 },
+    );
+
+    let keeper = event(env, None, topics(env, "keeper", &[beneficiary.to_val()]), Option::<Address>::None.into_val(env));
+    assert_eq!(
+        decode_event(env, ContractKind::Vesting, &keeper).unwrap().unwrap().event,
+        PaydEvent::KeeperSet { holder: beneficiary.clone(), keeper: None },
+    );
+
+    let clawback = event(env, None, topics(env, "clawback", &[beneficiary.to_val()]), (600i128, 550i128).into_val(env));
+    assert_eq!(
+        decode_event(env, ContractKind::Vesting, &clawback).unwrap().unwrap().event,
+        PaydEvent::ClawedBack { admin: beneficiary.clone(), unvested: 600, returned: 550 },
+    );
+
+    // A known event with the wrong payload is reported
+    let malformed = event(env, None, topics(env, "top_up", &[beneficiary.to_val()]), Symbol::new(env, "x").into_val(env));
+    assert!(decode_event(env, ContractKind::Vesting, &malformed).is_err());
+}
+
+#[test]
+fn test_topics_are_decoded_per_contract_kind() {
+    let builder = TxBuilder::new(SOURCE, 100).unwrap();
+    let env = builder.env();
+    let admin = builder.address(SOURCE).unwrap();
+    let holder = builder.address(CONTRACT).unwrap();
+
+    // The token's clawback names the holder as well as the admin
+    let clawback = event(env, None, topics(env, "clawback", &[admin.to_val(), holder.to_val()]), 70i128.into_val(env));
+    assert_eq!(
+        decode_event(env, ContractKind::OrgUsd, &clawback).unwrap().unwrap().event,
+        PaydEvent::TokenClawedBack { admin: admin.clone(), from: holder.clone(), amount: 70 },
+    );
+    assert!(decode_event(env, ContractKind::Vesting, &clawback).is_err());
+
+    // Salary streams report unpaid salary as `underpaid`, not as a vesting shortfall
+    let underpaid = event(env, None, topics(env, "underpaid", &[holder.to_val()]), 40i128.into_val(env));
+    assert_eq!(
+        decode_event(env, ContractKind::SalaryStream, &underpaid).unwrap().unwrap().event,
+        PaydEvent::StreamUnderpaid { employee: holder.clone(), amount: 40 },
+    );
+    assert!(decode_event(env, ContractKind::Vesting, &underpaid).unwrap().is_none());
+
+    let deposit = event(env, None, topics(env, "deposit", &[admin.to_val(), holder.to_val()]), 900i128.into_val(env));
+    assert_eq!(
+        decode_event(env, ContractKind::Payroll, &deposit).unwrap().unwrap().event,
+        PaydEvent::PayrollFunded { from: admin.clone(), asset: holder.clone(), amount: 900 },
+    );
+    assert_eq!(
+        decode_event(env, ContractKind::TreasuryVault, &deposit).unwrap().unwrap().event,
+        PaydEvent::VaultDeposited { from: admin.clone(), asset: holder.clone(), amount: 900 },
+    );
+
+    assert_eq!("salary_stream".parse::<ContractKind>().unwrap(), ContractKind::SalaryStream);
+    assert!(matches!("stream".parse::<ContractKind>(), Err(Error::UnknownContractKind(_))));
+}
+
+#[test]
+fn test_factory_grants_are_followed() {
+    let builder = TxBuilder::new(SOURCE, 100).unwrap();
+    let env = builder.env();
+    let funder = builder.address(SOURCE).unwrap();
+    let factory = builder.address(THIRD_CONTRACT).unwrap();
+    let grant = builder.address(CONTRACT).unwrap();
+
+    let mut contracts = Contracts::new();
+    contracts.insert(ScAddress::from(&factory), ContractKind::VestingFactory);
+
+    let granted = event(env, Some(&grant), topics(env, "granted", &[funder.to_val()]), (factory.clone(), 500i128).into_val(env));
+    assert!(contracts.decode(env, &granted).unwrap().is_none());
+
+    let created = event(
+        env,
+        Some(&factory),
+        topics(env, "grant_new", &[funder.to_val(), funder.to_val()]),
+        grant.to_val(),
+    );
+    let decoded = contracts.decode(env, &created).unwrap().unwrap();
+    assert_eq!(decoded.kind, ContractKind::VestingFactory);
+    assert_eq!(
+        decoded.event,
+        PaydEvent::GrantCreated { funder: funder.clone(), beneficiary: funder.clone(), grant: grant.clone() },
+    );
+
+    // The new grant is followed from here on
+    assert_eq!(contracts.kind(&ScAddress::from(&grant)), Some(ContractKind::Vesting));
+    let decoded = contracts.decode(env, &granted).unwrap().unwrap();
+    assert_eq!(decoded.contract, Some(grant));
+    assert_eq!(decoded.event, PaydEvent::Granted { beneficiary: funder, token: factory, amount: 500 });
+}
+
+#[test]
+fn test_rejects_contract_as_source() {
+    assert!(matches!(TxBuilder::new(CONTRACT, 100), Err(Error::InvalidSource(_))));
+
+    let builder = TxBuilder::new(SOURCE, 100).unwrap();
+    assert!(matches!(builder.address("not-a-strkey"), Err(Error::InvalidAddress(_))));
+}
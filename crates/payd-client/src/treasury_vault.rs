@@ -0,0 +1,119 @@
+//! Calls to `TreasuryVaultContract`.
+
+use soroban_sdk::xdr::InvokeContractArgs;
+use soroban_sdk::{Address, Env, IntoVal};
+
+use crate::tx::{invocation, nested_call, no_args, symbol};
+use crate::Error;
+
+pub fn init(env: &Env, contract: &Address, admin: &Address) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "init", (admin.clone(),).into_val(env))
+}
+
+pub fn set_admin(env: &Env, contract: &Address, new_admin: &Address) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "set_admin", (new_admin.clone(),).into_val(env))
+}
+
+pub fn set_council(env: &Env, contract: &Address, council: &Address) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "set_council", (council.clone(),).into_val(env))
+}
+
+pub fn set_threshold(env: &Env, contract: &Address, asset: &Address, threshold: i128) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "set_threshold", (asset.clone(), threshold).into_val(env))
+}
+
+pub fn deposit(env: &Env, contract: &Address, from: &Address, asset: &Address, amount: i128) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "deposit", (from.clone(), asset.clone(), amount).into_val(env))
+}
+
+pub fn set_role(env: &Env, contract: &Address, member: &Address, role: Option<&str>) -> Result<InvokeContractArgs, Error> {
+    let role = role.map(|role| symbol(env, role)).transpose()?;
+    invocation(env, contract, "set_role", (member.clone(), role).into_val(env))
+}
+
+pub fn set_daily_limit(env: &Env, contract: &Address, role: &str, asset: &Address, limit: i128) -> Result<InvokeContractArgs, Error> {
+    let role = symbol(env, role)?;
+    invocation(env, contract, "set_daily_limit", (role, asset.clone(), limit).into_val(env))
+}
+
+pub fn withdraw(
+    env: &Env,
+    contract: &Address,
+    member: &Address,
+    asset: &Address,
+    to: &Address,
+    amount: i128,
+) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "withdraw", (member.clone(), asset.clone(), to.clone(), amount).into_val(env))
+}
+
+pub fn approve(
+    env: &Env,
+    contract: &Address,
+    spender: &Address,
+    target: &Address,
+    function: &str,
+    asset: &Address,
+    amount: i128,
+) -> Result<InvokeContractArgs, Error> {
+    let function = symbol(env, function)?;
+    let args = (spender.clone(), target.clone(), function, asset.clone(), amount);
+    invocation(env, contract, "approve", args.into_val(env))
+}
+
+/// Has the vault make `call`, e.g. a `revenue_split` `distribute` or a vesting `top_up` built
+/// by this crate with the vault as the payer, which must pay exactly `amount` of `asset` out of
+/// the vault. Returns whatever `call` returns.
+pub fn spend(
+    env: &Env,
+    contract: &Address,
+    spender: &Address,
+    asset: &Address,
+    amount: i128,
+    call: &InvokeContractArgs,
+) -> Result<InvokeContractArgs, Error> {
+    let (target, function, args) = nested_call(env, call)?;
+    let args = (spender.clone(), target, function, asset.clone(), amount, args);
+    invocation(env, contract, "spend", args.into_val(env))
+}
+
+/// Returns `Option<Address>`.
+pub fn get_council(env: &Env, contract: &Address) -> Result<InvokeContractArgs, Error> {
+    no_args(env, contract, "get_council")
+}
+
+/// Returns `Option<i128>`.
+pub fn get_threshold(env: &Env, contract: &Address, asset: &Address) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "get_threshold", (asset.clone(),).into_val(env))
+}
+
+/// Returns `Option<Symbol>`.
+pub fn get_role(env: &Env, contract: &Address, member: &Address) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "get_role", (member.clone(),).into_val(env))
+}
+
+/// Returns `i128`.
+pub fn get_daily_limit(env: &Env, contract: &Address, role: &str, asset: &Address) -> Result<InvokeContractArgs, Error> {
+    let role = symbol(env, role)?;
+    invocation(env, contract, "get_daily_limit", (role, asset.clone()).into_val(env))
+}
+
+/// Returns `i128`.
+pub fn get_spent_today(env: &Env, contract: &Address, role: &str, asset: &Address) -> Result<InvokeContractArgs, Error> {
+    let role = symbol(env, role)?;
+    invocation(env, contract, "get_spent_today", (role, asset.clone()).into_val(env))
+}
+
+/// Returns `i128`.
+pub fn get_allowance(
+    env: &Env,
+    contract: &Address,
+    spender: &Address,
+    target: &Address,
+    function: &str,
+    asset: &Address,
+) -> Result<InvokeContractArgs, Error> {
+    let function = symbol(env, function)?;
+    let args = (spender.clone(), target.clone(), function, asset.clone());
+    invocation(env, contract, "get_allowance", args.into_val(env))
+}
@@ -0,0 +1,130 @@
+use std::str::FromStr;
+
+use soroban_sdk::xdr::{
+    HostFunction, InvokeContractArgs, InvokeHostFunctionOp, Limits, Memo, MuxedAccount, Operation, OperationBody,
+    Preconditions, ReadXdr, ScAddress, ScSymbol, ScVal, SequenceNumber, Transaction, TransactionEnvelope,
+    TransactionExt, TransactionV1Envelope, VecM, WriteXdr,
+};
+use soroban_sdk::{Address, Env, Symbol, TryFromVal, Val, Vec};
+
+use crate::Error;
+
+/// Builds unsigned invocation transactions from one source account.
+///
+/// Holds its own `Env`, which is only used to encode and decode contract values.
+pub struct TxBuilder {
+    env: Env,
+    source: MuxedAccount,
+    fee: u32,
+}
+
+impl TxBuilder {
+    /// `source` is the `G...` account that will sign and pay; `fee` is the base fee in stroops.
+    pub fn new(source: &str, fee: u32) -> Result<Self, Error> {
+        let source = match MuxedAccount::from_str(source) {
+            Ok(account @ MuxedAccount::Ed25519(_)) => account,
+            _ => return Err(Error::InvalidSource(source.to_string())),
+        };
+        Ok(TxBuilder { env: Env::default(), source, fee })
+    }
+
+    pub fn env(&self) -> &Env {
+        &self.env
+    }
+
+    /// Parses an account (`G...`) or contract (`C...`) strkey.
+    pub fn address(&self, strkey: &str) -> Result<Address, Error> {
+        let address = ScAddress::from_str(strkey).map_err(|_| Error::InvalidAddress(strkey.to_string()))?;
+        Address::try_from_val(&self.env, &ScVal::Address(address)).map_err(|_| Error::InvalidAddress(strkey.to_string()))
+    }
+
+    /// Wraps a single contract call in a transaction. `sequence` is the source account's
+    /// current sequence number plus one.
+    pub fn build(&self, sequence: i64, call: InvokeContractArgs) -> Result<TransactionEnvelope, Error> {
+        let operation = Operation {
+            source_account: None,
+            body: OperationBody::InvokeHostFunction(InvokeHostFunctionOp {
+                host_function: HostFunction::InvokeContract(call),
+                auth: VecM::default(),
+            }),
+        };
+
+        let tx = Transaction {
+            source_account: self.source.clone(),
+            fee: self.fee,
+            seq_num: SequenceNumber(sequence),
+            cond: Preconditions::None,
+            memo: Memo::None,
+            operations: [operation].try_into()?,
+            ext: TransactionExt::V0,
+        };
+
+        Ok(TransactionEnvelope::Tx(TransactionV1Envelope { tx, signatures: VecM::default() }))
+    }
+}
+
+/// Encodes a call to `function` on `contract` with already-converted arguments.
+pub(crate) fn invocation(env: &Env, contract: &Address, function: &str, args: Vec<Val>) -> Result<InvokeContractArgs, Error> {
+    let contract_address = ScAddress::from(contract);
+    let function_name = ScSymbol(function.try_into()?);
+
+    let mut encoded = std::vec::Vec::with_capacity(args.len() as usize);
+    for arg in args.iter() {
+        encoded.push(ScVal::try_from_val(env, &arg).map_err(|_| Error::Conversion("argument"))?);
+    }
+
+    Ok(InvokeContractArgs {
+        contract_address,
+        function_name,
+        args: encoded.try_into()?,
+    })
+}
+
+pub(crate) fn no_args(env: &Env, contract: &Address, function: &str) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, function, Vec::new(env))
+}
+
+/// Converts a role, status or function name into a `Symbol`, which only allows `[a-zA-Z0-9_]`
+/// and at most 32 characters.
+pub(crate) fn symbol(env: &Env, name: &str) -> Result<Symbol, Error> {
+    // Checked here first because the test `Env` panics on a host error instead of returning it
+    let valid = name.len() <= 32 && name.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_');
+    if !valid {
+        return Err(Error::Conversion("symbol"));
+    }
+    Symbol::try_from_val(env, &name).map_err(|_| Error::Conversion("symbol"))
+}
+
+/// Decodes an encoded call back into its contract, function and arguments, for contracts
+/// that make the call themselves, e.g. a multisig proposal or a vault spend.
+pub(crate) fn nested_call(env: &Env, call: &InvokeContractArgs) -> Result<(Address, Symbol, Vec<Val>), Error> {
+    let contract = Address::try_from_val(env, &ScVal::Address(call.contract_address.clone()))
+        .map_err(|_| Error::Conversion("nested call"))?;
+    let function = Symbol::try_from_val(env, &ScVal::Symbol(call.function_name.clone()))
+        .map_err(|_| Error::Conversion("nested call"))?;
+
+    let mut args = Vec::new(env);
+    for arg in call.args.iter() {
+        args.push_back(Val::try_from_val(env, arg).map_err(|_| Error::Conversion("nested call"))?);
+    }
+    Ok((contract, function, args))
+}
+
+pub fn envelope_to_base64(envelope: &TransactionEnvelope) -> Result<String, Error> {
+    Ok(envelope.to_xdr_base64(Limits::none())?)
+}
+
+pub fn envelope_from_base64(xdr: &str) -> Result<TransactionEnvelope, Error> {
+    Ok(TransactionEnvelope::from_xdr_base64(xdr, Limits::none())?)
+}
+
+/// Decodes a contract return value, e.g. the `results[].xdr` of a simulation.
+pub fn decode_return<T: TryFromVal<Env, Val>>(env: &Env, value: &ScVal) -> Result<T, Error> {
+    let value = Val::try_from_val(env, value).map_err(|_| Error::Conversion("return value"))?;
+    T::try_from_val(env, &value).map_err(|_| Error::Conversion("return value"))
+}
+
+pub fn decode_return_base64<T: TryFromVal<Env, Val>>(env: &Env, xdr: &str) -> Result<T, Error> {
+    let value = ScVal::from_xdr_base64(xdr, Limits::none())?;
+    decode_return(env, &value)
+}
@@ -0,0 +1,143 @@
+//! Calls to `VestingContract`. The grant address is the `contract` argument.
+
+use soroban_sdk::xdr::InvokeContractArgs;
+use soroban_sdk::{Address, Env, IntoVal};
+
+use crate::tx::{invocation, no_args, symbol};
+use crate::{Error, StartBounds, TrancheTerms};
+
+/// Arguments of `initialize`.
+#[derive(Clone, Debug)]
+pub struct Initialize {
+    pub funder: Address,
+    pub beneficiary: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub clawback_admin: Address,
+    pub terms: TrancheTerms,
+    pub start_bounds: StartBounds,
+}
+
+pub fn initialize(env: &Env, contract: &Address, grant: &Initialize) -> Result<InvokeContractArgs, Error> {
+    let args = (
+        grant.funder.clone(),
+        grant.beneficiary.clone(),
+        grant.token.clone(),
+        grant.amount,
+        grant.clawback_admin.clone(),
+        grant.terms.clone(),
+        grant.start_bounds,
+    );
+    invocation(env, contract, "initialize", args.into_val(env))
+}
+
+pub fn claim(env: &Env, contract: &Address) -> Result<InvokeContractArgs, Error> {
+    no_args(env, contract, "claim")
+}
+
+pub fn claim_to(env: &Env, contract: &Address, destination: &Address) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "claim_to", (destination.clone(),).into_val(env))
+}
+
+pub fn claim_for(env: &Env, contract: &Address) -> Result<InvokeContractArgs, Error> {
+    no_args(env, contract, "claim_for")
+}
+
+/// Returns the amount routed, as `i128`.
+pub fn claim_to_contract(env: &Env, contract: &Address, target: &Address, function: &str) -> Result<InvokeContractArgs, Error> {
+    let function = symbol(env, function)?;
+    invocation(env, contract, "claim_to_contract", (target.clone(), function).into_val(env))
+}
+
+pub fn set_keeper(env: &Env, contract: &Address, keeper: Option<&Address>) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "set_keeper", (keeper.cloned(),).into_val(env))
+}
+
+pub fn transfer_claim_right(env: &Env, contract: &Address, new_holder: &Address) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "transfer_claim_right", (new_holder.clone(),).into_val(env))
+}
+
+pub fn change_beneficiary(env: &Env, contract: &Address, new_beneficiary: &Address) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "change_beneficiary", (new_beneficiary.clone(),).into_val(env))
+}
+
+pub fn top_up(env: &Env, contract: &Address, funder: &Address, amount: i128, terms: Option<TrancheTerms>) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "top_up", (funder.clone(), amount, terms).into_val(env))
+}
+
+pub fn set_council(env: &Env, contract: &Address, council: &Address) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "set_council", (council.clone(),).into_val(env))
+}
+
+pub fn set_threshold(env: &Env, contract: &Address, threshold: i128) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "set_threshold", (threshold,).into_val(env))
+}
+
+pub fn clawback(env: &Env, contract: &Address) -> Result<InvokeContractArgs, Error> {
+    no_args(env, contract, "clawback")
+}
+
+pub fn clawback_partial(env: &Env, contract: &Address, amount: i128) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "clawback_partial", (amount,).into_val(env))
+}
+
+pub fn attest_milestone(env: &Env, contract: &Address, id: u32) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "attest_milestone", (id,).into_val(env))
+}
+
+/// Returns the amount returned to the clawback admin, as `i128`.
+pub fn clawback_expired_milestones(env: &Env, contract: &Address) -> Result<InvokeContractArgs, Error> {
+    no_args(env, contract, "clawback_expired_milestones")
+}
+
+pub fn schedule_termination(env: &Env, contract: &Address, termination_time: u64) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "schedule_termination", (termination_time,).into_val(env))
+}
+
+pub fn pause_vesting(env: &Env, contract: &Address) -> Result<InvokeContractArgs, Error> {
+    no_args(env, contract, "pause_vesting")
+}
+
+pub fn resume_vesting(env: &Env, contract: &Address) -> Result<InvokeContractArgs, Error> {
+    no_args(env, contract, "resume_vesting")
+}
+
+/// Returns `Reconciliation`.
+pub fn reconcile(env: &Env, contract: &Address) -> Result<InvokeContractArgs, Error> {
+    no_args(env, contract, "reconcile")
+}
+
+/// Returns the amount swept, as `i128`.
+pub fn sweep_excess(env: &Env, contract: &Address, to: &Address) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "sweep_excess", (to.clone(),).into_val(env))
+}
+
+/// Returns `Address`.
+pub fn get_claim_holder(env: &Env, contract: &Address) -> Result<InvokeContractArgs, Error> {
+    no_args(env, contract, "get_claim_holder")
+}
+
+/// Returns `Option<Address>`.
+pub fn get_keeper(env: &Env, contract: &Address) -> Result<InvokeContractArgs, Error> {
+    no_args(env, contract, "get_keeper")
+}
+
+/// Returns `u64`.
+pub fn get_end_time(env: &Env, contract: &Address) -> Result<InvokeContractArgs, Error> {
+    no_args(env, contract, "get_end_time")
+}
+
+/// Returns `i128`.
+pub fn get_vested_amount(env: &Env, contract: &Address) -> Result<InvokeContractArgs, Error> {
+    no_args(env, contract, "get_vested_amount")
+}
+
+/// Returns `i128`.
+pub fn get_claimable_amount(env: &Env, contract: &Address) -> Result<InvokeContractArgs, Error> {
+    no_args(env, contract, "get_claimable_amount")
+}
+
+/// Returns `VestingConfig`.
+pub fn get_config(env: &Env, contract: &Address) -> Result<InvokeContractArgs, Error> {
+    no_args(env, contract, "get_config")
+}
@@ -0,0 +1,61 @@
+//! Calls to `VestingFactory`.
+//!
+//! The factory has its own copies of the vesting types, encoded exactly like the
+//! escrow's; they are re-exported here for building `GrantTerms`.
+
+use soroban_sdk::xdr::InvokeContractArgs;
+use soroban_sdk::{Address, BytesN, Env, IntoVal};
+
+use crate::tx::{invocation, no_args};
+use crate::Error;
+
+pub use ::vesting_factory::{AttestedMilestone, CliffMode, GrantTerms, MilestoneStatus, StartBounds, VestingSchedule};
+
+pub fn init(env: &Env, contract: &Address, admin: &Address, wasm_hash: &BytesN<32>) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "init", (admin.clone(), wasm_hash.clone()).into_val(env))
+}
+
+pub fn set_wasm_hash(env: &Env, contract: &Address, new_wasm_hash: &BytesN<32>) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "set_wasm_hash", (new_wasm_hash.clone(),).into_val(env))
+}
+
+/// Returns the new grant's `Address`.
+pub fn create_grant(env: &Env, contract: &Address, funder: &Address, terms: &GrantTerms) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "create_grant", (funder.clone(), terms.clone()).into_val(env))
+}
+
+/// Returns the `Address` of the funder's `index`th grant, created or not.
+pub fn grant_address(env: &Env, contract: &Address, funder: &Address, index: u32) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "grant_address", (funder.clone(), index).into_val(env))
+}
+
+/// Returns `Vec<Address>`.
+pub fn get_grants_by_funder(env: &Env, contract: &Address, funder: &Address, start: u32, limit: u32) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "get_grants_by_funder", (funder.clone(), start, limit).into_val(env))
+}
+
+/// Returns `u32`.
+pub fn get_funder_grant_count(env: &Env, contract: &Address, funder: &Address) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "get_funder_grant_count", (funder.clone(),).into_val(env))
+}
+
+/// Returns `Vec<Address>`.
+pub fn get_grants_by_beneficiary(
+    env: &Env,
+    contract: &Address,
+    beneficiary: &Address,
+    start: u32,
+    limit: u32,
+) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "get_grants_by_beneficiary", (beneficiary.clone(), start, limit).into_val(env))
+}
+
+/// Returns `u32`.
+pub fn get_beneficiary_grant_count(env: &Env, contract: &Address, beneficiary: &Address) -> Result<InvokeContractArgs, Error> {
+    invocation(env, contract, "get_beneficiary_grant_count", (beneficiary.clone(),).into_val(env))
+}
+
+/// Returns `BytesN<32>`.
+pub fn get_wasm_hash(env: &Env, contract: &Address) -> Result<InvokeContractArgs, Error> {
+    no_args(env, contract, "get_wasm_hash")
+}
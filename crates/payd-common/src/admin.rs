@@ -0,0 +1,59 @@
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Symbol};
+
+use crate::{storage, CommonError};
+
+/// Storage keys used by the admin and role helpers. A unit variant is stored as its
+/// name, so `CommonKey::Admin` reads the same entry as a contract's own `DataKey::Admin`.
+#[contracttype(export = false)]
+pub enum CommonKey {
+    Admin,
+    Role(Address),
+}
+
+/// Stores the first admin, panicking with `AlreadyInitialized` if one is set.
+pub fn init_admin(e: &Env, admin: &Address) {
+    storage::guard_init(e, &CommonKey::Admin);
+    e.storage().instance().set(&CommonKey::Admin, admin);
+}
+
+pub fn is_initialized(e: &Env) -> bool {
+    e.storage().instance().has(&CommonKey::Admin)
+}
+
+pub fn get_admin(e: &Env) -> Address {
+    storage::read_instance(e, &CommonKey::Admin)
+}
+
+/// Requires the admin's authorization and returns the admin.
+pub fn require_admin(e: &Env) -> Address {
+    let admin = get_admin(e);
+    admin.require_auth();
+    admin
+}
+
+/// Hands the admin role to `new_admin` (current admin only).
+pub fn set_admin(e: &Env, new_admin: &Address) {
+    require_admin(e);
+    e.storage().instance().set(&CommonKey::Admin, new_admin);
+}
+
+/// Gives `account` a role, or takes it away with `None`. Callers check the admin first.
+pub fn set_role(e: &Env, account: &Address, role: Option<Symbol>) {
+    let key = CommonKey::Role(account.clone());
+    match role {
+        Some(role) => e.storage().persistent().set(&key, &role),
+        None => e.storage().persistent().remove(&key),
+    }
+}
+
+pub fn get_role(e: &Env, account: &Address) -> Option<Symbol> {
+    e.storage().persistent().get(&CommonKey::Role(account.clone()))
+}
+
+/// Requires `account`'s authorization and that it holds `role`, or panics with `Unauthorized`.
+pub fn require_role(e: &Env, account: &Address, role: &Symbol) {
+    account.require_auth();
+    if get_role(e, account).as_ref() != Some(role) {
+        panic_with_error!(e, CommonError::Unauthorized);
+    }
+}
@@ -0,0 +1,94 @@
+//! Hands movements of funds above a per-asset threshold to a council, normally a `multisig`
+//! contract, so that no single key can move large amounts on its own.
+//!
+//! Until a council is set nothing changes. Once one is set, only the council can replace
+//! itself or change a threshold, and an asset without a threshold needs the council for
+//! every amount.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::{admin, math, storage};
+
+pub const DAY_SECONDS: u64 = 24 * 60 * 60;
+
+#[contracttype(export = false)]
+pub enum CouncilKey {
+    Council,
+    Threshold(Address),
+    /// What left without the council on a day (ledger time divided by `DAY_SECONDS`).
+    MovedToday(Address, u64),
+}
+
+pub fn get_council(e: &Env) -> Option<Address> {
+    e.storage().instance().get(&CouncilKey::Council)
+}
+
+/// The largest amount of `asset` that can move without the council, if one was set.
+pub fn get_threshold(e: &Env, asset: &Address) -> Option<i128> {
+    e.storage().instance().get(&CouncilKey::Threshold(asset.clone()))
+}
+
+/// Sets the council. Needs the admin's authorization the first time and the current
+/// council's after that.
+pub fn set_council(e: &Env, council: &Address) {
+    require_owner(e);
+    e.storage().instance().set(&CouncilKey::Council, council);
+}
+
+/// Sets how much of `asset` can move without the council. Authorized like `set_council`.
+pub fn set_threshold(e: &Env, asset: &Address, threshold: i128) {
+    if threshold < 0 {
+        panic!("Threshold must not be negative");
+    }
+    require_owner(e);
+    e.storage().instance().set(&CouncilKey::Threshold(asset.clone()), &threshold);
+}
+
+/// Whether moving `amount` of `asset` needs the council.
+pub fn is_required(e: &Env, asset: &Address, amount: i128) -> bool {
+    get_council(e).is_some() && get_threshold(e, asset).is_none_or(|threshold| amount > threshold)
+}
+
+/// Requires the council's authorization if moving `amount` of `asset` needs it, and
+/// returns whether it did.
+pub fn require_for(e: &Env, asset: &Address, amount: i128) -> bool {
+    if !is_required(e, asset, amount) {
+        return false;
+    }
+    get_council(e).expect("No council").require_auth();
+    true
+}
+
+/// `require_for`, with the threshold capping everything moved without the council in a day
+/// rather than each call, so an amount cannot be split into calls just under the threshold.
+/// Amounts the council authorized do not count towards the day's total.
+pub fn require_for_today(e: &Env, asset: &Address, amount: i128) -> bool {
+    if get_council(e).is_none() {
+        return false;
+    }
+    let key = CouncilKey::MovedToday(asset.clone(), e.ledger().timestamp() / DAY_SECONDS);
+    let moved = math::add(e, moved_today(e, asset), amount);
+    if require_for(e, asset, moved) {
+        return true;
+    }
+    e.storage().temporary().set(&key, &moved);
+    e.storage().temporary().extend_ttl(&key, storage::DAY_IN_LEDGERS, 2 * storage::DAY_IN_LEDGERS);
+    false
+}
+
+/// How much of `asset` `require_for_today` has let through without the council today.
+pub fn moved_today(e: &Env, asset: &Address) -> i128 {
+    let key = CouncilKey::MovedToday(asset.clone(), e.ledger().timestamp() / DAY_SECONDS);
+    e.storage().temporary().get(&key).unwrap_or(0)
+}
+
+/// Requires the council's authorization once one is set, and the admin's before that.
+/// Guards settings that should not stay in a single key's hands once there is a council.
+pub fn require_owner(e: &Env) {
+    match get_council(e) {
+        Some(council) => council.require_auth(),
+        None => {
+            admin::require_admin(e);
+        }
+    }
+}
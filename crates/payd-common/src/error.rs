@@ -0,0 +1,14 @@
+use soroban_sdk::contracterror;
+
+/// Error codes raised by the shared helpers. Codes below 100 are reserved for this
+/// enum, so contracts defining their own `#[contracterror]` should start at 100.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum CommonError {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    Unauthorized = 3,
+    Overflow = 4,
+    DivisionByZero = 5,
+}
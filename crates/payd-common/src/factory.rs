@@ -0,0 +1,51 @@
+//! What the factory contracts share: an admin-controlled template (the WASM hash new
+//! contracts are deployed from) and salts derived from an owner and a running index, so the
+//! address of an owner's next deployment is known before it happens.
+
+use soroban_sdk::{contracttype, symbol_short, xdr::ToXdr, Address, Bytes, BytesN, Env};
+
+use crate::{admin, storage};
+
+#[contracttype(export = false)]
+pub enum FactoryKey {
+    WasmHash,
+}
+
+/// Stores the admin and the first template, panicking with `AlreadyInitialized` if the
+/// factory was set up before.
+pub fn init(e: &Env, admin: &Address, wasm_hash: &BytesN<32>) {
+    admin::init_admin(e, admin);
+    e.storage().instance().set(&FactoryKey::WasmHash, wasm_hash);
+}
+
+pub fn get_wasm_hash(e: &Env) -> BytesN<32> {
+    storage::read_instance(e, &FactoryKey::WasmHash)
+}
+
+/// Replaces the template used for future deployments (admin only). Contracts that were
+/// already deployed keep running their original code.
+pub fn set_wasm_hash(e: &Env, wasm_hash: &BytesN<32>) {
+    admin::require_admin(e);
+    e.storage().instance().set(&FactoryKey::WasmHash, wasm_hash);
+
+    e.events().publish((symbol_short!("tmpl_upd"),), wasm_hash.clone());
+}
+
+/// Deploys the template as `owner`'s `index`-th contract, without calling anything on it.
+pub fn deploy(e: &Env, owner: &Address, index: u32) -> Address {
+    e.deployer()
+        .with_current_contract(salt(e, owner, index))
+        .deploy_v2(get_wasm_hash(e), ())
+}
+
+/// The address `owner`'s `index`-th contract is (or will be) deployed at.
+pub fn address(e: &Env, owner: &Address, index: u32) -> Address {
+    e.deployer().with_current_contract(salt(e, owner, index)).deployed_address()
+}
+
+fn salt(e: &Env, owner: &Address, index: u32) -> BytesN<32> {
+    let mut preimage = Bytes::new(e);
+    preimage.append(&owner.clone().to_xdr(e));
+    preimage.extend_from_array(&index.to_be_bytes());
+    e.crypto().sha256(&preimage).to_bytes()
+}
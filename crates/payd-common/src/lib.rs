@@ -0,0 +1,17 @@
+//! Helpers shared by the PayD contracts: init guards, admin and role checks, council
+//! approval of large amounts, TTL extension, paged storage lists, checked `i128` math,
+//! factory templates and the error codes they panic with.
+#![no_std]
+
+pub mod admin;
+pub mod council;
+pub mod error;
+pub mod factory;
+pub mod list;
+pub mod math;
+pub mod storage;
+
+pub use error::CommonError;
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,110 @@
+//! Lists kept in persistent storage with one entry per item, so they can grow without any
+//! single entry approaching the ledger-entry size limit and adding an item costs the same
+//! however long the list is. Callers read them a page at a time.
+//!
+//! A list is identified by a storage key of the caller's (e.g. `DataKey::Grants(funder)`):
+//! its length is stored under that key and item `i` under `(key, i)`. Lists built with
+//! `insert` also map each item back to its position under `(key, "pos", item)`, which
+//! is what lets `remove` find it.
+
+use soroban_sdk::{symbol_short, Env, IntoVal, Symbol, TryFromVal, Val, Vec};
+
+use crate::storage;
+
+/// Most items `page` returns in one call.
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+const POSITION: Symbol = symbol_short!("pos");
+
+pub fn len<K: IntoVal<Env, Val>>(e: &Env, list: &K) -> u32 {
+    e.storage().persistent().get(list).unwrap_or(0)
+}
+
+pub fn get<K: IntoVal<Env, Val>, V: TryFromVal<Env, Val>>(e: &Env, list: &K, index: u32) -> Option<V> {
+    e.storage().persistent().get(&item_key(e, list, index))
+}
+
+/// Up to `limit` items (at most `MAX_PAGE_SIZE`) starting at `start`.
+pub fn page<K, V>(e: &Env, list: &K, start: u32, limit: u32) -> Vec<V>
+where
+    K: IntoVal<Env, Val>,
+    V: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    let end = len(e, list).min(start.saturating_add(limit.min(MAX_PAGE_SIZE)));
+
+    let mut items = Vec::new(e);
+    for index in start..end {
+        items.push_back(get(e, list, index).expect("List item missing"));
+    }
+    items
+}
+
+/// Appends `item` and returns its index.
+pub fn push<K: IntoVal<Env, Val>, V: IntoVal<Env, Val>>(e: &Env, list: &K, item: &V) -> u32 {
+    let index = len(e, list);
+    set_item(e, list, index, item);
+    set_len(e, list, index + 1);
+    index
+}
+
+/// Appends `item` if it is not already in the list, remembering its position for `remove`.
+/// Returns whether it was added.
+pub fn insert<K: IntoVal<Env, Val>, V: IntoVal<Env, Val>>(e: &Env, list: &K, item: &V) -> bool {
+    if contains(e, list, item) {
+        return false;
+    }
+
+    let index = push(e, list, item);
+    set_position(e, list, item, index);
+    true
+}
+
+/// Whether `item` was added with `insert` and not removed since.
+pub fn contains<K: IntoVal<Env, Val>, V: IntoVal<Env, Val>>(e: &Env, list: &K, item: &V) -> bool {
+    e.storage().persistent().has(&position_key(e, list, item))
+}
+
+/// Removes an item added with `insert` by moving the last item into its place, so the
+/// order of the remaining items changes. Returns whether it was in the list.
+pub fn remove<K: IntoVal<Env, Val>, V: IntoVal<Env, Val>>(e: &Env, list: &K, item: &V) -> bool {
+    let key = position_key(e, list, item);
+    let Some(index) = e.storage().persistent().get::<_, u32>(&key) else {
+        return false;
+    };
+    e.storage().persistent().remove(&key);
+
+    let last = len(e, list) - 1;
+    if index != last {
+        let moved: Val = get(e, list, last).expect("List item missing");
+        set_item(e, list, index, &moved);
+        set_position(e, list, &moved, index);
+    }
+    e.storage().persistent().remove(&item_key(e, list, last));
+    set_len(e, list, last);
+    true
+}
+
+fn item_key<K: IntoVal<Env, Val>>(e: &Env, list: &K, index: u32) -> (Val, u32) {
+    (list.into_val(e), index)
+}
+
+fn position_key<K: IntoVal<Env, Val>, V: IntoVal<Env, Val>>(e: &Env, list: &K, item: &V) -> (Val, Symbol, Val) {
+    (list.into_val(e), POSITION, item.into_val(e))
+}
+
+fn set_len<K: IntoVal<Env, Val>>(e: &Env, list: &K, len: u32) {
+    e.storage().persistent().set(list, &len);
+    storage::extend_persistent(e, list);
+}
+
+fn set_item<K: IntoVal<Env, Val>, V: IntoVal<Env, Val>>(e: &Env, list: &K, index: u32, item: &V) {
+    let key = item_key(e, list, index);
+    e.storage().persistent().set(&key, item);
+    storage::extend_persistent(e, &key);
+}
+
+fn set_position<K: IntoVal<Env, Val>, V: IntoVal<Env, Val>>(e: &Env, list: &K, item: &V, index: u32) {
+    let key = position_key(e, list, item);
+    e.storage().persistent().set(&key, &index);
+    storage::extend_persistent(e, &key);
+}
@@ -0,0 +1,35 @@
+use soroban_sdk::{panic_with_error, Env};
+
+use crate::CommonError;
+
+pub const TOTAL_BASIS_POINTS: u32 = 10000; // 100%
+
+pub fn add(e: &Env, a: i128, b: i128) -> i128 {
+    a.checked_add(b).unwrap_or_else(|| panic_with_error!(e, CommonError::Overflow))
+}
+
+pub fn sub(e: &Env, a: i128, b: i128) -> i128 {
+    a.checked_sub(b).unwrap_or_else(|| panic_with_error!(e, CommonError::Overflow))
+}
+
+pub fn mul(e: &Env, a: i128, b: i128) -> i128 {
+    a.checked_mul(b).unwrap_or_else(|| panic_with_error!(e, CommonError::Overflow))
+}
+
+/// `a * b / divisor`, rounded toward zero.
+pub fn mul_div(e: &Env, a: i128, b: i128, divisor: i128) -> i128 {
+    if divisor == 0 {
+        panic_with_error!(e, CommonError::DivisionByZero);
+    }
+    mul(e, a, b) / divisor
+}
+
+/// Adds two basis point counts, e.g. while summing a share table.
+pub fn add_bps(e: &Env, a: u32, b: u32) -> u32 {
+    a.checked_add(b).unwrap_or_else(|| panic_with_error!(e, CommonError::Overflow))
+}
+
+/// The `basis_points` share of `amount`, rounded down.
+pub fn bps(e: &Env, amount: i128, basis_points: u32) -> i128 {
+    mul_div(e, amount, basis_points as i128, TOTAL_BASIS_POINTS as i128)
+}
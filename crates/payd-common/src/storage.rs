@@ -0,0 +1,43 @@
+use soroban_sdk::{panic_with_error, Env, IntoVal, TryFromVal, Val};
+
+use crate::CommonError;
+
+/// Ledgers per day at a 5 second close time.
+pub const DAY_IN_LEDGERS: u32 = 17280;
+
+pub const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+pub const PERSISTENT_BUMP_AMOUNT: u32 = 90 * DAY_IN_LEDGERS;
+pub const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+/// Panics with `AlreadyInitialized` if `key` is already in instance storage.
+pub fn guard_init<K: IntoVal<Env, Val>>(e: &Env, key: &K) {
+    if e.storage().instance().has(key) {
+        panic_with_error!(e, CommonError::AlreadyInitialized);
+    }
+}
+
+/// Reads `key` from instance storage, panicking with `NotInitialized` if it is missing.
+pub fn read_instance<K: IntoVal<Env, Val>, V: TryFromVal<Env, Val>>(e: &Env, key: &K) -> V {
+    e.storage()
+        .instance()
+        .get(key)
+        .unwrap_or_else(|| panic_with_error!(e, CommonError::NotInitialized))
+}
+
+/// Keeps the contract instance (and everything in instance storage) alive for another month.
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+/// Keeps a persistent entry alive for another three months, if it exists.
+pub fn extend_persistent<K: IntoVal<Env, Val>>(e: &Env, key: &K) {
+    if e.storage().persistent().has(key) {
+        e.storage()
+            .persistent()
+            .extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+}
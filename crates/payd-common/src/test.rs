@@ -0,0 +1,273 @@
+#![cfg(test)]
+
+use crate::{admin, council, factory, list, math, storage, CommonError};
+use soroban_sdk::{contract, symbol_short, testutils::{Address as _, BytesN as _, Ledger, storage::Instance as _}, vec, Address, BytesN, Env, Symbol, Vec};
+
+#[contract]
+struct Host;
+
+fn setup(e: &Env) -> Address {
+    e.mock_all_auths();
+    e.register(Host, ())
+}
+
+#[test]
+fn test_admin_lifecycle() {
+    let e = Env::default();
+    let host = setup(&e);
+    let admin = Address::generate(&e);
+    let new_admin = Address::generate(&e);
+
+    e.as_contract(&host, || {
+        assert!(!admin::is_initialized(&e));
+        admin::init_admin(&e, &admin);
+        assert_eq!(admin::require_admin(&e), admin);
+    });
+
+    e.as_contract(&host, || {
+        admin::set_admin(&e, &new_admin);
+        assert_eq!(admin::get_admin(&e), new_admin);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn test_init_admin_twice() {
+    let e = Env::default();
+    let host = setup(&e);
+
+    e.as_contract(&host, || {
+        admin::init_admin(&e, &Address::generate(&e));
+        admin::init_admin(&e, &Address::generate(&e));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_get_admin_before_init() {
+    let e = Env::default();
+    let host = setup(&e);
+
+    e.as_contract(&host, || {
+        admin::get_admin(&e);
+    });
+}
+
+#[test]
+fn test_roles() {
+    let e = Env::default();
+    let host = setup(&e);
+    let account = Address::generate(&e);
+    let payroll = Symbol::new(&e, "payroll");
+
+    e.as_contract(&host, || {
+        admin::set_role(&e, &account, Some(payroll.clone()));
+        admin::require_role(&e, &account, &payroll);
+        assert_eq!(admin::get_role(&e, &account), Some(payroll));
+
+        admin::set_role(&e, &account, None);
+        assert_eq!(admin::get_role(&e, &account), None);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn test_require_missing_role() {
+    let e = Env::default();
+    let host = setup(&e);
+    let account = Address::generate(&e);
+
+    e.as_contract(&host, || {
+        admin::set_role(&e, &account, Some(Symbol::new(&e, "viewer")));
+        admin::require_role(&e, &account, &Symbol::new(&e, "payroll"));
+    });
+}
+
+#[test]
+fn test_council_thresholds() {
+    let e = Env::default();
+    let host = setup(&e);
+    let [usdc, eurc] = [Address::generate(&e), Address::generate(&e)];
+
+    e.as_contract(&host, || {
+        admin::init_admin(&e, &Address::generate(&e));
+
+        // Without a council nothing needs one
+        assert!(!council::is_required(&e, &usdc, i128::MAX));
+        council::set_threshold(&e, &usdc, 1_000);
+        assert!(!council::is_required(&e, &usdc, 1_001));
+    });
+
+    e.as_contract(&host, || {
+        council::set_council(&e, &Address::generate(&e));
+
+        assert!(!council::is_required(&e, &usdc, 1_000));
+        assert!(council::is_required(&e, &usdc, 1_001));
+        // An asset without a threshold needs the council for any amount
+        assert!(council::is_required(&e, &eurc, 1));
+    });
+}
+
+#[test]
+fn test_council_threshold_is_per_day() {
+    let e = Env::default();
+    let host = setup(&e);
+    let usdc = Address::generate(&e);
+
+    e.as_contract(&host, || {
+        admin::init_admin(&e, &Address::generate(&e));
+        council::set_threshold(&e, &usdc, 1_000);
+    });
+    e.as_contract(&host, || council::set_council(&e, &Address::generate(&e)));
+
+    e.as_contract(&host, || {
+        // Two calls under the threshold add up to it, and the next one needs the council
+        assert!(!council::require_for_today(&e, &usdc, 600));
+        assert!(!council::require_for_today(&e, &usdc, 400));
+        assert!(council::require_for_today(&e, &usdc, 1));
+        assert_eq!(council::moved_today(&e, &usdc), 1_000);
+    });
+
+    e.ledger().set_timestamp(council::DAY_SECONDS);
+    e.as_contract(&host, || {
+        assert_eq!(council::moved_today(&e, &usdc), 0);
+        assert!(!council::require_for_today(&e, &usdc, 1_000));
+    });
+}
+
+#[test]
+fn test_factory_template_and_addresses() {
+    let e = Env::default();
+    let host = setup(&e);
+    let [v1, v2] = [BytesN::<32>::random(&e), BytesN::<32>::random(&e)];
+    let [owner, other] = [Address::generate(&e), Address::generate(&e)];
+
+    e.as_contract(&host, || {
+        factory::init(&e, &Address::generate(&e), &v1);
+        assert_eq!(factory::get_wasm_hash(&e), v1);
+    });
+
+    e.as_contract(&host, || {
+        factory::set_wasm_hash(&e, &v2);
+        assert_eq!(factory::get_wasm_hash(&e), v2);
+
+        // Each owner's deployments get their own addresses, which don't depend on the template
+        let first = factory::address(&e, &owner, 0);
+        assert_eq!(factory::address(&e, &owner, 0), first);
+        assert_ne!(factory::address(&e, &owner, 1), first);
+        assert_ne!(factory::address(&e, &other, 0), first);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn test_factory_init_twice() {
+    let e = Env::default();
+    let host = setup(&e);
+
+    e.as_contract(&host, || {
+        factory::init(&e, &Address::generate(&e), &BytesN::<32>::random(&e));
+        factory::init(&e, &Address::generate(&e), &BytesN::<32>::random(&e));
+    });
+}
+
+#[test]
+fn test_extend_instance() {
+    let e = Env::default();
+    let host = setup(&e);
+
+    e.as_contract(&host, || {
+        storage::extend_instance(&e);
+        assert_eq!(e.storage().instance().get_ttl(), storage::INSTANCE_BUMP_AMOUNT);
+    });
+}
+
+#[test]
+fn test_list_push_and_page() {
+    let e = Env::default();
+    let host = setup(&e);
+    let key = symbol_short!("grants");
+
+    // One push per call, like the contracts using it
+    for i in 0..60u64 {
+        e.as_contract(&host, || assert_eq!(list::push(&e, &key, &(i * 10)), i as u32));
+    }
+
+    e.as_contract(&host, || {
+        assert_eq!(list::len(&e, &key), 60);
+        assert_eq!(list::get::<_, u64>(&e, &key, 59), Some(590));
+
+        let page: Vec<u64> = list::page(&e, &key, 58, 10);
+        assert_eq!(page, vec![&e, 580, 590]);
+        // Pages are capped however many items are asked for
+        let page: Vec<u64> = list::page(&e, &key, 0, 1000);
+        assert_eq!(page.len(), list::MAX_PAGE_SIZE);
+        let page: Vec<u64> = list::page(&e, &key, 100, 10);
+        assert!(page.is_empty());
+    });
+}
+
+#[test]
+fn test_list_insert_and_remove() {
+    let e = Env::default();
+    let host = setup(&e);
+    let key = symbol_short!("roster");
+    let [a, b, c] = [Address::generate(&e), Address::generate(&e), Address::generate(&e)];
+
+    e.as_contract(&host, || {
+        assert!(list::insert(&e, &key, &a));
+        assert!(list::insert(&e, &key, &b));
+        assert!(list::insert(&e, &key, &c));
+        assert!(!list::insert(&e, &key, &b));
+        assert_eq!(list::len(&e, &key), 3);
+
+        // The last item moves into the gap
+        assert!(list::remove(&e, &key, &a));
+        assert!(!list::contains(&e, &key, &a));
+        let page: Vec<Address> = list::page(&e, &key, 0, 10);
+        assert_eq!(page, vec![&e, c.clone(), b.clone()]);
+
+        assert!(list::remove(&e, &key, &b));
+        assert!(!list::remove(&e, &key, &b));
+        let page: Vec<Address> = list::page(&e, &key, 0, 10);
+        assert_eq!(page, vec![&e, c.clone()]);
+
+        assert!(list::remove(&e, &key, &c));
+        assert_eq!(list::len(&e, &key), 0);
+        assert!(list::insert(&e, &key, &a));
+        assert_eq!(list::get(&e, &key, 0), Some(a.clone()));
+    });
+}
+
+#[test]
+fn test_math() {
+    let e = Env::default();
+
+    assert_eq!(math::bps(&e, 1000, 2500), 250);
+    assert_eq!(math::bps(&e, 999, 3333), 332);
+    assert_eq!(math::mul_div(&e, 7, 3, 2), 10);
+    assert_eq!(math::sub(&e, 5, 7), -2);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4)")]
+fn test_mul_overflow() {
+    let e = Env::default();
+    math::mul(&e, i128::MAX, 2);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #5)")]
+fn test_mul_div_by_zero() {
+    let e = Env::default();
+    math::mul_div(&e, 1, 1, 0);
+}
+
+#[test]
+fn test_error_codes_are_stable() {
+    assert_eq!(CommonError::AlreadyInitialized as u32, 1);
+    assert_eq!(CommonError::NotInitialized as u32, 2);
+    assert_eq!(CommonError::Unauthorized as u32, 3);
+    assert_eq!(CommonError::Overflow as u32, 4);
+    assert_eq!(CommonError::DivisionByZero as u32, 5);
+}
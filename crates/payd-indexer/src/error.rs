@@ -0,0 +1,46 @@
+use core::fmt;
+
+#[derive(Debug)]
+pub enum Error {
+    Io(std::io::Error),
+    Sqlite(rusqlite::Error),
+    /// An input line (1-based) that is not a valid PayD event.
+    Decode(usize, payd_client::Error),
+    /// An input line (1-based) whose event position is not `<ledger> <tx_hash> <index>`.
+    BadEventId(usize),
+    /// An input line (1-based) without an event position, which a persistent projection
+    /// needs to recognise events it has already applied.
+    MissingEventId(usize),
+    /// An event without the emitting contract's address, which the projection is keyed by.
+    MissingContract,
+    /// A stored amount that is not a valid `i128`.
+    Corrupt(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "io error: {e}"),
+            Error::Sqlite(e) => write!(f, "sqlite error: {e}"),
+            Error::Decode(line, e) => write!(f, "line {line}: {e}"),
+            Error::BadEventId(line) => write!(f, "line {line}: expected `<ledger> <tx_hash> <index> <event>` or `<event>`"),
+            Error::MissingEventId(line) => write!(f, "line {line}: event has no `<ledger> <tx_hash> <index>` position"),
+            Error::MissingContract => write!(f, "event has no contract address"),
+            Error::Corrupt(value) => write!(f, "stored amount is not a number: {value}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<rusqlite::Error> for Error {
+    fn from(e: rusqlite::Error) -> Self {
+        Error::Sqlite(e)
+    }
+}
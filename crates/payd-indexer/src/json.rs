@@ -0,0 +1,265 @@
+//! JSON rendering of decoded events.
+//!
+//! Addresses are strkeys and `i128` amounts are decimal strings, since JSON numbers
+//! can't hold them exactly.
+
+use payd_client::events::{DecodedEvent, PaydEvent};
+use payd_client::xdr::ScAddress;
+use serde_json::{json, Map, Value};
+use soroban_sdk::{Address, BytesN};
+
+pub fn address(address: &Address) -> String {
+    ScAddress::from(address).to_string()
+}
+
+fn amount(amount: i128) -> Value {
+    Value::String(amount.to_string())
+}
+
+fn hash(hash: &BytesN<32>) -> Value {
+    Value::String(hash.to_array().iter().map(|byte| format!("{byte:02x}")).collect())
+}
+
+/// Renders an event as `{"contract": ..., "kind": ..., "event": ..., <fields>}`.
+pub fn to_json(decoded: &DecodedEvent) -> Value {
+    let (name, fields) = match &decoded.event {
+        PaydEvent::PaymentInitiated { payment_id, record } => ("payment_initiated", json!({
+            "payment_id": payment_id,
+            "from": address(&record.from),
+            "amount": amount(record.amount),
+            "asset": address(&record.asset),
+            "receiver_id": record.receiver_id.to_string(),
+            "target_asset": record.target_asset.to_string(),
+            "anchor_id": record.anchor_id.to_string(),
+            "status": record.status.to_string(),
+        })),
+        PaydEvent::PaymentStatusUpdated { payment_id, status } => {
+            ("payment_status_updated", json!({ "payment_id": payment_id, "status": status }))
+        }
+        PaydEvent::CouncilSet { council } => ("council_set", json!({ "council": address(council) })),
+        PaydEvent::ThresholdSet { asset, threshold } => {
+            ("threshold_set", json!({ "asset": address(asset), "threshold": amount(*threshold) }))
+        }
+        PaydEvent::SharesUpdated { shares } => {
+            let shares: Vec<Value> = shares
+                .iter()
+                .map(|share| json!({ "destination": address(&share.destination), "basis_points": share.basis_points }))
+                .collect();
+            ("shares_updated", json!({ "shares": shares }))
+        }
+        PaydEvent::Distributed { token, from, amount: total } => {
+            ("distributed", json!({ "token": address(token), "from": address(from), "amount": amount(*total) }))
+        }
+        PaydEvent::Upgraded { wasm_hash } => ("upgraded", json!({ "wasm_hash": hash(wasm_hash) })),
+        PaydEvent::TemplateUpdated { wasm_hash } => ("template_updated", json!({ "wasm_hash": hash(wasm_hash) })),
+        PaydEvent::SplitCreated { owner, split } => {
+            ("split_created", json!({ "owner": address(owner), "split": address(split) }))
+        }
+        PaydEvent::GrantCreated { funder, beneficiary, grant } => ("grant_created", json!({
+            "funder": address(funder),
+            "beneficiary": address(beneficiary),
+            "grant": address(grant),
+        })),
+        PaydEvent::Granted { beneficiary, token, amount: total } => ("granted", json!({
+            "beneficiary": address(beneficiary),
+            "token": address(token),
+            "amount": amount(*total),
+        })),
+        PaydEvent::Claimed { destination, amount: paid } => {
+            ("claimed", json!({ "destination": address(destination), "amount": amount(*paid) }))
+        }
+        PaydEvent::ToppedUp { funder, amount: added } => {
+            ("topped_up", json!({ "funder": address(funder), "amount": amount(*added) }))
+        }
+        PaydEvent::KeeperSet { holder, keeper } => {
+            ("keeper_set", json!({ "holder": address(holder), "keeper": keeper.as_ref().map(address) }))
+        }
+        PaydEvent::ClaimRightTransferred { from, to } => {
+            ("claim_right_transferred", json!({ "from": address(from), "to": address(to) }))
+        }
+        PaydEvent::BeneficiaryChanged { old, new } => {
+            ("beneficiary_changed", json!({ "old": address(old), "new": address(new) }))
+        }
+        PaydEvent::ClawedBack { admin, unvested, returned } => ("clawed_back", json!({
+            "admin": address(admin),
+            "unvested": amount(*unvested),
+            "returned": amount(*returned),
+        })),
+        PaydEvent::PartiallyClawedBack { admin, amount: returned } => {
+            ("partially_clawed_back", json!({ "admin": address(admin), "amount": amount(*returned) }))
+        }
+        PaydEvent::MilestoneAttested { attester, milestone_id } => {
+            ("milestone_attested", json!({ "attester": address(attester), "milestone_id": milestone_id }))
+        }
+        PaydEvent::MilestonesForfeited { admin, amount: returned } => {
+            ("milestones_forfeited", json!({ "admin": address(admin), "amount": amount(*returned) }))
+        }
+        PaydEvent::TerminationScheduled { beneficiary, termination_time } => (
+            "termination_scheduled",
+            json!({ "beneficiary": address(beneficiary), "termination_time": termination_time }),
+        ),
+        PaydEvent::Paused { beneficiary, at } => ("paused", json!({ "beneficiary": address(beneficiary), "at": at })),
+        PaydEvent::Resumed { beneficiary, paused_seconds } => {
+            ("resumed", json!({ "beneficiary": address(beneficiary), "paused_seconds": paused_seconds }))
+        }
+        PaydEvent::ExcessSwept { to, amount: swept } => {
+            ("excess_swept", json!({ "to": address(to), "amount": amount(*swept) }))
+        }
+        PaydEvent::Shortfall { beneficiary, amount: missing } => {
+            ("shortfall", json!({ "beneficiary": address(beneficiary), "amount": amount(*missing) }))
+        }
+
+        PaydEvent::StreamFunded { from, amount: funded } => {
+            ("stream_funded", json!({ "from": address(from), "amount": amount(*funded) }))
+        }
+        PaydEvent::StreamCreated { employee, rate_per_second } => (
+            "stream_created",
+            json!({ "employee": address(employee), "rate_per_second": amount(*rate_per_second) }),
+        ),
+        PaydEvent::StreamRateUpdated { employee, rate_per_second } => (
+            "stream_rate_updated",
+            json!({ "employee": address(employee), "rate_per_second": amount(*rate_per_second) }),
+        ),
+        PaydEvent::StreamCancelled { employee, accrued } => {
+            ("stream_cancelled", json!({ "employee": address(employee), "accrued": amount(*accrued) }))
+        }
+        PaydEvent::StreamWithdrawn { employee, amount: paid } => {
+            ("stream_withdrawn", json!({ "employee": address(employee), "amount": amount(*paid) }))
+        }
+        PaydEvent::StreamUnderpaid { employee, amount: missing } => {
+            ("stream_underpaid", json!({ "employee": address(employee), "amount": amount(*missing) }))
+        }
+        PaydEvent::TreasuryWithdrawn { to, amount: withdrawn } => {
+            ("treasury_withdrawn", json!({ "to": address(to), "amount": amount(*withdrawn) }))
+        }
+
+        PaydEvent::PayrollFunded { from, asset, amount: funded } => ("payroll_funded", json!({
+            "from": address(from),
+            "asset": address(asset),
+            "amount": amount(*funded),
+        })),
+        PaydEvent::PayrollWithdrawn { to, asset, amount: withdrawn } => ("payroll_withdrawn", json!({
+            "to": address(to),
+            "asset": address(asset),
+            "amount": amount(*withdrawn),
+        })),
+        PaydEvent::EmployeeSet { entry } => ("employee_set", json!({
+            "employee": address(&entry.employee),
+            "asset": address(&entry.asset),
+            "amount": amount(entry.amount),
+            "frequency": entry.frequency,
+            "start_period": entry.start_period,
+        })),
+        PaydEvent::EmployeeRemoved { employee } => ("employee_removed", json!({ "employee": address(employee) })),
+        PaydEvent::EmployeePaid { period, employee, amount: paid } => ("employee_paid", json!({
+            "period": period,
+            "employee": address(employee),
+            "amount": amount(*paid),
+        })),
+        PaydEvent::PayrollRunCompleted { period, roster_size } => {
+            ("payroll_run_completed", json!({ "period": period, "roster_size": roster_size }))
+        }
+        PaydEvent::PayslipIssued { period, employee, payslip_id } => ("payslip_issued", json!({
+            "period": period,
+            "employee": address(employee),
+            "payslip_id": payslip_id,
+        })),
+
+        PaydEvent::VaultDeposited { from, asset, amount: deposited } => ("vault_deposited", json!({
+            "from": address(from),
+            "asset": address(asset),
+            "amount": amount(*deposited),
+        })),
+        PaydEvent::RoleSet { member, role } => ("role_set", json!({ "member": address(member), "role": role })),
+        PaydEvent::DailyLimitSet { role, asset, limit } => ("daily_limit_set", json!({
+            "role": role,
+            "asset": address(asset),
+            "limit": amount(*limit),
+        })),
+        PaydEvent::VaultWithdrawn { member, asset, to, amount: withdrawn } => ("vault_withdrawn", json!({
+            "member": address(member),
+            "asset": address(asset),
+            "to": address(to),
+            "amount": amount(*withdrawn),
+        })),
+        PaydEvent::SpendApproved { spender, target, function, asset, amount: allowed } => ("spend_approved", json!({
+            "spender": address(spender),
+            "target": address(target),
+            "function": function,
+            "asset": address(asset),
+            "amount": amount(*allowed),
+        })),
+        PaydEvent::Spent { spender, target, asset, amount: spent } => ("spent", json!({
+            "spender": address(spender),
+            "target": address(target),
+            "asset": address(asset),
+            "amount": amount(*spent),
+        })),
+
+        PaydEvent::Proposed { proposal_id, proposer, call_hash } => ("proposed", json!({
+            "proposal_id": proposal_id,
+            "proposer": address(proposer),
+            "call_hash": hash(call_hash),
+        })),
+        PaydEvent::ProposalApproved { proposal_id, signer } => {
+            ("proposal_approved", json!({ "proposal_id": proposal_id, "signer": address(signer) }))
+        }
+        PaydEvent::ApprovalRevoked { proposal_id, signer } => {
+            ("approval_revoked", json!({ "proposal_id": proposal_id, "signer": address(signer) }))
+        }
+        PaydEvent::ProposalExecuted { proposal_id, call_hash } => {
+            ("proposal_executed", json!({ "proposal_id": proposal_id, "call_hash": hash(call_hash) }))
+        }
+        PaydEvent::SignersSet { signers, threshold } => {
+            let signers: Vec<String> = signers.iter().map(address).collect();
+            ("signers_set", json!({ "signers": signers, "threshold": threshold }))
+        }
+
+        PaydEvent::DistributionFunded { period, distribution } => ("distribution_funded", json!({
+            "period": period,
+            "asset": address(&distribution.asset),
+            "root": hash(&distribution.root),
+            "leaf_count": distribution.leaf_count,
+            "total": amount(distribution.total),
+            "expires_at": distribution.expires_at,
+        })),
+        PaydEvent::DistributionClaimed { period, employee, index, amount: paid } => ("distribution_claimed", json!({
+            "period": period,
+            "employee": address(employee),
+            "index": index,
+            "amount": amount(*paid),
+        })),
+        PaydEvent::DistributionReclaimed { period, amount: reclaimed } => {
+            ("distribution_reclaimed", json!({ "period": period, "amount": amount(*reclaimed) }))
+        }
+
+        PaydEvent::TokenClawedBack { admin, from, amount: taken } => ("token_clawed_back", json!({
+            "admin": address(admin),
+            "from": address(from),
+            "amount": amount(*taken),
+        })),
+        PaydEvent::FreezeSet { account, frozen } => {
+            ("freeze_set", json!({ "account": address(account), "frozen": frozen }))
+        }
+
+        PaydEvent::IssuerSet { issuer, approved } => {
+            ("issuer_set", json!({ "issuer": address(issuer), "approved": approved }))
+        }
+        PaydEvent::PayslipMinted { to, payer, payslip_id, asset, net } => ("payslip_minted", json!({
+            "to": address(to),
+            "payer": address(payer),
+            "payslip_id": payslip_id,
+            "asset": address(asset),
+            "net": amount(*net),
+        })),
+    };
+
+    let mut object = Map::new();
+    object.insert("contract".into(), decoded.contract.as_ref().map(address).into());
+    object.insert("kind".into(), decoded.kind.name().into());
+    object.insert("event".into(), name.into());
+    if let Value::Object(fields) = fields {
+        object.extend(fields);
+    }
+    Value::Object(object)
+}
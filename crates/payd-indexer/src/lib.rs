@@ -0,0 +1,88 @@
+//! Decodes PayD contract events and keeps a local SQLite projection of payments,
+//! revenue splits and vesting grants.
+//!
+//! Input is one base64 `ContractEvent` per line, as found in transaction meta or
+//! returned by `getEvents`, optionally prefixed by the event's `<ledger> <tx_hash> <index>`
+//! so the projection can recognise events it has already applied. Only events from the
+//! contracts being followed are decoded, each by its contract's kind; others (e.g. token
+//! transfers) are skipped. Splits and grants deployed by a followed factory are followed
+//! from their creation on.
+
+mod error;
+
+pub mod json;
+pub mod store;
+
+use std::io::BufRead;
+
+use payd_client::events::{Contracts, DecodedEvent};
+use soroban_sdk::Env;
+
+pub use error::Error;
+pub use store::Store;
+
+/// Where an event was emitted: the ledger it closed in, the hash of its transaction and its
+/// position among that transaction's events. Unique across the network.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventId {
+    pub ledger: u32,
+    pub tx_hash: String,
+    pub index: u32,
+}
+
+/// One decoded input line.
+#[derive(Clone, Debug)]
+pub struct InputEvent {
+    /// 1-based.
+    pub line: usize,
+    /// Present when the line carries the event's position.
+    pub id: Option<EventId>,
+    pub event: DecodedEvent,
+}
+
+/// Decodes `input` line by line, handing each event from a contract in `contracts` to `f` as
+/// soon as it is read, so `f` sees every event before the first malformed line. Blank lines
+/// are ignored.
+pub fn for_each_event(
+    env: &Env,
+    contracts: &mut Contracts,
+    input: impl BufRead,
+    mut f: impl FnMut(InputEvent) -> Result<(), Error>,
+) -> Result<(), Error> {
+    for (index, line) in input.lines().enumerate() {
+        let line = line?;
+        let number = index + 1;
+        let (id, xdr) = match line.split_whitespace().collect::<Vec<_>>()[..] {
+            [] => continue,
+            [xdr] => (None, xdr),
+            [ledger, tx_hash, index, xdr] => (Some(parse_id(ledger, tx_hash, index).ok_or(Error::BadEventId(number))?), xdr),
+            _ => return Err(Error::BadEventId(number)),
+        };
+        if let Some(event) = contracts.decode_base64(env, xdr).map_err(|e| Error::Decode(number, e))? {
+            f(InputEvent { line: number, id, event })?;
+        }
+    }
+    Ok(())
+}
+
+/// Decodes every event in `input` from a contract in `contracts`, in order.
+pub fn read_events(env: &Env, contracts: &mut Contracts, input: impl BufRead) -> Result<Vec<DecodedEvent>, Error> {
+    let mut events = Vec::new();
+    for_each_event(env, contracts, input, |input| {
+        events.push(input.event);
+        Ok(())
+    })?;
+    Ok(events)
+}
+
+fn parse_id(ledger: &str, tx_hash: &str, index: &str) -> Option<EventId> {
+    let is_hash = tx_hash.len() == 64 && tx_hash.bytes().all(|b| b.is_ascii_hexdigit());
+    is_hash.then_some(EventId {
+        ledger: ledger.parse().ok()?,
+        tx_hash: tx_hash.to_ascii_lowercase(),
+        index: index.parse().ok()?,
+    })
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,82 @@
+//! Usage: `payd-indexer [--db <path>] [--contract <kind>=<address>]... [<file>]`
+//!
+//! Reads base64 contract events, one per line, from `<file>` or stdin and prints each
+//! event from a followed contract as a line of JSON as soon as it is read. Each
+//! `--contract` follows one contract, e.g. `--contract vesting_factory=C...`; `<kind>` is
+//! one of the names in `ContractKind::ALL`. With `--db`, the events are also applied to the
+//! SQLite projection at `<path>`, which is created if it does not exist. Every line then
+//! needs its `<ledger> <tx_hash> <index>` prefix, and events already in the projection are
+//! skipped, so overlapping input can be fed again.
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::process::ExitCode;
+
+use payd_client::events::{ContractKind, Contracts};
+use payd_client::xdr::ScAddress;
+use payd_indexer::{for_each_event, json, Error, InputEvent, Store};
+use soroban_sdk::Env;
+
+const USAGE: &str = "usage: payd-indexer [--db <path>] [--contract <kind>=<address>]... [<file>]";
+
+fn main() -> ExitCode {
+    let mut db = None;
+    let mut file = None;
+    let mut contracts = Contracts::new();
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--db" => match args.next() {
+                Some(path) => db = Some(path),
+                None => return usage(),
+            },
+            "--contract" => match args.next().as_deref().and_then(parse_contract) {
+                Some((kind, contract)) => contracts.insert(contract, kind),
+                None => return usage(),
+            },
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return ExitCode::SUCCESS;
+            }
+            _ if file.is_none() && !arg.starts_with('-') => file = Some(arg),
+            _ => return usage(),
+        }
+    }
+
+    match run(db, contracts, file) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("payd-indexer: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn usage() -> ExitCode {
+    eprintln!("{USAGE}");
+    ExitCode::from(2)
+}
+
+/// Parses `<kind>=<address>`.
+fn parse_contract(arg: &str) -> Option<(ContractKind, ScAddress)> {
+    let (kind, contract) = arg.split_once('=')?;
+    Some((kind.parse().ok()?, contract.parse().ok()?))
+}
+
+fn run(db: Option<String>, mut contracts: Contracts, file: Option<String>) -> Result<(), Error> {
+    let env = Env::default();
+    let input: Box<dyn BufRead> = match file {
+        Some(path) => Box::new(BufReader::new(File::open(path)?)),
+        None => Box::new(io::stdin().lock()),
+    };
+
+    let mut store = db.map(Store::open).transpose()?;
+    for_each_event(&env, &mut contracts, input, |InputEvent { line, id, event }| {
+        println!("{}", json::to_json(&event));
+        if let Some(store) = &mut store {
+            store.apply_once(&id.ok_or(Error::MissingEventId(line))?, &event)?;
+        }
+        Ok(())
+    })
+}
@@ -0,0 +1,428 @@
+//! The SQLite projection.
+//!
+//! Events must be applied in ledger order. `apply_once` records each event's `EventId` and
+//! skips events it has seen, so re-reading overlapping input into the same database is
+//! harmless; `apply` keeps no record, and replaying an event through it counts its amounts
+//! twice. Amounts are stored as decimal text since SQLite integers are only 64 bits.
+
+use std::path::Path;
+
+use payd_client::events::{DecodedEvent, PaydEvent};
+use rusqlite::{params, Connection, OptionalExtension, Transaction};
+
+use crate::json::address;
+use crate::{Error, EventId};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS payments (
+    contract TEXT NOT NULL,
+    payment_id INTEGER NOT NULL,
+    sender TEXT NOT NULL,
+    amount TEXT NOT NULL,
+    asset TEXT NOT NULL,
+    receiver_id TEXT NOT NULL,
+    target_asset TEXT NOT NULL,
+    anchor_id TEXT NOT NULL,
+    status TEXT NOT NULL,
+    PRIMARY KEY (contract, payment_id)
+);
+CREATE TABLE IF NOT EXISTS splits (
+    contract TEXT PRIMARY KEY,
+    owner TEXT
+);
+CREATE TABLE IF NOT EXISTS split_shares (
+    contract TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    destination TEXT NOT NULL,
+    basis_points INTEGER NOT NULL,
+    PRIMARY KEY (contract, position)
+);
+CREATE TABLE IF NOT EXISTS split_distributions (
+    contract TEXT NOT NULL,
+    token TEXT NOT NULL,
+    total TEXT NOT NULL,
+    PRIMARY KEY (contract, token)
+);
+CREATE TABLE IF NOT EXISTS grants (
+    contract TEXT PRIMARY KEY,
+    beneficiary TEXT NOT NULL,
+    token TEXT NOT NULL,
+    total_amount TEXT NOT NULL,
+    claimed_amount TEXT NOT NULL,
+    clawed_back_amount TEXT NOT NULL,
+    status TEXT NOT NULL,
+    termination_time INTEGER,
+    claim_holder TEXT
+);
+CREATE TABLE IF NOT EXISTS applied_events (
+    ledger INTEGER NOT NULL,
+    tx_hash TEXT NOT NULL,
+    event_index INTEGER NOT NULL,
+    PRIMARY KEY (ledger, tx_hash, event_index)
+);
+";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Payment {
+    pub contract: String,
+    pub payment_id: u64,
+    pub from: String,
+    pub amount: i128,
+    pub asset: String,
+    pub receiver_id: String,
+    pub target_asset: String,
+    pub anchor_id: String,
+    pub status: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Split {
+    pub contract: String,
+    /// Known when the split was deployed through the factory.
+    pub owner: Option<String>,
+    /// `(destination, basis_points)`, in payout order.
+    pub shares: Vec<(String, u32)>,
+    /// `(token, total distributed)`.
+    pub distributed: Vec<(String, i128)>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Grant {
+    pub contract: String,
+    pub beneficiary: String,
+    pub token: String,
+    pub total_amount: i128,
+    pub claimed_amount: i128,
+    pub clawed_back_amount: i128,
+    /// `active`, `paused` or `revoked`.
+    pub status: String,
+    pub termination_time: Option<u64>,
+    /// Set once the claim right has been transferred away from the beneficiary.
+    pub claim_holder: Option<String>,
+}
+
+pub struct Store {
+    conn: Connection,
+}
+
+impl Store {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::with_connection(Connection::open(path)?)
+    }
+
+    pub fn open_in_memory() -> Result<Self, Error> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    fn with_connection(conn: Connection) -> Result<Self, Error> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(Store { conn })
+    }
+
+    /// Applies one event. Returns `false` for events that don't change the projection,
+    /// such as keeper changes or updates to a grant that was created before indexing began.
+    pub fn apply(&mut self, decoded: &DecodedEvent) -> Result<bool, Error> {
+        let tx = self.conn.transaction()?;
+        let changed = project(&tx, decoded)?;
+        tx.commit()?;
+        Ok(changed)
+    }
+
+    /// Applies the event at `id` unless it was applied before. Returns `false` for an event
+    /// already applied as well as for one that doesn't change the projection.
+    pub fn apply_once(&mut self, id: &EventId, decoded: &DecodedEvent) -> Result<bool, Error> {
+        let tx = self.conn.transaction()?;
+        let first_time = tx.execute(
+            "INSERT OR IGNORE INTO applied_events (ledger, tx_hash, event_index) VALUES (?1, ?2, ?3)",
+            params![id.ledger, id.tx_hash, id.index],
+        )? > 0;
+        let changed = first_time && project(&tx, decoded)?;
+        tx.commit()?;
+        Ok(changed)
+    }
+
+    /// The last event applied with `apply_once`, e.g. to resume `getEvents` from its ledger.
+    pub fn cursor(&self) -> Result<Option<EventId>, Error> {
+        Ok(self
+            .conn
+            .query_row(
+                "SELECT ledger, tx_hash, event_index FROM applied_events ORDER BY rowid DESC LIMIT 1",
+                [],
+                |row| Ok(EventId { ledger: row.get(0)?, tx_hash: row.get(1)?, index: row.get(2)? }),
+            )
+            .optional()?)
+    }
+
+    pub fn payment(&self, contract: &str, payment_id: u64) -> Result<Option<Payment>, Error> {
+        let row = self
+            .conn
+            .query_row(
+                "SELECT sender, amount, asset, receiver_id, target_asset, anchor_id, status
+                 FROM payments WHERE contract = ?1 AND payment_id = ?2",
+                params![contract, payment_id],
+                |row| {
+                    Ok((
+                        row.get::<_, String>(0)?,
+                        row.get::<_, String>(1)?,
+                        row.get(2)?,
+                        row.get(3)?,
+                        row.get(4)?,
+                        row.get(5)?,
+                        row.get(6)?,
+                    ))
+                },
+            )
+            .optional()?;
+
+        let Some((from, amount, asset, receiver_id, target_asset, anchor_id, status)) = row else {
+            return Ok(None);
+        };
+        Ok(Some(Payment {
+            contract: contract.to_string(),
+            payment_id,
+            from,
+            amount: parse_amount(amount)?,
+            asset,
+            receiver_id,
+            target_asset,
+            anchor_id,
+            status,
+        }))
+    }
+
+    pub fn split(&self, contract: &str) -> Result<Option<Split>, Error> {
+        let owner: Option<Option<String>> = self
+            .conn
+            .query_row("SELECT owner FROM splits WHERE contract = ?1", params![contract], |row| row.get(0))
+            .optional()?;
+        let Some(owner) = owner else {
+            return Ok(None);
+        };
+
+        let mut statement = self
+            .conn
+            .prepare("SELECT destination, basis_points FROM split_shares WHERE contract = ?1 ORDER BY position")?;
+        let shares = statement
+            .query_map(params![contract], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<Result<Vec<(String, u32)>, _>>()?;
+
+        let mut statement = self
+            .conn
+            .prepare("SELECT token, total FROM split_distributions WHERE contract = ?1 ORDER BY token")?;
+        let distributed = statement
+            .query_map(params![contract], |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)))?
+            .map(|row| {
+                let (token, total) = row?;
+                Ok((token, parse_amount(total)?))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        Ok(Some(Split { contract: contract.to_string(), owner, shares, distributed }))
+    }
+
+    pub fn grant(&self, contract: &str) -> Result<Option<Grant>, Error> {
+        let row = self
+            .conn
+            .query_row(
+                "SELECT beneficiary, token, total_amount, claimed_amount, clawed_back_amount, status,
+                        termination_time, claim_holder
+                 FROM grants WHERE contract = ?1",
+                params![contract],
+                |row| {
+                    Ok((
+                        row.get::<_, String>(0)?,
+                        row.get::<_, String>(1)?,
+                        row.get::<_, String>(2)?,
+                        row.get::<_, String>(3)?,
+                        row.get::<_, String>(4)?,
+                        row.get::<_, String>(5)?,
+                        row.get::<_, Option<u64>>(6)?,
+                        row.get::<_, Option<String>>(7)?,
+                    ))
+                },
+            )
+            .optional()?;
+
+        let Some((beneficiary, token, total, claimed, clawed_back, status, termination_time, claim_holder)) = row else {
+            return Ok(None);
+        };
+        Ok(Some(Grant {
+            contract: contract.to_string(),
+            beneficiary,
+            token,
+            total_amount: parse_amount(total)?,
+            claimed_amount: parse_amount(claimed)?,
+            clawed_back_amount: parse_amount(clawed_back)?,
+            status,
+            termination_time,
+            claim_holder,
+        }))
+    }
+}
+
+/// Applies one event within `tx`. Returns whether the projection changed.
+fn project(tx: &Transaction, decoded: &DecodedEvent) -> Result<bool, Error> {
+    let contract = address(decoded.contract.as_ref().ok_or(Error::MissingContract)?);
+
+    let changed = match &decoded.event {
+        PaydEvent::PaymentInitiated { payment_id, record } => tx.execute(
+            "INSERT OR REPLACE INTO payments
+             (contract, payment_id, sender, amount, asset, receiver_id, target_asset, anchor_id, status)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            params![
+                contract,
+                payment_id,
+                address(&record.from),
+                record.amount.to_string(),
+                address(&record.asset),
+                record.receiver_id.to_string(),
+                record.target_asset.to_string(),
+                record.anchor_id.to_string(),
+                record.status.to_string(),
+            ],
+        )?,
+        PaydEvent::PaymentStatusUpdated { payment_id, status } => tx.execute(
+            "UPDATE payments SET status = ?3 WHERE contract = ?1 AND payment_id = ?2",
+            params![contract, payment_id, status],
+        )?,
+
+        PaydEvent::SharesUpdated { shares } => {
+            tx.execute("INSERT OR IGNORE INTO splits (contract) VALUES (?1)", params![contract])?;
+            tx.execute("DELETE FROM split_shares WHERE contract = ?1", params![contract])?;
+            for (position, share) in shares.iter().enumerate() {
+                tx.execute(
+                    "INSERT INTO split_shares (contract, position, destination, basis_points) VALUES (?1, ?2, ?3, ?4)",
+                    params![contract, position, address(&share.destination), share.basis_points],
+                )?;
+            }
+            1
+        }
+        PaydEvent::Distributed { token, amount, .. } => {
+            let token = address(token);
+            let total: Option<String> = tx
+                .query_row(
+                    "SELECT total FROM split_distributions WHERE contract = ?1 AND token = ?2",
+                    params![contract, token],
+                    |row| row.get(0),
+                )
+                .optional()?;
+            let total = total.map(parse_amount).transpose()?.unwrap_or(0) + amount;
+            tx.execute(
+                "INSERT OR REPLACE INTO split_distributions (contract, token, total) VALUES (?1, ?2, ?3)",
+                params![contract, token, total.to_string()],
+            )?
+        }
+        // Emitted by the factory; the split itself is the `split` address
+        PaydEvent::SplitCreated { owner, split } => tx.execute(
+            "INSERT INTO splits (contract, owner) VALUES (?1, ?2)
+             ON CONFLICT (contract) DO UPDATE SET owner = excluded.owner",
+            params![address(split), address(owner)],
+        )?,
+
+        PaydEvent::Granted { beneficiary, token, amount } => tx.execute(
+            "INSERT OR REPLACE INTO grants
+             (contract, beneficiary, token, total_amount, claimed_amount, clawed_back_amount, status)
+             VALUES (?1, ?2, ?3, ?4, '0', '0', 'active')",
+            params![contract, address(beneficiary), address(token), amount.to_string()],
+        )?,
+        PaydEvent::Claimed { amount, .. } => add_to_grant(tx, &contract, "claimed_amount", *amount)?,
+        PaydEvent::ToppedUp { amount, .. } => add_to_grant(tx, &contract, "total_amount", *amount)?,
+        // The grant counts what was unvested; less may have come back if it was underfunded
+        PaydEvent::ClawedBack { unvested, .. } => {
+            tx.execute("UPDATE grants SET status = 'revoked' WHERE contract = ?1", params![contract])?;
+            add_to_grant(tx, &contract, "clawed_back_amount", *unvested)?
+        }
+        PaydEvent::PartiallyClawedBack { amount, .. } | PaydEvent::MilestonesForfeited { amount, .. } => {
+            add_to_grant(tx, &contract, "clawed_back_amount", *amount)?
+        }
+        // A revoked grant stays revoked whatever follows
+        PaydEvent::Paused { .. } => tx.execute(
+            "UPDATE grants SET status = 'paused' WHERE contract = ?1 AND status = 'active'",
+            params![contract],
+        )?,
+        PaydEvent::Resumed { .. } => tx.execute(
+            "UPDATE grants SET status = 'active' WHERE contract = ?1 AND status = 'paused'",
+            params![contract],
+        )?,
+        PaydEvent::BeneficiaryChanged { new, .. } => tx.execute(
+            "UPDATE grants SET beneficiary = ?2 WHERE contract = ?1",
+            params![contract, address(new)],
+        )?,
+        PaydEvent::ClaimRightTransferred { to, .. } => tx.execute(
+            "UPDATE grants SET claim_holder = ?2 WHERE contract = ?1",
+            params![contract, address(to)],
+        )?,
+        PaydEvent::TerminationScheduled { termination_time, .. } => tx.execute(
+            "UPDATE grants SET termination_time = ?2 WHERE contract = ?1",
+            params![contract, termination_time],
+        )?,
+
+        // The grant's own `granted` event fills in the row
+        PaydEvent::GrantCreated { .. } => 0,
+
+        PaydEvent::KeeperSet { .. }
+        | PaydEvent::MilestoneAttested { .. }
+        | PaydEvent::ExcessSwept { .. }
+        | PaydEvent::Shortfall { .. } => 0,
+
+        // Only payments, splits and grants are projected
+        PaydEvent::CouncilSet { .. }
+        | PaydEvent::ThresholdSet { .. }
+        | PaydEvent::Upgraded { .. }
+        | PaydEvent::TemplateUpdated { .. }
+        | PaydEvent::StreamFunded { .. }
+        | PaydEvent::StreamCreated { .. }
+        | PaydEvent::StreamRateUpdated { .. }
+        | PaydEvent::StreamCancelled { .. }
+        | PaydEvent::StreamWithdrawn { .. }
+        | PaydEvent::StreamUnderpaid { .. }
+        | PaydEvent::TreasuryWithdrawn { .. }
+        | PaydEvent::PayrollFunded { .. }
+        | PaydEvent::PayrollWithdrawn { .. }
+        | PaydEvent::EmployeeSet { .. }
+        | PaydEvent::EmployeeRemoved { .. }
+        | PaydEvent::EmployeePaid { .. }
+        | PaydEvent::PayrollRunCompleted { .. }
+        | PaydEvent::PayslipIssued { .. }
+        | PaydEvent::VaultDeposited { .. }
+        | PaydEvent::RoleSet { .. }
+        | PaydEvent::DailyLimitSet { .. }
+        | PaydEvent::VaultWithdrawn { .. }
+        | PaydEvent::SpendApproved { .. }
+        | PaydEvent::Spent { .. }
+        | PaydEvent::Proposed { .. }
+        | PaydEvent::ProposalApproved { .. }
+        | PaydEvent::ApprovalRevoked { .. }
+        | PaydEvent::ProposalExecuted { .. }
+        | PaydEvent::SignersSet { .. }
+        | PaydEvent::DistributionFunded { .. }
+        | PaydEvent::DistributionClaimed { .. }
+        | PaydEvent::DistributionReclaimed { .. }
+        | PaydEvent::TokenClawedBack { .. }
+        | PaydEvent::FreezeSet { .. }
+        | PaydEvent::IssuerSet { .. }
+        | PaydEvent::PayslipMinted { .. } => 0,
+    };
+
+    Ok(changed > 0)
+}
+
+fn parse_amount(value: String) -> Result<i128, Error> {
+    value.parse().map_err(|_| Error::Corrupt(value))
+}
+
+/// Adds `delta` to one of the grant's amount columns. Returns the number of grants updated.
+fn add_to_grant(tx: &Transaction, contract: &str, column: &str, delta: i128) -> Result<usize, Error> {
+    let current: Option<String> = tx
+        .query_row(&format!("SELECT {column} FROM grants WHERE contract = ?1"), params![contract], |row| row.get(0))
+        .optional()?;
+    let Some(current) = current else {
+        return Ok(0);
+    };
+
+    let updated = parse_amount(current)? + delta;
+    Ok(tx.execute(
+        &format!("UPDATE grants SET {column} = ?2 WHERE contract = ?1"),
+        params![contract, updated.to_string()],
+    )?)
+}
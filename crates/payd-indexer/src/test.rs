@@ -0,0 +1,248 @@
+#![cfg(test)]
+
+use crate::*;
+use payd_client::events::{ContractKind, Contracts, PaydEvent};
+use soroban_sdk::Env;
+
+// Captured from a payment, a revenue split and a vesting grant running in the SDK test
+// environment, token transfer events included.
+const EVENTS: &str = include_str!("../fixtures/events.txt");
+
+const PAYMENT: &str = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAITA4";
+const SPLIT: &str = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAMDR4";
+const GRANT: &str = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAATYON";
+const ASSET: &str = "CBEPDNVYXQGWB5YUBXKJWYJA7OXTZW5LFLNO5JRRGE6Z6C5OSUZPCCEL";
+const RECIPIENT_1: &str = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAOLZM";
+const RECIPIENT_2: &str = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAARQG5";
+const BENEFICIARY: &str = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAVAX5";
+const CLAIM_HOLDER: &str = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAXI7N";
+
+fn contracts() -> Contracts {
+    let mut contracts = Contracts::new();
+    for (contract, kind) in [(PAYMENT, ContractKind::Payment), (SPLIT, ContractKind::Split), (GRANT, ContractKind::Vesting)] {
+        contracts.insert(contract.parse().unwrap(), kind);
+    }
+    contracts
+}
+
+fn events(env: &Env) -> Vec<DecodedEvent> {
+    read_events(env, &mut contracts(), EVENTS.as_bytes()).unwrap()
+}
+
+fn indexed(env: &Env) -> Store {
+    let mut store = Store::open_in_memory().unwrap();
+    for event in events(env) {
+        store.apply(&event).unwrap();
+    }
+    store
+}
+
+#[test]
+fn test_read_events_skips_other_contracts() {
+    let env = Env::default();
+    let events = events(&env);
+
+    let names: Vec<String> = events.iter().map(|e| json::to_json(e)["event"].as_str().unwrap().to_string()).collect();
+    assert_eq!(names, [
+        "payment_initiated",
+        "payment_status_updated",
+        "shares_updated",
+        "distributed",
+        "distributed",
+        "granted",
+        "claimed",
+        "partially_clawed_back",
+        "claim_right_transferred",
+        "claimed",
+    ]);
+
+    // Only the payment is followed now
+    let mut contracts = Contracts::new();
+    contracts.insert(PAYMENT.parse().unwrap(), ContractKind::Payment);
+    assert_eq!(read_events(&env, &mut contracts, EVENTS.as_bytes()).unwrap().len(), 2);
+}
+
+#[test]
+fn test_json_output() {
+    let env = Env::default();
+    let events = events(&env);
+
+    let value = json::to_json(&events[0]);
+    assert_eq!(value["contract"], PAYMENT);
+    assert_eq!(value["kind"], "payment");
+    assert_eq!(value["payment_id"], 1);
+    assert_eq!(value["amount"], "250");
+    assert_eq!(value["asset"], ASSET);
+    assert_eq!(value["receiver_id"], "emp-42");
+    assert_eq!(value["status"], "pending");
+
+    let value = json::to_json(&events[2]);
+    assert_eq!(value["shares"][1]["destination"], RECIPIENT_2);
+    assert_eq!(value["shares"][1]["basis_points"], 3000);
+}
+
+#[test]
+fn test_payment_projection() {
+    let env = Env::default();
+    let store = indexed(&env);
+
+    let payment = store.payment(PAYMENT, 1).unwrap().unwrap();
+    assert_eq!(payment.amount, 250);
+    assert_eq!(payment.target_asset, "NGN");
+    assert_eq!(payment.anchor_id, "anchor-1");
+    assert_eq!(payment.status, "settled");
+
+    assert!(store.payment(PAYMENT, 2).unwrap().is_none());
+}
+
+#[test]
+fn test_split_projection() {
+    let env = Env::default();
+    let store = indexed(&env);
+
+    let split = store.split(SPLIT).unwrap().unwrap();
+    assert_eq!(split.owner, None);
+    assert_eq!(split.shares, [(RECIPIENT_1.to_string(), 7000), (RECIPIENT_2.to_string(), 3000)]);
+    assert_eq!(split.distributed, [(ASSET.to_string(), 1500)]);
+}
+
+#[test]
+fn test_grant_projection() {
+    let env = Env::default();
+    let store = indexed(&env);
+
+    // Matches the grant's own config at the end of the captured run
+    let grant = store.grant(GRANT).unwrap().unwrap();
+    assert_eq!(grant.beneficiary, BENEFICIARY);
+    assert_eq!(grant.token, ASSET);
+    assert_eq!(grant.total_amount, 1000);
+    assert_eq!(grant.claimed_amount, 600);
+    assert_eq!(grant.clawed_back_amount, 100);
+    assert_eq!(grant.status, "active");
+    assert_eq!(grant.claim_holder.as_deref(), Some(CLAIM_HOLDER));
+}
+
+#[test]
+fn test_projection_persists() {
+    let env = Env::default();
+    let path = std::env::temp_dir().join(format!("payd-indexer-test-{}.sqlite", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let mut store = Store::open(&path).unwrap();
+    for event in events(&env) {
+        store.apply(&event).unwrap();
+    }
+    drop(store);
+
+    let store = Store::open(&path).unwrap();
+    assert_eq!(store.grant(GRANT).unwrap().unwrap().claimed_amount, 600);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_event_without_contract() {
+    let env = Env::default();
+    let mut event = events(&env).remove(0);
+    event.contract = None;
+
+    let mut store = Store::open_in_memory().unwrap();
+    assert!(matches!(store.apply(&event), Err(Error::MissingContract)));
+}
+
+#[test]
+fn test_unprojected_events() {
+    let env = Env::default();
+    let mut store = indexed(&env);
+
+    // Keeper changes are decoded but don't touch the projection
+    let mut event = events(&env).remove(0);
+    event.event = PaydEvent::KeeperSet { holder: event.contract.clone().unwrap(), keeper: None };
+    assert!(!store.apply(&event).unwrap());
+}
+
+#[test]
+fn test_revoked_grant_stays_revoked() {
+    let env = Env::default();
+    let mut store = indexed(&env);
+    let granted = events(&env).remove(5);
+    let admin = granted.contract.clone().unwrap();
+    let grant_event = |event| DecodedEvent { event, ..granted.clone() };
+
+    store.apply(&grant_event(PaydEvent::Paused { beneficiary: admin.clone(), at: 10 })).unwrap();
+    assert_eq!(store.grant(GRANT).unwrap().unwrap().status, "paused");
+
+    // Only the unvested amount counts as clawed back, whatever the grant could return
+    store.apply(&grant_event(PaydEvent::ClawedBack { admin: admin.clone(), unvested: 300, returned: 250 })).unwrap();
+    let grant = store.grant(GRANT).unwrap().unwrap();
+    assert_eq!(grant.status, "revoked");
+    assert_eq!(grant.clawed_back_amount, 400);
+
+    assert!(!store.apply(&grant_event(PaydEvent::Resumed { beneficiary: admin.clone(), paused_seconds: 5 })).unwrap());
+    assert!(!store.apply(&grant_event(PaydEvent::Paused { beneficiary: admin, at: 20 })).unwrap());
+    assert_eq!(store.grant(GRANT).unwrap().unwrap().status, "revoked");
+}
+
+#[test]
+fn test_malformed_line() {
+    let env = Env::default();
+    let input = format!("\n{}\nnot-xdr\n", EVENTS.lines().next().unwrap());
+
+    assert!(matches!(read_events(&env, &mut contracts(), input.as_bytes()), Err(Error::Decode(3, _))));
+}
+
+#[test]
+fn test_events_are_streamed() {
+    let env = Env::default();
+    let input = format!("{EVENTS}not-xdr\n");
+
+    // Every event before the malformed line reaches the callback first
+    let mut seen = 0;
+    let result = for_each_event(&env, &mut contracts(), input.as_bytes(), |_| {
+        seen += 1;
+        Ok(())
+    });
+    assert!(matches!(result, Err(Error::Decode(22, _))));
+    assert_eq!(seen, 10);
+}
+
+/// The fixture with a position on every line: one transaction per ledger from ledger 100.
+fn keyed_events() -> String {
+    let hash = "ab".repeat(32);
+    EVENTS.lines().enumerate().map(|(i, line)| format!("{} {hash} 0 {line}\n", 100 + i)).collect()
+}
+
+#[test]
+fn test_replayed_events_are_skipped() {
+    let env = Env::default();
+    let mut store = Store::open_in_memory().unwrap();
+    let mut apply = |input: &str| {
+        for_each_event(&env, &mut contracts(), input.as_bytes(), |input| {
+            store.apply_once(&input.id.unwrap(), &input.event)?;
+            Ok(())
+        })
+        .unwrap();
+    };
+
+    // A re-run over the same input, or one overlapping the last, changes nothing
+    let input = keyed_events();
+    apply(&input);
+    apply(&input);
+    apply(input.lines().last().unwrap());
+
+    let grant = store.grant(GRANT).unwrap().unwrap();
+    assert_eq!(grant.claimed_amount, 600);
+    assert_eq!(store.split(SPLIT).unwrap().unwrap().distributed, [(ASSET.to_string(), 1500)]);
+
+    let cursor = store.cursor().unwrap().unwrap();
+    assert_eq!((cursor.ledger, cursor.index), (100 + EVENTS.lines().count() as u32 - 1, 0));
+}
+
+#[test]
+fn test_bad_event_id() {
+    let env = Env::default();
+    let event = EVENTS.lines().next().unwrap();
+
+    for input in [format!("12 not-a-hash 0 {event}"), format!("12 {event}")] {
+        assert!(matches!(read_events(&env, &mut contracts(), input.as_bytes()), Err(Error::BadEventId(1))));
+    }
+}
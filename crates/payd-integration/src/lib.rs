@@ -0,0 +1,218 @@
+//! Fixture builders that register the PayD contracts side by side in one test environment.
+//! The end-to-end flows themselves live under `tests/`.
+
+use cross_asset_payment::{CrossAssetPaymentContract, CrossAssetPaymentContractClient};
+use multisig::{Action, MultisigContract, MultisigContractClient};
+use payroll::{PayrollContract, PayrollContractClient, RosterEntry};
+use revenue_split::{RecipientShare, RevenueSplitContract, RevenueSplitContractClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env, IntoVal, String, Symbol, Val, Vec};
+use treasury_vault::{TreasuryVaultContract, TreasuryVaultContractClient};
+use vesting_escrow::{CliffMode, TrancheTerms, VestingContract, VestingContractClient, VestingSchedule, DEFAULT_START_BOUNDS};
+
+pub use payd_testutils::Asset;
+
+/// Ledger time every fixture environment starts at.
+pub const START: u64 = 1_700_000_000;
+pub const DAY: u64 = 24 * 60 * 60;
+
+/// A fresh environment at `START` with every authorization mocked.
+pub fn env() -> Env {
+    let e = payd_testutils::env();
+    e.cost_estimate().budget().reset_unlimited();
+    e.ledger().set_timestamp(START);
+    e
+}
+
+pub fn advance(e: &Env, seconds: u64) {
+    e.ledger().set_timestamp(e.ledger().timestamp() + seconds);
+}
+
+pub fn payments<'a>(e: &Env, admin: &Address) -> CrossAssetPaymentContractClient<'a> {
+    let client = CrossAssetPaymentContractClient::new(e, &e.register(CrossAssetPaymentContract, ()));
+    client.init(admin);
+    client
+}
+
+/// A split paying `(recipient, basis_points)` pairs.
+pub fn split<'a>(e: &Env, admin: &Address, shares: &[(&Address, u32)]) -> RevenueSplitContractClient<'a> {
+    let mut list = Vec::new(e);
+    for (destination, basis_points) in shares {
+        list.push_back(RecipientShare { destination: (*destination).clone(), basis_points: *basis_points });
+    }
+
+    let client = RevenueSplitContractClient::new(e, &e.register(RevenueSplitContract, ()));
+    client.init(admin, &list);
+    client
+}
+
+pub fn treasury<'a>(e: &Env, admin: &Address) -> TreasuryVaultContractClient<'a> {
+    let client = TreasuryVaultContractClient::new(e, &e.register(TreasuryVaultContract, ()));
+    client.init(admin);
+    client
+}
+
+/// A payroll whose period 0 starts now, with every employee paid `amount` of `asset` each period.
+pub fn payroll<'a>(
+    e: &Env,
+    employer: &Address,
+    period_seconds: u64,
+    asset: &Asset,
+    employees: &[(&Address, i128)],
+) -> PayrollContractClient<'a> {
+    let client = PayrollContractClient::new(e, &e.register(PayrollContract, ()));
+    client.init(employer, &e.ledger().timestamp(), &period_seconds);
+
+    for (employee, amount) in employees {
+        client.set_employee(&RosterEntry {
+            employee: (*employee).clone(),
+            asset: asset.address.clone(),
+            amount: *amount,
+            frequency: 1,
+            start_period: 0,
+        });
+    }
+    client
+}
+
+/// A multisig whose signers can pass calls in one step.
+pub struct Council<'a> {
+    pub client: MultisigContractClient<'a>,
+    pub signers: std::vec::Vec<Address>,
+    pub threshold: u32,
+}
+
+impl Council<'_> {
+    pub fn new(e: &Env, signers: u32, threshold: u32) -> Self {
+        let signers: std::vec::Vec<Address> = (0..signers).map(|_| Address::generate(e)).collect();
+        let client = MultisigContractClient::new(e, &e.register(MultisigContract, ()));
+        client.init(&Vec::from_slice(e, &signers), &threshold);
+
+        Council { client, signers, threshold }
+    }
+
+    pub fn address(&self) -> Address {
+        self.client.address.clone()
+    }
+
+    /// Proposes `contract.function(args)` and collects exactly the approvals it needs.
+    /// Returns the proposal id, ready to execute.
+    pub fn approve_call(&self, contract: &Address, function: &str, args: Vec<Val>) -> u32 {
+        let e = &self.client.env;
+        let action = Action::Call(contract.clone(), Symbol::new(e, function), args);
+        let id = self.client.propose(&self.signers[0], &action, &(e.ledger().timestamp() + DAY));
+        for signer in &self.signers[1..self.threshold as usize] {
+            self.client.approve(signer, &id);
+        }
+        id
+    }
+
+    /// Proposes, approves and executes `contract.function(args)`.
+    pub fn pass(&self, contract: &Address, function: &str, args: Vec<Val>) -> Val {
+        let id = self.approve_call(contract, function, args);
+        self.client.execute(&id)
+    }
+}
+
+/// Builds and funds a vesting grant. Defaults to a one-year linear grant starting now,
+/// with no cliff, funded by a fresh account.
+pub struct GrantBuilder<'a> {
+    e: Env,
+    asset: &'a Asset<'a>,
+    beneficiary: Address,
+    funder: Option<Address>,
+    clawback_admin: Option<Address>,
+    amount: i128,
+    start_time: Option<u64>,
+    cliff_seconds: u64,
+    duration_seconds: u64,
+    schedule: VestingSchedule,
+}
+
+impl<'a> GrantBuilder<'a> {
+    pub fn new(e: &Env, asset: &'a Asset<'a>, beneficiary: &Address) -> Self {
+        GrantBuilder {
+            e: e.clone(),
+            asset,
+            beneficiary: beneficiary.clone(),
+            funder: None,
+            clawback_admin: None,
+            amount: 1_000_000,
+            start_time: None,
+            cliff_seconds: 0,
+            duration_seconds: 365 * DAY,
+            schedule: VestingSchedule::Linear,
+        }
+    }
+
+    pub fn amount(mut self, amount: i128) -> Self {
+        self.amount = amount;
+        self
+    }
+
+    pub fn funder(mut self, funder: &Address) -> Self {
+        self.funder = Some(funder.clone());
+        self
+    }
+
+    pub fn clawback_admin(mut self, admin: &Address) -> Self {
+        self.clawback_admin = Some(admin.clone());
+        self
+    }
+
+    pub fn start_time(mut self, start_time: u64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn cliff(mut self, seconds: u64) -> Self {
+        self.cliff_seconds = seconds;
+        self
+    }
+
+    pub fn duration(mut self, seconds: u64) -> Self {
+        self.duration_seconds = seconds;
+        self
+    }
+
+    pub fn schedule(mut self, schedule: VestingSchedule) -> Self {
+        self.schedule = schedule;
+        self
+    }
+
+    /// Registers the grant and funds it. A funder that wasn't set is minted the amount first.
+    pub fn build(self) -> VestingContractClient<'a> {
+        let e = &self.e;
+        let funder = self.funder.unwrap_or_else(|| {
+            let funder = Address::generate(e);
+            self.asset.mint(&funder, self.amount);
+            funder
+        });
+        let clawback_admin = self.clawback_admin.unwrap_or_else(|| Address::generate(e));
+
+        let terms = TrancheTerms {
+            start_time: self.start_time.unwrap_or(e.ledger().timestamp()),
+            cliff_seconds: self.cliff_seconds,
+            duration_seconds: self.duration_seconds,
+            schedule: self.schedule,
+            cliff_mode: CliffMode::CatchUp,
+        };
+
+        let client = VestingContractClient::new(e, &e.register(VestingContract, ()));
+        client.initialize(&funder, &self.beneficiary, &self.asset.address, &self.amount, &clawback_admin, &terms, &DEFAULT_START_BOUNDS);
+        client
+    }
+}
+
+/// `initiate_payment` arguments for a payout of `amount` to an off-chain receiver.
+pub fn payment_args(e: &Env, from: &Address, amount: i128, asset: &Asset, receiver_id: &str) -> Vec<Val> {
+    (
+        from.clone(),
+        amount,
+        asset.address.clone(),
+        String::from_str(e, receiver_id),
+        String::from_str(e, "NGN"),
+        String::from_str(e, "anchor-ng"),
+    )
+        .into_val(e)
+}
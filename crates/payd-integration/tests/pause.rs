@@ -0,0 +1,59 @@
+//! A council pausing and resuming a grant whose claims are routed into a split.
+
+use payd_integration::*;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Symbol};
+
+#[test]
+fn test_council_pauses_grant_routed_into_split() {
+    let e = env();
+    let asset = Asset::new(&e);
+    let employee = Address::generate(&e);
+    let pension = Address::generate(&e);
+
+    let council = Council::new(&e, 3, 2);
+    let split = split(&e, &employee, &[(&employee, 9000), (&pension, 1000)]);
+    let grant = GrantBuilder::new(&e, &asset, &employee)
+        .amount(1_000_000)
+        .duration(100 * DAY)
+        .clawback_admin(&council.address())
+        .build();
+    let distribute = Symbol::new(&e, "distribute");
+
+    // Unpaid leave from day 20 to day 50
+    advance(&e, 20 * DAY);
+    council.pass(&grant.address, "pause_vesting", vec![&e]);
+    advance(&e, 30 * DAY);
+    assert_eq!(grant.claim_to_contract(&split.address, &distribute), 200_000);
+
+    council.pass(&grant.address, "resume_vesting", vec![&e]);
+    assert_eq!(grant.get_end_time(), START + 130 * DAY);
+
+    // Nothing accrued while paused, so the grant finishes 30 days late
+    advance(&e, 70 * DAY);
+    assert_eq!(grant.claim_to_contract(&split.address, &distribute), 700_000);
+    advance(&e, 10 * DAY);
+    assert_eq!(grant.claim_to_contract(&split.address, &distribute), 100_000);
+
+    assert_eq!(asset.balance(&employee), 900_000);
+    assert_eq!(asset.balance(&pension), 100_000);
+}
+
+#[test]
+fn test_pause_needs_the_council() {
+    let e = env();
+    let asset = Asset::new(&e);
+    let employee = Address::generate(&e);
+
+    let council = Council::new(&e, 3, 2);
+    let grant = GrantBuilder::new(&e, &asset, &employee).clawback_admin(&council.address()).build();
+
+    let id = council.approve_call(&grant.address, "pause_vesting", vec![&e]);
+    council.client.revoke(&council.signers[1], &id);
+    assert!(council.client.try_execute(&id).is_err());
+    assert_eq!(grant.get_config().paused_at, None);
+
+    council.client.approve(&council.signers[2], &id);
+    council.client.execute(&id);
+    assert_eq!(grant.get_config().paused_at, Some(START));
+}
@@ -0,0 +1,155 @@
+//! Payroll batches paid out through the cross-asset payment contract and settled by the anchor.
+
+use payd_integration::*;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{symbol_short, vec, Address, IntoVal, String, Symbol, TryFromVal};
+
+#[test]
+fn test_treasury_batch_settled_by_anchor() {
+    let e = env();
+    let asset = Asset::new(&e);
+    let cfo = Address::generate(&e);
+    let operator = Address::generate(&e);
+    let anchor = Address::generate(&e);
+
+    let vault = treasury(&e, &cfo);
+    asset.mint(&cfo, 10_000);
+    vault.deposit(&cfo, &asset.address, &10_000);
+    let payments = payments(&e, &anchor);
+
+    // The CFO approves this month's batch; the operator sends each line through the anchor
+    let batch = [("emp-1", 1_200), ("emp-2", 900), ("emp-3", 1_500)];
+    let initiate = Symbol::new(&e, "initiate_payment");
+    vault.approve(&operator, &payments.address, &initiate, &asset.address, &3_600);
+
+    let mut ids = std::vec::Vec::new();
+    for (receiver_id, amount) in batch {
+        let args = payment_args(&e, &vault.address, amount, &asset, receiver_id);
+        let id = vault.spend(&operator, &payments.address, &initiate, &asset.address, &amount, &args);
+        ids.push(u64::try_from_val(&e, &id).unwrap());
+    }
+
+    assert_eq!(ids, [1, 2, 3]);
+    assert_eq!(asset.balance(&payments.address), 3_600);
+    assert_eq!(asset.balance(&vault.address), 6_400);
+    assert_eq!(vault.get_allowance(&operator, &payments.address, &initiate, &asset.address), 0);
+
+    // The approved batch is used up
+    let args = payment_args(&e, &vault.address, 1, &asset, "emp-4");
+    assert!(vault.try_spend(&operator, &payments.address, &initiate, &asset.address, &1, &args).is_err());
+
+    for id in &ids {
+        payments.update_status(id, &symbol_short!("settled"));
+    }
+    for (id, (receiver_id, amount)) in ids.iter().zip(batch) {
+        let record = payments.get_payment(id).unwrap();
+        assert_eq!(record.from, vault.address);
+        assert_eq!(record.amount, amount);
+        assert_eq!(record.receiver_id, String::from_str(&e, receiver_id));
+        assert_eq!(record.status, symbol_short!("settled"));
+    }
+}
+
+#[test]
+fn test_paged_payroll_run_then_anchor_payout() {
+    let e = env();
+    let asset = Asset::new(&e);
+    let employer = Address::generate(&e);
+    let anchor = Address::generate(&e);
+
+    // More employees than one `run_payroll` page
+    let employees: std::vec::Vec<Address> = (0..25).map(|_| Address::generate(&e)).collect();
+    let roster: std::vec::Vec<(&Address, i128)> = employees.iter().map(|employee| (employee, 1_000)).collect();
+    let payroll = payroll(&e, &employer, 30 * DAY, &asset, &roster);
+    asset.mint(&employer, 30_000);
+    payroll.deposit(&employer, &asset.address, &30_000);
+
+    assert!(!payroll.run_payroll(&0));
+    assert!(!payroll.run_payroll(&0));
+    assert!(payroll.run_payroll(&0));
+    // A completed run pays nobody twice
+    assert!(payroll.run_payroll(&0));
+
+    for employee in &employees {
+        assert_eq!(asset.balance(employee), 1_000);
+        assert!(payroll.is_paid(&0, employee));
+    }
+    assert_eq!(asset.balance(&payroll.address), 5_000);
+
+    // An employee abroad cashes out through the anchor
+    let payments = payments(&e, &anchor);
+    let id = payments.initiate_payment(
+        &employees[0],
+        &1_000,
+        &asset.address,
+        &String::from_str(&e, "emp-0"),
+        &String::from_str(&e, "NGN"),
+        &String::from_str(&e, "anchor-ng"),
+    );
+    payments.update_status(&id, &symbol_short!("settled"));
+
+    assert_eq!(asset.balance(&employees[0]), 0);
+    assert_eq!(asset.balance(&payments.address), 1_000);
+    assert_eq!(payments.get_payment(&id).unwrap().status, symbol_short!("settled"));
+}
+
+#[test]
+fn test_settlement_through_council() {
+    let e = env();
+    let asset = Asset::new(&e);
+    let employer = Address::generate(&e);
+
+    // The anchor's operations team settles payments 2-of-3
+    let council = Council::new(&e, 3, 2);
+    let payments = payments(&e, &council.address());
+    asset.mint(&employer, 500);
+    let id = payments.initiate_payment(
+        &employer,
+        &500,
+        &asset.address,
+        &String::from_str(&e, "emp-1"),
+        &String::from_str(&e, "NGN"),
+        &String::from_str(&e, "anchor-ng"),
+    );
+
+    let settle = vec![&e, id.into_val(&e), symbol_short!("settled").into_val(&e)];
+    let action = multisig::Action::Call(payments.address.clone(), Symbol::new(&e, "update_status"), settle);
+    let proposal = council.client.propose(&council.signers[0], &action, &(START + DAY));
+
+    // One approval is not enough
+    assert!(council.client.try_execute(&proposal).is_err());
+    assert_eq!(payments.get_payment(&id).unwrap().status, symbol_short!("pending"));
+
+    council.client.approve(&council.signers[1], &proposal);
+    council.client.execute(&proposal);
+    assert_eq!(payments.get_payment(&id).unwrap().status, symbol_short!("settled"));
+}
+
+#[test]
+fn test_large_payment_settled_by_council() {
+    let e = env();
+    let asset = Asset::new(&e);
+    let employer = Address::generate(&e);
+    let anchor = Address::generate(&e);
+
+    // The anchor settles routine payments alone and anything above 10,000 goes 2-of-3
+    let council = Council::new(&e, 3, 2);
+    let payments = payments(&e, &anchor);
+    payments.set_council(&council.address());
+    council.pass(&payments.address, "set_threshold", vec![&e, asset.address.into_val(&e), 10_000i128.into_val(&e)]);
+    assert_eq!(payments.get_threshold(&asset.address), Some(10_000));
+
+    asset.mint(&employer, 60_000);
+    let id = payments.initiate_payment(
+        &employer,
+        &50_000,
+        &asset.address,
+        &String::from_str(&e, "emp-1"),
+        &String::from_str(&e, "NGN"),
+        &String::from_str(&e, "anchor-ng"),
+    );
+
+    let settle = vec![&e, id.into_val(&e), symbol_short!("settled").into_val(&e)];
+    council.pass(&payments.address, "update_status", settle);
+    assert_eq!(payments.get_payment(&id).unwrap().status, symbol_short!("settled"));
+}
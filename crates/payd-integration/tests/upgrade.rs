@@ -0,0 +1,81 @@
+//! Upgrading a deployed split in place, and a council swapping the split factory's template.
+
+use payd_integration::*;
+use soroban_sdk::testutils::{Address as _, BytesN as _};
+use soroban_sdk::{vec, Address, BytesN, IntoVal};
+
+#[test]
+fn test_split_keeps_state_across_upgrade() {
+    let e = env();
+    let asset = Asset::new(&e);
+    let admin = Address::generate(&e);
+    let payer = Address::generate(&e);
+    let [first, second] = [Address::generate(&e), Address::generate(&e)];
+
+    let split = revenue_split::RevenueSplitContractClient::new(&e, &e.register(payd_wasm::REVENUE_SPLIT, ()));
+    split.init(&admin, &vec![
+        &e,
+        revenue_split::RecipientShare { destination: first.clone(), basis_points: 7000 },
+        revenue_split::RecipientShare { destination: second.clone(), basis_points: 3000 },
+    ]);
+    asset.mint(&payer, 2_000);
+    split.distribute(&asset.address, &payer, &1_000);
+
+    // The new version is different code, and only the admin can switch to it
+    let v1 = e.deployer().upload_contract_wasm(payd_wasm::REVENUE_SPLIT);
+    let v2 = e.deployer().upload_contract_wasm(payd_wasm::REVENUE_SPLIT_WITH_LOGS);
+    assert_ne!(v1, v2);
+    split.upgrade(&v2);
+    assert_eq!(e.auths()[0].0, admin);
+
+    // Recipients and admin survive the upgrade
+    split.distribute(&asset.address, &payer, &1_000);
+    assert_eq!(asset.balance(&first), 1_400);
+    assert_eq!(asset.balance(&second), 600);
+
+    // Once a council owns the split, upgrades go through it too
+    let council = Council::new(&e, 3, 2);
+    split.set_council(&council.address());
+    assert_eq!(e.auths()[0].0, admin);
+    council.pass(&split.address, "upgrade", vec![&e, v1.into_val(&e)]);
+    split.update_recipients(&vec![&e, revenue_split::RecipientShare { destination: first.clone(), basis_points: 10000 }]);
+    assert_eq!(e.auths()[0].0, council.address());
+}
+
+#[test]
+fn test_council_swaps_split_template() {
+    let e = env();
+    let asset = Asset::new(&e);
+    let owner = Address::generate(&e);
+    let partner = Address::generate(&e);
+
+    let council = Council::new(&e, 3, 2);
+    let factory = revenue_split_factory::RevenueSplitFactoryContractClient::new(
+        &e,
+        &e.register(revenue_split_factory::RevenueSplitFactoryContract, ()),
+    );
+    let v1 = e.deployer().upload_contract_wasm(payd_wasm::REVENUE_SPLIT);
+    factory.init(&council.address(), &v1);
+
+    let shares = vec![
+        &e,
+        revenue_split_factory::RecipientShare { destination: owner.clone(), basis_points: 6000 },
+        revenue_split_factory::RecipientShare { destination: partner.clone(), basis_points: 4000 },
+    ];
+    let deployed = factory.deploy_split(&owner, &shares);
+
+    // Only the council can change the template
+    let v2 = BytesN::<32>::random(&e);
+    council.pass(&factory.address, "set_wasm_hash", vec![&e, v2.into_val(&e)]);
+    assert_eq!(factory.get_wasm_hash(), v2);
+
+    // Splits deployed from the old template keep running their code
+    asset.mint(&owner, 1_000);
+    revenue_split::RevenueSplitContractClient::new(&e, &deployed).distribute(&asset.address, &owner, &1_000);
+    assert_eq!(asset.balance(&owner), 600);
+    assert_eq!(asset.balance(&partner), 400);
+
+    // New deployments use the new template, which has not been uploaded here
+    assert!(factory.try_deploy_split(&owner, &shares).is_err());
+    assert_eq!(factory.get_splits(&owner, &0, &10), vec![&e, deployed]);
+}
@@ -0,0 +1,74 @@
+//! Vesting claims routed straight into a revenue split.
+
+use payd_integration::*;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Symbol};
+
+#[test]
+fn test_claim_routed_into_split() {
+    let e = env();
+    let asset = Asset::new(&e);
+    let contractor = Address::generate(&e);
+    let agency = Address::generate(&e);
+
+    // The contractor keeps 70% of what vests, their agency takes 30%
+    let split = split(&e, &contractor, &[(&contractor, 7000), (&agency, 3000)]);
+    let grant = GrantBuilder::new(&e, &asset, &contractor).amount(1_000_000).duration(100 * DAY).build();
+
+    advance(&e, 25 * DAY);
+    let claimed = grant.claim_to_contract(&split.address, &Symbol::new(&e, "distribute"));
+
+    assert_eq!(claimed, 250_000);
+    assert_eq!(asset.balance(&contractor), 175_000);
+    assert_eq!(asset.balance(&agency), 75_000);
+    assert_eq!(asset.balance(&split.address), 0);
+    assert_eq!(grant.get_config().claimed_amount, 250_000);
+    assert_eq!(asset.balance(&grant.address), 750_000);
+}
+
+#[test]
+fn test_later_claims_follow_updated_shares() {
+    let e = env();
+    let asset = Asset::new(&e);
+    let contractor = Address::generate(&e);
+    let agency = Address::generate(&e);
+    let new_agency = Address::generate(&e);
+
+    let split = split(&e, &contractor, &[(&contractor, 7000), (&agency, 3000)]);
+    let grant = GrantBuilder::new(&e, &asset, &contractor).amount(1_000_000).duration(100 * DAY).build();
+    let distribute = Symbol::new(&e, "distribute");
+
+    advance(&e, 50 * DAY);
+    grant.claim_to_contract(&split.address, &distribute);
+
+    // The contractor moves agencies; the rest of the grant is split under the new terms
+    split.update_recipients(&soroban_sdk::vec![
+        &e,
+        revenue_split::RecipientShare { destination: contractor.clone(), basis_points: 8000 },
+        revenue_split::RecipientShare { destination: new_agency.clone(), basis_points: 2000 },
+    ]);
+    advance(&e, 50 * DAY);
+    grant.claim_to_contract(&split.address, &distribute);
+
+    assert_eq!(asset.balance(&contractor), 350_000 + 400_000);
+    assert_eq!(asset.balance(&agency), 150_000);
+    assert_eq!(asset.balance(&new_agency), 100_000);
+    assert_eq!(asset.balance(&grant.address), 0);
+}
+
+#[test]
+fn test_nothing_vested_skips_split() {
+    let e = env();
+    let asset = Asset::new(&e);
+    let contractor = Address::generate(&e);
+    let agency = Address::generate(&e);
+
+    let split = split(&e, &contractor, &[(&contractor, 7000), (&agency, 3000)]);
+    let grant = GrantBuilder::new(&e, &asset, &contractor).cliff(30 * DAY).duration(100 * DAY).build();
+
+    // Before the cliff the split is never called, so it emits no distribution
+    advance(&e, 10 * DAY);
+    assert_eq!(grant.claim_to_contract(&split.address, &Symbol::new(&e, "distribute")), 0);
+    assert_eq!(asset.balance(&agency), 0);
+    assert_eq!(grant.get_config().claimed_amount, 0);
+}
@@ -0,0 +1,37 @@
+use core::fmt;
+
+#[derive(Debug)]
+pub enum Error {
+    Scenario(toml::de::Error),
+    /// A step or grant refers to an account, split or grant that isn't declared.
+    UnknownName(String),
+    /// Accounts, splits and grants share one namespace.
+    DuplicateName(String),
+    /// A split or grant could not be created.
+    Setup(String),
+    /// Advancing by `.1` seconds from timestamp `.0` would overflow the ledger clock.
+    TimeOverflow(u64, u64),
+    /// A payment status that is not a valid `Symbol`: `[a-zA-Z0-9_]`, at most 32 characters.
+    InvalidStatus(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Scenario(e) => write!(f, "invalid scenario: {e}"),
+            Error::UnknownName(name) => write!(f, "unknown name: {name}"),
+            Error::DuplicateName(name) => write!(f, "name used twice: {name}"),
+            Error::Setup(what) => write!(f, "setup failed: {what}"),
+            Error::TimeOverflow(now, seconds) => write!(f, "cannot advance {seconds}s past timestamp {now}"),
+            Error::InvalidStatus(status) => write!(f, "invalid payment status: {status:?}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<toml::de::Error> for Error {
+    fn from(e: toml::de::Error) -> Self {
+        Error::Scenario(e)
+    }
+}
@@ -0,0 +1,18 @@
+//! Dry-runs payroll scenarios against the PayD contracts in a soroban-sdk test `Env`.
+//!
+//! A scenario declares accounts, revenue splits and vesting grants, then a list of steps
+//! (payments, distributions, claims, clawbacks and ledger-time jumps). Each step reports
+//! its outcome, the events it emitted, every balance afterwards and the CPU and memory
+//! it cost. All authorization is mocked, so steps check contract logic and budget only.
+
+mod error;
+
+pub mod scenario;
+pub mod sim;
+
+pub use error::Error;
+pub use scenario::Scenario;
+pub use sim::{Simulation, StepReport};
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,50 @@
+//! Usage: `payd-sim <scenario.toml>`
+//!
+//! Prints each step's outcome, CPU and memory cost, events and resulting balances.
+
+use std::process::ExitCode;
+
+use payd_sim::{Scenario, Simulation, StepReport};
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let (Some(path), None) = (args.next(), args.next()) else {
+        eprintln!("usage: payd-sim <scenario.toml>");
+        return ExitCode::from(2);
+    };
+
+    let reports = std::fs::read_to_string(&path)
+        .map_err(|e| format!("{path}: {e}"))
+        .and_then(|source| Scenario::from_toml(&source).map_err(|e| format!("{path}: {e}")))
+        .and_then(|scenario| Simulation::run(&scenario).map_err(|e| e.to_string()));
+
+    match reports {
+        Ok(reports) => {
+            let failed = reports.iter().filter(|report| report.outcome.is_err()).count();
+            for (index, report) in reports.iter().enumerate() {
+                print_report(index, report);
+            }
+            println!("{} steps, {failed} failed", reports.len() - 1);
+            if failed > 0 { ExitCode::FAILURE } else { ExitCode::SUCCESS }
+        }
+        Err(e) => {
+            eprintln!("payd-sim: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn print_report(index: usize, report: &StepReport) {
+    println!("== {index}: {} (t={})", report.description, report.timestamp);
+    match &report.outcome {
+        Ok(()) => println!("   ok"),
+        Err(e) => println!("   FAILED: {e}"),
+    }
+    println!("   budget: {} cpu instructions, {} memory bytes", report.cpu_instructions, report.memory_bytes);
+    for event in &report.events {
+        println!("   event: {event}");
+    }
+    let balances: Vec<String> = report.balances.iter().map(|(name, balance)| format!("{name}={balance}")).collect();
+    println!("   balances: {}", balances.join(" "));
+    println!();
+}
@@ -0,0 +1,92 @@
+//! The scenario file format. See `scenarios/example.toml`.
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+
+/// Everything is denominated in one token, minted to the accounts at the start.
+/// Amounts are `i64` because TOML integers are.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Scenario {
+    /// Ledger timestamp the run starts at.
+    #[serde(default)]
+    pub start_time: u64,
+    /// Account that administers the payment contract and claws back grants.
+    pub admin: String,
+    /// Named accounts (employer, employees, contractors) and their opening balances.
+    pub accounts: BTreeMap<String, i64>,
+    #[serde(default)]
+    pub splits: Vec<Split>,
+    #[serde(default)]
+    pub grants: Vec<Grant>,
+    #[serde(default)]
+    pub steps: Vec<Step>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Split {
+    pub name: String,
+    pub admin: String,
+    pub shares: Vec<Share>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Share {
+    pub to: String,
+    pub basis_points: u32,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Grant {
+    pub name: String,
+    pub funder: String,
+    pub beneficiary: String,
+    pub amount: i64,
+    /// Defaults to the scenario's start time.
+    pub start_time: Option<u64>,
+    #[serde(default)]
+    pub cliff_seconds: u64,
+    pub duration_seconds: u64,
+    #[serde(default)]
+    pub schedule: Schedule,
+    /// Nothing accrues before the cliff instead of catching up when it passes.
+    #[serde(default)]
+    pub start_at_cliff: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Schedule {
+    #[default]
+    Linear,
+    Periodic { period_seconds: u64, amount: i64 },
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case", deny_unknown_fields)]
+pub enum Step {
+    /// Moves ledger time forward.
+    Advance { seconds: u64 },
+    /// Adds tokens to an account, e.g. the employer topping up its treasury.
+    Mint { to: String, amount: i64 },
+    /// `initiate_payment` through an anchor.
+    Pay { from: String, amount: i64, receiver_id: String, target_asset: String, anchor_id: String },
+    /// `update_status` on a payment, by the admin.
+    SetStatus { payment: u64, status: String },
+    Distribute { split: String, from: String, amount: i64 },
+    SetShares { split: String, shares: Vec<Share> },
+    Claim { grant: String },
+    TopUp { grant: String, funder: String, amount: i64 },
+    /// Claws back the whole unvested balance, or only `amount` of it.
+    Clawback { grant: String, amount: Option<i64> },
+}
+
+impl Scenario {
+    pub fn from_toml(source: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(source)
+    }
+}
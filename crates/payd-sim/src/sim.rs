@@ -0,0 +1,345 @@
+//! Runs a scenario step by step.
+
+use std::fmt::Debug;
+
+use cross_asset_payment::{CrossAssetPaymentContract, CrossAssetPaymentContractClient};
+use payd_client::events::{decode_event, ContractKind, Contracts};
+use payd_client::xdr::{ContractEventBody, ScAddress, ScVal};
+use payd_indexer::json;
+use revenue_split::{RecipientShare, RevenueSplitContract, RevenueSplitContractClient};
+use serde_json::Value;
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{token, Address, Env, String, Symbol, TryFromVal, Vec};
+use vesting_escrow::{CliffMode, TrancheTerms, VestingContract, VestingContractClient, VestingSchedule, DEFAULT_START_BOUNDS};
+
+use crate::scenario::{Scenario, Schedule, Share, Step};
+use crate::Error;
+
+/// What one step did.
+#[derive(Clone, Debug)]
+pub struct StepReport {
+    pub description: std::string::String,
+    /// Ledger timestamp after the step.
+    pub timestamp: u64,
+    /// The contract error, if the call failed. A failed step changes nothing.
+    pub outcome: Result<(), std::string::String>,
+    pub cpu_instructions: u64,
+    pub memory_bytes: u64,
+    /// Emitted events as JSON, with known addresses replaced by their scenario names.
+    pub events: std::vec::Vec<Value>,
+    /// `(name, balance)` for every account and contract, accounts first.
+    pub balances: std::vec::Vec<(std::string::String, i128)>,
+}
+
+pub struct Simulation {
+    env: Env,
+    token: Address,
+    payments: Address,
+    /// Accounts first, then contracts, in declaration order.
+    names: std::vec::Vec<(std::string::String, Address)>,
+    splits: std::vec::Vec<std::string::String>,
+    grants: std::vec::Vec<std::string::String>,
+    /// The payment contract, splits and grants, for decoding their events.
+    contracts: Contracts,
+}
+
+impl Simulation {
+    /// Runs every step of `scenario`, starting with a report for the setup itself.
+    pub fn run(scenario: &Scenario) -> Result<std::vec::Vec<StepReport>, Error> {
+        let (mut sim, setup) = Simulation::new(scenario)?;
+        let mut reports = vec![setup];
+        for step in &scenario.steps {
+            reports.push(sim.step(step)?);
+        }
+        Ok(reports)
+    }
+
+    /// Creates the accounts, splits and grants.
+    pub fn new(scenario: &Scenario) -> Result<(Self, StepReport), Error> {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_timestamp(scenario.start_time);
+        env.cost_estimate().budget().reset_unlimited();
+
+        let token = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+        let payments = env.register(CrossAssetPaymentContract, ());
+
+        let mut sim = Simulation {
+            env,
+            token,
+            payments,
+            names: std::vec::Vec::new(),
+            splits: std::vec::Vec::new(),
+            grants: std::vec::Vec::new(),
+            contracts: Contracts::new(),
+        };
+
+        for (name, balance) in &scenario.accounts {
+            let account = Address::generate(&sim.env);
+            sim.add_name(name, account.clone())?;
+            if *balance > 0 {
+                sim.token_admin().mint(&account, &i128::from(*balance));
+            }
+        }
+
+        let admin = sim.address(&scenario.admin)?;
+        CrossAssetPaymentContractClient::new(&sim.env, &sim.payments).init(&admin);
+        sim.add_name("payments", sim.payments.clone())?;
+        sim.contracts.insert(ScAddress::from(&sim.payments), ContractKind::Payment);
+
+        for split in &scenario.splits {
+            let id = sim.env.register(RevenueSplitContract, ());
+            let admin = sim.address(&split.admin)?;
+            let shares = sim.shares(&split.shares)?;
+            failure(RevenueSplitContractClient::new(&sim.env, &id).try_init(&admin, &shares))
+                .map_err(|e| Error::Setup(format!("split {}: {e}", split.name)))?;
+            sim.contracts.insert(ScAddress::from(&id), ContractKind::Split);
+            sim.add_name(&split.name, id)?;
+            sim.splits.push(split.name.clone());
+        }
+
+        for grant in &scenario.grants {
+            let id = sim.env.register(VestingContract, ());
+            let schedule = match grant.schedule {
+                Schedule::Linear => VestingSchedule::Linear,
+                Schedule::Periodic { period_seconds, amount } => VestingSchedule::Periodic(period_seconds, amount.into()),
+            };
+            let terms = TrancheTerms {
+                start_time: grant.start_time.unwrap_or(scenario.start_time),
+                cliff_seconds: grant.cliff_seconds,
+                duration_seconds: grant.duration_seconds,
+                schedule,
+                cliff_mode: if grant.start_at_cliff { CliffMode::StartAtCliff } else { CliffMode::CatchUp },
+            };
+            failure(VestingContractClient::new(&sim.env, &id).try_initialize(
+                &sim.address(&grant.funder)?,
+                &sim.address(&grant.beneficiary)?,
+                &sim.token,
+                &grant.amount.into(),
+                &admin,
+                &terms,
+                &DEFAULT_START_BOUNDS,
+            ))
+            .map_err(|e| Error::Setup(format!("grant {}: {e}", grant.name)))?;
+            sim.contracts.insert(ScAddress::from(&id), ContractKind::Vesting);
+            sim.add_name(&grant.name, id)?;
+            sim.grants.push(grant.name.clone());
+        }
+
+        let report = sim.report("setup".into(), Ok(()), std::vec::Vec::new());
+        Ok((sim, report))
+    }
+
+    pub fn step(&mut self, step: &Step) -> Result<StepReport, Error> {
+        // Each step is measured against a fresh transaction budget
+        self.env.cost_estimate().budget().reset_default();
+
+        let env = &self.env;
+        let outcome = match step {
+            Step::Advance { seconds } => {
+                let now = env.ledger().timestamp();
+                env.ledger().set_timestamp(now.checked_add(*seconds).ok_or(Error::TimeOverflow(now, *seconds))?);
+                Ok(())
+            }
+            Step::Mint { to, amount } => failure(self.token_admin().try_mint(&self.address(to)?, &i128::from(*amount))),
+            Step::Pay { from, amount, receiver_id, target_asset, anchor_id } => {
+                failure(CrossAssetPaymentContractClient::new(env, &self.payments).try_initiate_payment(
+                    &self.address(from)?,
+                    &i128::from(*amount),
+                    &self.token,
+                    &String::from_str(env, receiver_id),
+                    &String::from_str(env, target_asset),
+                    &String::from_str(env, anchor_id),
+                ))
+            }
+            Step::SetStatus { payment, status } => failure(
+                CrossAssetPaymentContractClient::new(env, &self.payments)
+                    .try_update_status(payment, &status_symbol(env, status)?),
+            ),
+            Step::Distribute { split, from, amount } => failure(
+                RevenueSplitContractClient::new(env, &self.split(split)?)
+                    .try_distribute(&self.token, &self.address(from)?, &i128::from(*amount)),
+            ),
+            Step::SetShares { split, shares } => failure(
+                RevenueSplitContractClient::new(env, &self.split(split)?).try_update_recipients(&self.shares(shares)?),
+            ),
+            Step::Claim { grant } => failure(VestingContractClient::new(env, &self.grant(grant)?).try_claim()),
+            Step::TopUp { grant, funder, amount } => failure(
+                VestingContractClient::new(env, &self.grant(grant)?).try_top_up(&self.address(funder)?, &i128::from(*amount), &None),
+            ),
+            Step::Clawback { grant, amount: None } => {
+                failure(VestingContractClient::new(env, &self.grant(grant)?).try_clawback())
+            }
+            Step::Clawback { grant, amount: Some(amount) } => {
+                failure(VestingContractClient::new(env, &self.grant(grant)?).try_clawback_partial(&i128::from(*amount)))
+            }
+        };
+
+        let events = match (step, &outcome) {
+            (Step::Advance { .. }, _) | (_, Err(_)) => std::vec::Vec::new(),
+            _ => self.events(),
+        };
+        Ok(self.report(describe(step), outcome, events))
+    }
+
+    pub fn env(&self) -> &Env {
+        &self.env
+    }
+
+    /// Looks up an account or contract by its scenario name.
+    pub fn address(&self, name: &str) -> Result<Address, Error> {
+        self.names
+            .iter()
+            .find(|(known, _)| known == name)
+            .map(|(_, address)| address.clone())
+            .ok_or_else(|| Error::UnknownName(name.to_string()))
+    }
+
+    pub fn balance(&self, name: &str) -> Result<i128, Error> {
+        Ok(token::Client::new(&self.env, &self.token).balance(&self.address(name)?))
+    }
+
+    fn add_name(&mut self, name: &str, address: Address) -> Result<(), Error> {
+        if self.names.iter().any(|(known, _)| known == name) {
+            return Err(Error::DuplicateName(name.to_string()));
+        }
+        self.names.push((name.to_string(), address));
+        Ok(())
+    }
+
+    fn split(&self, name: &str) -> Result<Address, Error> {
+        if !self.splits.iter().any(|split| split == name) {
+            return Err(Error::UnknownName(name.to_string()));
+        }
+        self.address(name)
+    }
+
+    fn grant(&self, name: &str) -> Result<Address, Error> {
+        if !self.grants.iter().any(|grant| grant == name) {
+            return Err(Error::UnknownName(name.to_string()));
+        }
+        self.address(name)
+    }
+
+    fn token_admin(&self) -> token::StellarAssetClient<'_> {
+        token::StellarAssetClient::new(&self.env, &self.token)
+    }
+
+    fn shares(&self, shares: &[Share]) -> Result<Vec<RecipientShare>, Error> {
+        let mut result = Vec::new(&self.env);
+        for share in shares {
+            result.push_back(RecipientShare { destination: self.address(&share.to)?, basis_points: share.basis_points });
+        }
+        Ok(result)
+    }
+
+    /// Events from the last contract call. PayD events are rendered like the indexer does;
+    /// anything else (token transfers, mints) as its name, topics and data.
+    fn events(&self) -> std::vec::Vec<Value> {
+        let mut events = std::vec::Vec::new();
+        for event in self.env.events().all().events() {
+            let kind = event.contract_id.as_ref().and_then(|id| self.contracts.kind(&ScAddress::Contract(id.clone())));
+            let decoded = kind.map(|kind| decode_event(&self.env, kind, event));
+            let value = match decoded {
+                Some(Ok(Some(decoded))) => json::to_json(&decoded),
+                _ => {
+                    let ContractEventBody::V0(body) = &event.body;
+                    let mut topics = body.topics.iter().map(scval_json);
+                    let name = topics.next().unwrap_or(Value::Null);
+                    let contract = event.contract_id.as_ref().map(|id| {
+                        Value::String(ScAddress::Contract(id.clone()).to_string())
+                    });
+                    serde_json::json!({
+                        "contract": contract,
+                        "event": name,
+                        "topics": topics.collect::<std::vec::Vec<_>>(),
+                        "data": scval_json(&body.data),
+                    })
+                }
+            };
+            events.push(self.rename(value));
+        }
+        events
+    }
+
+    /// Replaces strkeys of known addresses with their scenario names.
+    fn rename(&self, value: Value) -> Value {
+        match value {
+            Value::String(strkey) => {
+                let name = self.names.iter().find(|(_, address)| json::address(address) == strkey);
+                match name {
+                    Some((name, _)) => Value::String(name.clone()),
+                    None if strkey == json::address(&self.token) => Value::String("token".into()),
+                    None => Value::String(strkey),
+                }
+            }
+            Value::Array(values) => Value::Array(values.into_iter().map(|v| self.rename(v)).collect()),
+            Value::Object(fields) => Value::Object(fields.into_iter().map(|(k, v)| (k, self.rename(v))).collect()),
+            other => other,
+        }
+    }
+
+    fn report(
+        &self,
+        description: std::string::String,
+        outcome: Result<(), std::string::String>,
+        events: std::vec::Vec<Value>,
+    ) -> StepReport {
+        let budget = self.env.cost_estimate().budget();
+        let client = token::Client::new(&self.env, &self.token);
+        StepReport {
+            description,
+            timestamp: self.env.ledger().timestamp(),
+            outcome,
+            cpu_instructions: budget.cpu_instruction_cost(),
+            memory_bytes: budget.memory_bytes_cost(),
+            events,
+            balances: self.names.iter().map(|(name, address)| (name.clone(), client.balance(address))).collect(),
+        }
+    }
+}
+
+/// Reduces a client `try_` result to whether the call went through.
+fn failure<T, E: Debug, F: Debug>(result: Result<T, Result<E, F>>) -> Result<(), std::string::String> {
+    match result {
+        Ok(_) => Ok(()),
+        Err(Ok(e)) => Err(format!("{e:?}")),
+        Err(Err(e)) => Err(format!("{e:?}")),
+    }
+}
+
+/// Checked before converting because the test `Env` panics on a host error instead of returning it.
+fn status_symbol(env: &Env, status: &str) -> Result<Symbol, Error> {
+    let valid = status.len() <= 32 && status.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_');
+    let symbol = valid.then(|| Symbol::try_from_val(env, &status).ok()).flatten();
+    symbol.ok_or_else(|| Error::InvalidStatus(status.into()))
+}
+
+fn scval_json(value: &ScVal) -> Value {
+    match value {
+        ScVal::Address(address) => Value::String(address.to_string()),
+        ScVal::Symbol(symbol) => Value::String(symbol.to_utf8_string_lossy()),
+        ScVal::String(string) => Value::String(string.to_utf8_string_lossy()),
+        ScVal::U32(n) => Value::from(*n),
+        ScVal::U64(n) => Value::from(*n),
+        ScVal::I128(n) => Value::String(i128::from(n).to_string()),
+        other => Value::String(format!("{other:?}")),
+    }
+}
+
+fn describe(step: &Step) -> std::string::String {
+    match step {
+        Step::Advance { seconds } => format!("advance {seconds}s"),
+        Step::Mint { to, amount } => format!("mint {amount} to {to}"),
+        Step::Pay { from, amount, receiver_id, target_asset, anchor_id } => {
+            format!("pay {amount} from {from} to {receiver_id} as {target_asset} via {anchor_id}")
+        }
+        Step::SetStatus { payment, status } => format!("set payment {payment} to {status}"),
+        Step::Distribute { split, from, amount } => format!("distribute {amount} from {from} through {split}"),
+        Step::SetShares { split, shares } => format!("set {} shares on {split}", shares.len()),
+        Step::Claim { grant } => format!("claim {grant}"),
+        Step::TopUp { grant, funder, amount } => format!("top up {grant} with {amount} from {funder}"),
+        Step::Clawback { grant, amount: None } => format!("claw back {grant}"),
+        Step::Clawback { grant, amount: Some(amount) } => format!("claw back {amount} of {grant}"),
+    }
+}
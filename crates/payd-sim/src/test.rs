@@ -0,0 +1,175 @@
+#![cfg(test)]
+
+use crate::*;
+
+const EXAMPLE: &str = include_str!("../scenarios/example.toml");
+
+fn balance(report: &StepReport, name: &str) -> i128 {
+    report.balances.iter().find(|(known, _)| known == name).unwrap().1
+}
+
+#[test]
+fn test_example_scenario() {
+    let scenario = Scenario::from_toml(EXAMPLE).unwrap();
+    let reports = Simulation::run(&scenario).unwrap();
+
+    assert_eq!(reports.len(), scenario.steps.len() + 1);
+    assert!(reports.iter().all(|report| report.outcome.is_ok()));
+
+    let setup = &reports[0];
+    assert_eq!(balance(setup, "employer"), 100000 - 12000);
+    assert_eq!(balance(setup, "alice_grant"), 12000);
+
+    // The payment is escrowed in the payment contract for the anchor
+    assert_eq!(balance(&reports[1], "payments"), 5000);
+    let pay_init = reports[1].events.iter().find(|e| e["event"] == "payment_initiated").unwrap();
+    assert_eq!(pay_init["contract"], "payments");
+    assert_eq!(pay_init["from"], "employer");
+    assert_eq!(pay_init["asset"], "token");
+
+    let distributed = &reports[3];
+    assert_eq!(balance(distributed, "alice"), 600);
+    assert_eq!(balance(distributed, "bob"), 400);
+
+    // A month in, one twelfth has vested
+    let last = reports.last().unwrap();
+    assert_eq!(last.timestamp, 2592000);
+    assert_eq!(balance(last, "alice"), 600 + 1000);
+    assert!(last.events.iter().any(|e| e["event"] == "claimed" && e["amount"] == "1000"));
+    assert!(last.cpu_instructions > 0);
+    assert!(last.memory_bytes > 0);
+}
+
+#[test]
+fn test_failed_step_is_reported() {
+    let scenario = Scenario::from_toml(
+        r#"
+        admin = "employer"
+        [accounts]
+        employer = 100
+        alice = 0
+
+        [[splits]]
+        name = "pool"
+        admin = "employer"
+        shares = [{ to = "alice", basis_points = 10000 }]
+
+        [[steps]]
+        action = "distribute"
+        split = "pool"
+        from = "employer"
+        amount = 500
+
+        [[steps]]
+        action = "mint"
+        to = "employer"
+        amount = 400
+
+        [[steps]]
+        action = "distribute"
+        split = "pool"
+        from = "employer"
+        amount = 500
+        "#,
+    )
+    .unwrap();
+    let reports = Simulation::run(&scenario).unwrap();
+
+    // Overdrawing fails without moving anything, and the run carries on
+    assert!(reports[1].outcome.is_err());
+    assert!(reports[1].events.is_empty());
+    assert_eq!(balance(&reports[1], "employer"), 100);
+
+    assert!(reports[3].outcome.is_ok());
+    assert_eq!(balance(&reports[3], "alice"), 500);
+}
+
+#[test]
+fn test_unknown_name() {
+    let scenario = Scenario::from_toml(
+        r#"
+        admin = "employer"
+        [accounts]
+        employer = 100
+
+        [[steps]]
+        action = "claim"
+        grant = "missing"
+        "#,
+    )
+    .unwrap();
+
+    assert!(matches!(Simulation::run(&scenario), Err(Error::UnknownName(name)) if name == "missing"));
+}
+
+#[test]
+fn test_advance_past_end_of_time() {
+    let scenario = Scenario::from_toml(
+        r#"
+        admin = "employer"
+        start_time = 100
+        [accounts]
+        employer = 100
+
+        [[steps]]
+        action = "advance"
+        seconds = 9223372036854775807
+
+        [[steps]]
+        action = "advance"
+        seconds = 9223372036854775807
+        "#,
+    )
+    .unwrap();
+
+    // TOML integers stop at `i64::MAX`, so it takes two steps to run out of clock
+    let after_first = 100 + i64::MAX as u64;
+    assert!(matches!(
+        Simulation::run(&scenario),
+        Err(Error::TimeOverflow(now, seconds)) if now == after_first && seconds == i64::MAX as u64
+    ));
+}
+
+#[test]
+fn test_invalid_status() {
+    let scenario = Scenario::from_toml(
+        r#"
+        admin = "employer"
+        [accounts]
+        employer = 100
+
+        [[steps]]
+        action = "set_status"
+        payment = 1
+        status = "not settled"
+        "#,
+    )
+    .unwrap();
+
+    assert!(matches!(Simulation::run(&scenario), Err(Error::InvalidStatus(status)) if status == "not settled"));
+}
+
+#[test]
+fn test_invalid_split_fails_setup() {
+    let scenario = Scenario::from_toml(
+        r#"
+        admin = "employer"
+        [accounts]
+        employer = 100
+
+        [[splits]]
+        name = "pool"
+        admin = "employer"
+        shares = [{ to = "employer", basis_points = 5000 }]
+        "#,
+    )
+    .unwrap();
+
+    assert!(matches!(Simulation::run(&scenario), Err(Error::Setup(_))));
+}
+
+#[test]
+fn test_rejects_unknown_action() {
+    let source = "admin = \"a\"\n[accounts]\na = 1\n[[steps]]\naction = \"teleport\"\n";
+    assert!(Scenario::from_toml(source).is_err());
+}
@@ -0,0 +1,50 @@
+//! Test fixtures shared by the contract test suites: an environment with every
+//! authorization mocked, generated accounts, and Stellar asset contracts to pay with.
+//! Only ever used as a dev-dependency.
+
+use soroban_sdk::{testutils::Address as _, token, Address, Env};
+
+/// A fresh environment with every authorization mocked.
+pub fn env() -> Env {
+    let e = Env::default();
+    e.mock_all_auths();
+    e
+}
+
+/// `N` freshly generated accounts.
+pub fn accounts<const N: usize>(e: &Env) -> [Address; N] {
+    core::array::from_fn(|_| Address::generate(e))
+}
+
+/// A Stellar asset contract with its own issuer.
+pub struct Asset<'a> {
+    pub address: Address,
+    pub client: token::Client<'a>,
+    issuer: token::StellarAssetClient<'a>,
+}
+
+impl Asset<'_> {
+    pub fn new(e: &Env) -> Self {
+        let address = e.register_stellar_asset_contract_v2(Address::generate(e)).address();
+        Asset {
+            client: token::Client::new(e, &address),
+            issuer: token::StellarAssetClient::new(e, &address),
+            address,
+        }
+    }
+
+    /// A new asset with `amount` already minted to `holder`.
+    pub fn funded(e: &Env, holder: &Address, amount: i128) -> Self {
+        let asset = Self::new(e);
+        asset.mint(holder, amount);
+        asset
+    }
+
+    pub fn mint(&self, to: &Address, amount: i128) {
+        self.issuer.mint(to, &amount);
+    }
+
+    pub fn balance(&self, of: &Address) -> i128 {
+        self.client.balance(of)
+    }
+}
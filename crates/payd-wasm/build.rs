@@ -0,0 +1,57 @@
+//! Compiles the contracts that tests deploy from WASM into `OUT_DIR`, so nothing has to be
+//! built by hand before `cargo test`.
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const CONTRACTS: [&str; 2] = ["revenue_split", "vesting_escrow"];
+/// Also built with the `release-with-logs` profile, as a second version to upgrade to.
+const WITH_LOGS: [&str; 1] = ["revenue_split"];
+
+fn main() {
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").expect("OUT_DIR is set by cargo"));
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+    for source in ["contracts", "crates/payd-common", "Cargo.toml"] {
+        println!("cargo:rerun-if-changed={}", workspace.join(source).display());
+    }
+
+    let target_dir = out_dir.join("target");
+    build(&workspace, &target_dir, "release", &CONTRACTS);
+    build(&workspace, &target_dir, "release-with-logs", &WITH_LOGS);
+
+    let built = target_dir.join("wasm32v1-none");
+    for contract in CONTRACTS {
+        copy(&built.join(format!("release/{contract}.wasm")), &out_dir.join(format!("{contract}.wasm")));
+    }
+    for contract in WITH_LOGS {
+        copy(
+            &built.join(format!("release-with-logs/{contract}.wasm")),
+            &out_dir.join(format!("{contract}_with_logs.wasm")),
+        );
+    }
+}
+
+fn build(workspace: &Path, target_dir: &Path, profile: &str, contracts: &[&str]) {
+    let mut cargo = Command::new(env::var_os("CARGO").expect("CARGO is set by cargo"));
+    cargo
+        .current_dir(workspace)
+        .args(["build", "--target", "wasm32v1-none", "--profile", profile, "--target-dir"])
+        .arg(target_dir)
+        // These flags are for this crate's host build, not for the contracts
+        .env_remove("CARGO_ENCODED_RUSTFLAGS");
+    for contract in contracts {
+        cargo.args(["-p", contract]);
+    }
+
+    let status = cargo.status().expect("cargo runs");
+    if !status.success() {
+        panic!("building {contracts:?} with the {profile} profile failed");
+    }
+}
+
+fn copy(from: &Path, to: &Path) {
+    if let Err(error) = std::fs::copy(from, to) {
+        panic!("copying {} failed: {error}", from.display());
+    }
+}
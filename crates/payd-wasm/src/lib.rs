@@ -0,0 +1,12 @@
+//! The compiled contracts that tests deploy from WASM, i.e. the templates the factories
+//! deploy. `build.rs` compiles them from the workspace sources whenever those change.
+#![no_std]
+
+pub const REVENUE_SPLIT: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/revenue_split.wasm"));
+
+/// `revenue_split` built with the `release-with-logs` profile: the same contract as different
+/// code, to upgrade a deployed split to.
+pub const REVENUE_SPLIT_WITH_LOGS: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/revenue_split_with_logs.wasm"));
+
+pub const VESTING_ESCROW: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/vesting_escrow.wasm"));
+